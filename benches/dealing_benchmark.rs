@@ -0,0 +1,59 @@
+use criterion::{criterion_group, Criterion};
+
+// run with `--features parallel` to compare against the sequential dealing
+mod dealing_benches {
+    use super::*;
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+    use zkrand::dkg::DkgConfig;
+    use zkrand::{dkg_global_public_params, DkgMemberParams, MemberKey};
+
+    fn dealing<const THRESHOLD: usize, const NUMBER_OF_MEMBERS: usize>(c: &mut Criterion) {
+        let mut rng = ChaCha20Rng::seed_from_u64(42);
+
+        let dkg_config = DkgConfig::new(THRESHOLD, NUMBER_OF_MEMBERS).unwrap();
+        let members: Vec<_> = (0..NUMBER_OF_MEMBERS)
+            .map(|_| MemberKey::random(&mut rng))
+            .collect();
+        let pks: Vec<_> = members.iter().map(|m| m.public_key()).collect();
+
+        let name = format!("dkg dealing ({THRESHOLD}, {NUMBER_OF_MEMBERS})");
+        let pks_clone = pks.clone();
+        c.bench_function(name.as_str(), move |b| {
+            b.iter(|| DkgMemberParams::new(dkg_config, 1, pks_clone.clone(), &mut rng).unwrap())
+        });
+
+        // simplified setup with threshold dealers only used for benchmark
+        let mut rng = ChaCha20Rng::seed_from_u64(43);
+        let dkgs: Vec<_> = (0..THRESHOLD)
+            .map(|i| DkgMemberParams::new(dkg_config, i + 1, pks.clone(), &mut rng).unwrap())
+            .collect();
+        let dkgs_pub: Vec<_> = dkgs
+            .iter()
+            .map(|dkg| dkg.member_public_params().clone())
+            .collect();
+
+        let name = format!("dkg global public params ({THRESHOLD}, {NUMBER_OF_MEMBERS})");
+        let dkgs_pub_clone = dkgs_pub.clone();
+        c.bench_function(name.as_str(), move |b| {
+            let pps: Vec<_> = dkgs_pub_clone.iter().collect();
+            b.iter(|| dkg_global_public_params(&pps))
+        });
+
+        let name = format!("dkg share key ({THRESHOLD}, {NUMBER_OF_MEMBERS})");
+        c.bench_function(name.as_str(), move |b| {
+            let pps: Vec<_> = dkgs_pub.iter().collect();
+            b.iter(|| members[0].dkg_share_key(&dkg_config, 1, &pps).unwrap())
+        });
+    }
+
+    criterion_group! {
+        name = dealing_benches;
+        config = Criterion::default().sample_size(10);
+        targets =
+            dealing::<42,83>,
+            dealing::<86,171>,
+    }
+}
+
+criterion::criterion_main!(dealing_benches::dealing_benches);
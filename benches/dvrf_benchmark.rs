@@ -9,8 +9,8 @@ mod dvrf_benches {
     use sha3::{Digest, Keccak256};
     use zkrand::dkg::DkgConfig;
     use zkrand::{
-        combine_partial_evaluations, hash_to_curve_bn, keygen, shares, DkgShareKey, PseudoRandom,
-        EVAL_PREFIX,
+        combine_partial_evaluations, hash_to_curve_bn, keygen, shares, Combiner, DkgShareKey,
+        PseudoRandom, EVAL_PREFIX,
     };
 
     // partial evaluation time independent of the values of threshold and number of members
@@ -56,8 +56,18 @@ mod dvrf_benches {
         assert!(res);
 
         let name = format!("dvrf combine partial evaluation ({THRESHOLD}, {NUMBER_OF_MEMBERS})");
+        let evals_clone = evals.clone();
         c.bench_function(name.as_str(), move |b| {
-            b.iter(|| combine_partial_evaluations(&dkg_config, &evals[0..THRESHOLD]).unwrap());
+            b.iter(|| {
+                combine_partial_evaluations(&dkg_config, &evals_clone[0..THRESHOLD]).unwrap()
+            });
+        });
+
+        // lagrange coefficients are cached after the first round with the same index set
+        let mut combiner = Combiner::new(dkg_config);
+        let name = format!("dvrf combiner with cached lambdas ({THRESHOLD}, {NUMBER_OF_MEMBERS})");
+        c.bench_function(name.as_str(), move |b| {
+            b.iter(|| combiner.combine(&evals[0..THRESHOLD]).unwrap());
         });
     }
 
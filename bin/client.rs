@@ -28,10 +28,13 @@ use std::fs::{create_dir_all, read, read_to_string, write};
 use serialise::DkgMemberPublicParams as DkgMemberPublicParamsSerde;
 
 use zkrand::dkg::{DkgConfig, PartialEval};
+use zkrand::dkg_circuit::{MAX_WINDOW_SIZE, MIN_WINDOW_SIZE};
 use zkrand::{
-    combine_partial_evaluations, dkg_global_public_params, load_or_create_params,
-    load_or_create_pk, load_or_create_vk, DkgGlobalPubParams, DkgMemberParams,
-    DkgMemberPublicParams, DkgShareKey, MemberKey, PseudoRandom,
+    check_public_keys, combine_partial_evaluations, dkg_global_public_params,
+    load_or_create_params, load_or_create_pk, load_or_create_vk, recommend_circuit_params,
+    DealerContext, DkgCircuitParams, DkgGlobalPubParams, DkgMemberParams, DkgMemberPublicParams,
+    DkgShareKey, HashSuite, MemberKey, PseudoRandom, SessionId, GRUMPKIN_WINDOW_SIZE, MAX_DEGREE,
+    WINDOW_SIZE,
 };
 
 mod mock;
@@ -69,6 +72,15 @@ enum Commands {
         /// If split is selected, verifier contract and verifying key contract are generated separately
         #[arg(long, default_value_t = false)]
         split: bool,
+        /// Window size of fixed base multiplications; overrides WINDOW_SIZE
+        #[arg(long)]
+        window: Option<u32>,
+    },
+    /// Measure the circuit for (t, n) and recommend the smallest degree and the window size
+    Measure {
+        /// Largest window size to try
+        #[arg(long, default_value_t = 6)]
+        max_window: usize,
     },
     /// Generate member secret/public key pair
     Keygen {
@@ -155,13 +167,115 @@ struct ParamsConfig {
     threshold: u32,
     number_of_members: u32,
     degree: u32,
+    // window size of fixed base multiplications in the circuit
+    window_size: u32,
+    // window size of the grumpkin fixed base multiplication g^r
+    #[serde(default = "default_grumpkin_window_size")]
+    grumpkin_window_size: u32,
+    // range check the bits of the encryption randomness against the scalar modulus
+    #[serde(default)]
+    canonical_random: bool,
+    // circuit shape for padded mode
+    #[serde(default)]
+    max_threshold: Option<u32>,
+    #[serde(default)]
+    max_number_of_members: Option<u32>,
+    // session id of the dkg run as a 32-byte hex string, same as sessionId in the contract
+    #[serde(default)]
+    session_id: Option<String>,
+    // hash to curve suite of the deployment, same as the suite of the PseudoRand contract
+    #[serde(default)]
+    hash_suite: HashSuite,
+}
+
+fn default_grumpkin_window_size() -> u32 {
+    GRUMPKIN_WINDOW_SIZE as u32
 }
 
 impl ParamsConfig {
     pub fn dkg_config(&self) -> Result<DkgConfig> {
-        let config = DkgConfig::new(self.threshold as usize, self.number_of_members as usize)?;
+        let config = match (self.max_threshold, self.max_number_of_members) {
+            (Some(max_threshold), Some(max_number_of_members)) => DkgConfig::new_padded(
+                self.threshold as usize,
+                self.number_of_members as usize,
+                max_threshold as usize,
+                max_number_of_members as usize,
+            )?,
+            _ => DkgConfig::new(self.threshold as usize, self.number_of_members as usize)?,
+        };
+
+        let session = match &self.session_id {
+            Some(session_id) => {
+                let bytes = hex_to_le_bytes(session_id);
+                let session = Option::<BnScalar>::from(BnScalar::from_bytes(&bytes))
+                    .ok_or_else(|| anyhow!("Invalid session id {session_id}"))?;
+                SessionId::new(session)
+            }
+            None => SessionId::default(),
+        };
+
+        Ok(config.with_session(session.with_suite(self.hash_suite)))
+    }
+
+    // use the given degree or the smallest degree for the circuit, up to the degree of the downloaded params
+    pub fn set_degree(&mut self, degree: Option<u32>) -> Result<()> {
+        let degree = match degree {
+            Some(degree) => degree,
+            None => {
+                let dkg_config = self.dkg_config()?;
+                let degree = dkg_config.min_degree_with_params(self.circuit_params()?)?;
+                info!("DEGREE is not set; use the smallest degree {degree} for the circuit");
+                degree
+            }
+        };
+
+        if degree as usize > MAX_DEGREE {
+            return Err(anyhow!(
+                "degree {degree} is above the maximum degree {MAX_DEGREE} of the downloaded kzg params; \
+                 use a smaller (threshold, number_of_members)"
+            ));
+        }
+        self.degree = degree;
+        info!("degree = {degree}");
 
-        Ok(config)
+        Ok(())
+    }
+
+    pub fn circuit_params(&self) -> Result<DkgCircuitParams> {
+        let circuit_params = DkgCircuitParams {
+            canonical_random: self.canonical_random,
+            ..DkgCircuitParams::new(self.window_size as usize)?
+                .with_grumpkin_window_size(self.grumpkin_window_size as usize)?
+        };
+
+        Ok(circuit_params)
+    }
+
+    // (t, n, degree) in contract names, with (t_max, n_max) for padded circuits
+    // and the window sizes if they are not the default, with -c for a canonical decomposition of r
+    pub fn tag(&self) -> String {
+        let shape = match (self.max_threshold, self.max_number_of_members) {
+            (Some(max_threshold), Some(max_number_of_members)) => {
+                format!("padded-{}-{}", max_threshold, max_number_of_members)
+            }
+            _ => format!("{}-{}", self.threshold, self.number_of_members),
+        };
+        let window = if self.window_size as usize == WINDOW_SIZE {
+            String::new()
+        } else {
+            format!("-w{}", self.window_size)
+        };
+        let grumpkin_window = if self.grumpkin_window_size as usize == GRUMPKIN_WINDOW_SIZE {
+            String::new()
+        } else {
+            format!("-gw{}", self.grumpkin_window_size)
+        };
+        let canonical = if self.canonical_random { "-c" } else { "" };
+
+        format!(
+            "{}-{}{}{}{}",
+            shape, self.degree, window, grumpkin_window, canonical
+        )
     }
 }
 
@@ -217,10 +331,14 @@ fn save_proof(proof: &[u8], instance: &[BnScalar], index: usize) -> Result<()> {
 }
 
 fn public_keys(dkg_config: &DkgConfig, instance: &[BnScalar]) -> Vec<GkG1> {
-    let mut begin = if cfg!(feature = "g2chip") {
-        5 * dkg_config.number_of_members() + 14
+    // offsets follow the member slots of the circuit; padded slots come after the active members
+    let number_of_slots = dkg_config.max_number_of_members();
+    let mut begin = if cfg!(feature = "g2shares") {
+        14 * number_of_slots + 16
+    } else if cfg!(feature = "g2chip") {
+        6 * number_of_slots + 16
     } else {
-        5 * dkg_config.number_of_members() + 6
+        6 * number_of_slots + 8
     };
 
     let mut pks = vec![];
@@ -235,18 +353,30 @@ fn public_keys(dkg_config: &DkgConfig, instance: &[BnScalar]) -> Vec<GkG1> {
 }
 
 fn setup(params: &ParamsConfig, skip: bool, split: bool) -> Result<()> {
+    let dkg_config = params.dkg_config()?;
+    let circuit_params = params.circuit_params()?;
+
+    let min_degree = dkg_config.min_degree_with_params(circuit_params)?;
+    if params.degree < min_degree {
+        return Err(anyhow!(
+            "degree {} is too small for ({}, {}); at least {min_degree} is required",
+            params.degree,
+            params.threshold,
+            params.number_of_members
+        ));
+    }
+
     let start = start_timer!(|| format!("kzg load or setup params with degree {}", params.degree));
     let general_params = load_or_create_params(KZG_PARAMS_DIR, params.degree as usize)?;
     end_timer!(start);
 
-    let dkg_config = params.dkg_config()?;
-
     let start = start_timer!(|| format!(
         "kzg load or setup proving keys with degree {}",
         params.degree
     ));
     let pk = load_or_create_pk(
         dkg_config,
+        circuit_params,
         KZG_PARAMS_DIR,
         &general_params,
         params.degree as usize,
@@ -267,15 +397,9 @@ fn setup(params: &ParamsConfig, skip: bool, split: bool) -> Result<()> {
             save_solidity("Halo2Verifier.sol", &verifier_solidity)?;
 
             let contract_name = if cfg!(feature = "g2chip") {
-                format!(
-                    "Halo2VerifyingKey-{}-{}-{}-g2.sol",
-                    params.threshold, params.number_of_members, params.degree,
-                )
+                format!("Halo2VerifyingKey-{}-g2.sol", params.tag())
             } else {
-                format!(
-                    "Halo2VerifyingKey-{}-{}-{}.sol",
-                    params.threshold, params.number_of_members, params.degree,
-                )
+                format!("Halo2VerifyingKey-{}.sol", params.tag())
             };
 
             save_solidity(contract_name, &vk_solidity)?;
@@ -288,15 +412,9 @@ fn setup(params: &ParamsConfig, skip: bool, split: bool) -> Result<()> {
             let generator = SolidityGenerator::new(&general_params, vk, Bdfg21, num_instances);
             let verifier_solidity = generator.render()?;
             let contract_name = if cfg!(feature = "g2chip") {
-                format!(
-                    "Halo2Verifier-{}-{}-{}-g2.sol",
-                    params.threshold, params.number_of_members, params.degree,
-                )
+                format!("Halo2Verifier-{}-g2.sol", params.tag())
             } else {
-                format!(
-                    "Halo2Verifier-{}-{}-{}.sol",
-                    params.threshold, params.number_of_members, params.degree,
-                )
+                format!("Halo2Verifier-{}.sol", params.tag())
             };
 
             save_solidity(contract_name, &verifier_solidity)?;
@@ -332,30 +450,68 @@ fn main() -> Result<()> {
         })
     };
 
-    let config_keys = ["THRESHOLD", "NUMBER_OF_MEMBERS", "DEGREE"];
-    let default_values: (u32, u32, u32) = (3, 5, 18);
-    let (threshold, number_of_members, degree) = if is_any_var_missing_or_invalid(&config_keys) {
+    let config_keys = ["THRESHOLD", "NUMBER_OF_MEMBERS"];
+    let default_values: (u32, u32) = (3, 5);
+    let (threshold, number_of_members) = if is_any_var_missing_or_invalid(&config_keys) {
         info!("One or more env variables are missing or invalid. Using default config");
         default_values
     } else {
         (
             env::var(config_keys[0]).unwrap().parse::<u32>().unwrap(),
             env::var(config_keys[1]).unwrap().parse::<u32>().unwrap(),
-            env::var(config_keys[2]).unwrap().parse::<u32>().unwrap(),
         )
     };
 
-    info!(
-        "(threshold, number_of_members, degree) = ({}, {}, {})",
-        threshold, number_of_members, degree
-    );
+    // the degree is only needed for setup, prove and verify, and is chosen automatically if DEGREE is not set
+    let parse_var = |key: &str| env::var(key).ok().and_then(|val| val.parse::<u32>().ok());
+    let degree = parse_var("DEGREE");
+
+    // optional circuit shape (t_max, n_max) for a padded circuit
+    let max_threshold = parse_var("MAX_THRESHOLD");
+    let max_number_of_members = parse_var("MAX_NUMBER_OF_MEMBERS");
+    if let (Some(t), Some(n)) = (max_threshold, max_number_of_members) {
+        info!("padded circuit with (max_threshold, max_number_of_members) = ({t}, {n})");
+    }
+
+    // window size of the circuit; prove and verify need the same value as setup
+    let window_size = parse_var("WINDOW_SIZE").unwrap_or(WINDOW_SIZE as u32);
+    let grumpkin_window_size =
+        parse_var("GRUMPKIN_WINDOW_SIZE").unwrap_or(GRUMPKIN_WINDOW_SIZE as u32);
+    // canonical decomposition of the encryption randomness; prove and verify need the same value as setup
+    let canonical_random = parse_var("CANONICAL_RANDOM").unwrap_or(0) != 0;
+
+    // optional session id binding dkg proofs and evaluations to one run
+    let session_id = env::var("SESSION_ID").ok();
+    if let Some(session_id) = &session_id {
+        info!("session id {session_id}");
+    }
 
-    let params = ParamsConfig {
+    // hash to curve suite: evm (default), sha256 or keccak256
+    let hash_suite = match env::var("HASH_SUITE").ok().as_deref() {
+        None | Some("evm") => HashSuite::Evm,
+        Some("sha256") => HashSuite::Sha256,
+        Some("keccak256") => HashSuite::Keccak256,
+        Some(suite) => return Err(anyhow!("Unknown hash suite {suite}")),
+    };
+    info!("hash suite {hash_suite:?}");
+
+    let mut params = ParamsConfig {
         threshold,
         number_of_members,
-        degree,
+        degree: 0,
+        window_size,
+        grumpkin_window_size,
+        canonical_random,
+        max_threshold,
+        max_number_of_members,
+        session_id,
+        hash_suite,
     };
     let dkg_config = params.dkg_config()?;
+    info!(
+        "(threshold, number_of_members) = ({}, {})",
+        threshold, number_of_members
+    );
 
     let cli = Cli::parse();
     match cli.command {
@@ -382,9 +538,33 @@ fn main() -> Result<()> {
                 );
             }
         }
-        Commands::Setup { skip, split } => {
+        Commands::Setup {
+            skip,
+            split,
+            window,
+        } => {
+            if let Some(window) = window {
+                params.window_size = window;
+            }
+            params.set_degree(degree)?;
             setup(&params, skip, split)?;
         }
+        Commands::Measure { max_window } => {
+            if max_window < MIN_WINDOW_SIZE || max_window > MAX_WINDOW_SIZE {
+                return Err(anyhow!(
+                    "window size must be between {MIN_WINDOW_SIZE} and {MAX_WINDOW_SIZE}"
+                ));
+            }
+            let (circuit_params, degree) = recommend_circuit_params(dkg_config, max_window)?;
+            info!(
+                "({}, {}): smallest degree {} with window size {} and grumpkin window size {}",
+                dkg_config.threshold(),
+                dkg_config.number_of_members(),
+                degree,
+                circuit_params.window_size,
+                circuit_params.grumpkin_window_size
+            );
+        }
         Commands::Keygen { file } => {
             let member = MemberKey::random(&mut rng);
             let mpk = member.public_key();
@@ -410,13 +590,19 @@ fn main() -> Result<()> {
                     if index < 1 || index > dkg_config.number_of_members() {
                         return Err(anyhow!("Invalid member index"));
                     }
+                    params.set_degree(degree)?;
                     // read all member public keys
                     let bytes = read_to_string(MEM_PUBLIC_KEYS_PATH)?;
                     let mpks_bytes: Vec<Point> = serde_json::from_str(&bytes)?;
                     let mpks: Vec<GkG1> = mpks_bytes.into_iter().map(|pk| pk.into()).collect();
-
-                    let dkg = DkgMemberParams::new(dkg_config, mpks, &mut rng)?;
-                    let circuit = dkg.circuit(&mut rng);
+                    check_public_keys(&mpks)
+                        .map_err(|e| anyhow!("Registered member public keys are rejected: {e}"))?;
+
+                    // bind the encrypted shares to this dealer
+                    let context = DealerContext::new(dkg_config.session(), index);
+                    let dkg =
+                        DkgMemberParams::new_with_context(dkg_config, context, mpks, &mut rng)?;
+                    let circuit = dkg.circuit_with_params(params.circuit_params()?, &mut rng);
                     let instance = dkg.instance();
 
                     let start = start_timer!(|| format!(
@@ -433,6 +619,7 @@ fn main() -> Result<()> {
                     ));
                     let pk = load_or_create_pk(
                         dkg_config,
+                        params.circuit_params()?,
                         params_dir,
                         &general_params,
                         params.degree as usize,
@@ -451,6 +638,7 @@ fn main() -> Result<()> {
                     if index < 1 || index > dkg_config.number_of_members() {
                         return Err(anyhow!("Invalid member index"));
                     }
+                    params.set_degree(degree)?;
 
                     let proof_path = &format!("{DKG_PROOFS_DIR}/proof_{index}.dat");
                     let proof = read(proof_path)?;
@@ -474,6 +662,9 @@ fn main() -> Result<()> {
                         let bytes = read_to_string(MEM_PUBLIC_KEYS_PATH)?;
                         let mpks_bytes: Vec<Point> = serde_json::from_str(&bytes)?;
                         let mpks: Vec<GkG1> = mpks_bytes.into_iter().map(|pk| pk.into()).collect();
+                        check_public_keys(&mpks).map_err(|e| {
+                            anyhow!("Registered member public keys are rejected: {e}")
+                        })?;
                         let pks = public_keys(&dkg_config, &instance);
 
                         if !pks.eq(&mpks) {
@@ -496,6 +687,7 @@ fn main() -> Result<()> {
                     ));
                     let vk = load_or_create_vk(
                         dkg_config,
+                        params.circuit_params()?,
                         params_dir,
                         &general_params,
                         params.degree as usize,
@@ -524,13 +716,13 @@ fn main() -> Result<()> {
                             instances.push(s);
                         }
 
-                        let dkgs_pub: Vec<_> = instances
+                        let dkgs_pub = instances
                             .iter()
                             .map(|s| {
-                                let (pp, _) = DkgMemberPublicParams::from_instance(&dkg_config, s);
-                                pp
+                                let (pp, _) = DkgMemberPublicParams::from_instance(&dkg_config, s)?;
+                                Ok(pp)
                             })
-                            .collect();
+                            .collect::<Result<Vec<_>, zkrand::Error>>()?;
 
                         dkgs_pub
                     };
@@ -583,6 +775,7 @@ fn main() -> Result<()> {
                     let bytes = read_to_string(path)?;
                     let share_bytes: DkgShareKeySerde = serde_json::from_str(&bytes)?;
                     let share: DkgShareKey = share_bytes.into();
+                    let share = share.with_session(dkg_config.session());
                     let sigma = share.evaluate(input.as_bytes(), &mut rng);
                     let sigma_bytes: PartialEvalSerde = sigma.into();
                     let serialised = serde_json::to_string(&sigma_bytes)?;
@@ -652,7 +845,7 @@ fn main() -> Result<()> {
                         &verified[0..dkg_config.threshold()],
                     )?;
 
-                    pseudo.verify(input.as_bytes(), &gpk)?;
+                    pseudo.verify_in_session(&dkg_config.session(), input.as_bytes(), &gpk)?;
 
                     let pseudo_bytes: PseudoRandomSerde = pseudo.into();
                     let serialized = serde_json::to_string(&pseudo_bytes)?;
@@ -675,7 +868,7 @@ fn main() -> Result<()> {
                     let gpk_bytes: Point2 = serde_json::from_str(&bytes)?;
                     let gpk: BnG2 = gpk_bytes.into();
 
-                    pseudo.verify(input.as_bytes(), &gpk)?;
+                    pseudo.verify_in_session(&dkg_config.session(), input.as_bytes(), &gpk)?;
                     info!("final pseudorandom on input \"{input}\" verified successfully");
                 }
             }
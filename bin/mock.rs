@@ -11,8 +11,8 @@ use rand_core::RngCore;
 use std::fs::{read_to_string, write};
 use zkrand::dkg::{DkgConfig, PartialEval};
 use zkrand::{
-    combine_partial_evaluations, dkg_global_public_params, DkgGlobalPubParams, DkgMemberParams,
-    DkgMemberPublicParams, DkgShareKey, MemberKey, PseudoRandom,
+    combine_partial_evaluations, dkg_global_public_params, DealerContext, DkgGlobalPubParams,
+    DkgMemberParams, DkgMemberPublicParams, DkgShareKey, MemberKey, PseudoRandom,
 };
 
 fn save_params(
@@ -136,7 +136,10 @@ pub fn mock_dkg(dkg_config: &DkgConfig, mut rng: impl RngCore) -> Result<()> {
 
     // member index from 1..n
     let dkgs: Vec<_> = (0..dkg_config.number_of_members())
-        .map(|_| DkgMemberParams::new(*dkg_config, mpks.clone(), &mut rng).unwrap())
+        .map(|i| {
+            let context = DealerContext::new(dkg_config.session(), i + 1);
+            DkgMemberParams::new_with_context(*dkg_config, context, mpks.clone(), &mut rng).unwrap()
+        })
         .collect();
     let dkgs_pub: Vec<_> = dkgs.iter().map(|dkg| dkg.member_public_params()).collect();
 
@@ -171,7 +174,7 @@ pub fn mock_random(dkg_config: &DkgConfig, input: &[u8], mut rng: impl RngCore)
         let bytes = read_to_string(path)?;
         let share_bytes: DkgShareKeySerde = serde_json::from_str(&bytes)?;
         let share: DkgShareKey = share_bytes.into();
-        shares.push(share);
+        shares.push(share.with_session(dkg_config.session()));
     }
 
     let path = format!("{DKG_DIR}/gpp.json");
@@ -189,7 +192,8 @@ pub fn mock_random(dkg_config: &DkgConfig, input: &[u8], mut rng: impl RngCore)
     }
 
     let v = combine_partial_evaluations(&dkg_config, &sigmas[0..dkg_config.threshold()]).unwrap();
-    v.verify(input, &gpp.g2a).unwrap();
+    v.verify_in_session(&dkg_config.session(), input, &gpp.g2a)
+        .unwrap();
 
     save_evals(&sigmas, &v)?;
 
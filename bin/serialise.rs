@@ -6,10 +6,10 @@ use hex::{decode, encode};
 use serde::{Deserialize, Serialize};
 use zkrand::{
     dkg::DkgConfig, dkg::PartialEval as PartialEvalCurve,
-    dkg::PartialEvalProof as PartialEvalProofCurve, DkgGlobalPubParams as DkgGlobalPubParamsCurve,
-    DkgMemberParams as DkgMemberParamsCurve, DkgMemberPublicParams as DkgMemberPublicParamsCurve,
-    DkgShareKey as DkgShareKeyCurve, MemberKey as MemberKeyCurve,
-    PseudoRandom as PseudoRandomCurve,
+    dkg::PartialEvalProof as PartialEvalProofCurve, DealerContext,
+    DkgGlobalPubParams as DkgGlobalPubParamsCurve, DkgMemberParams as DkgMemberParamsCurve,
+    DkgMemberPublicParams as DkgMemberPublicParamsCurve, DkgShareKey as DkgShareKeyCurve,
+    MemberKey as MemberKeyCurve, PseudoRandom as PseudoRandomCurve,
 };
 
 pub fn le_bytes_to_hex(bytes: [u8; 32]) -> String {
@@ -193,9 +193,14 @@ pub struct DkgMemberPublicParams {
     // each member is indexed between 1...NUMBER_OF_MEMBERS
     public_shares: Vec<Point>,
     ciphers: Vec<String>,
+    tags: Vec<String>,
+    dealer_index: usize,
+    session: String,
     gr: Point,
     ga: Point,
     g2a: Point2,
+    #[cfg(feature = "g2shares")]
+    public_shares2: Vec<Point2>,
 }
 
 impl From<DkgMemberPublicParamsCurve> for DkgMemberPublicParams {
@@ -212,13 +217,23 @@ impl From<&DkgMemberPublicParamsCurve> for DkgMemberPublicParams {
             .iter()
             .map(|c| le_bytes_to_hex(c.to_bytes()))
             .collect();
+        let tags: Vec<_> = mp
+            .tags
+            .iter()
+            .map(|t| le_bytes_to_hex(t.to_bytes()))
+            .collect();
 
         DkgMemberPublicParams {
             public_shares,
             ciphers,
+            tags,
+            dealer_index: mp.context.dealer_index(),
+            session: le_bytes_to_hex(mp.context.session().value().to_bytes()),
             gr: mp.gr.into(),
             ga: mp.ga.into(),
             g2a: mp.g2a.into(),
+            #[cfg(feature = "g2shares")]
+            public_shares2: mp.public_shares2.iter().map(|s| s.into()).collect(),
         }
     }
 }
@@ -240,13 +255,27 @@ impl Into<DkgMemberPublicParamsCurve> for &DkgMemberPublicParams {
                 Fr::from_bytes(&c_bytes).expect("failed to deserialise Bn256 scalar")
             })
             .collect();
+        let tags: Vec<Fr> = self
+            .tags
+            .iter()
+            .map(|t| {
+                let t_bytes = hex_to_le_bytes(t);
+                Fr::from_bytes(&t_bytes).expect("failed to deserialise Bn256 scalar")
+            })
+            .collect();
+        let session = Fr::from_bytes(&hex_to_le_bytes(&self.session))
+            .expect("failed to deserialise Bn256 scalar");
 
         DkgMemberPublicParamsCurve {
             public_shares,
             ciphers,
+            tags,
+            context: DealerContext::new(SessionId::new(session), self.dealer_index),
             gr: (&self.gr).into(),
             ga: (&self.ga).into(),
             g2a: (&self.g2a).into(),
+            #[cfg(feature = "g2shares")]
+            public_shares2: self.public_shares2.iter().map(|s| s.into()).collect(),
         }
     }
 }
@@ -340,6 +369,8 @@ pub struct DkgGlobalPubParams {
     pub ga: Point,
     pub g2a: Point2,
     pub verify_keys: Vec<Point>,
+    #[cfg(feature = "g2shares")]
+    pub verify_keys2: Vec<Point2>,
 }
 
 impl From<DkgGlobalPubParamsCurve> for DkgGlobalPubParams {
@@ -356,6 +387,8 @@ impl From<&DkgGlobalPubParamsCurve> for DkgGlobalPubParams {
             ga: gpp.ga.into(),
             g2a: gpp.g2a.into(),
             verify_keys,
+            #[cfg(feature = "g2shares")]
+            verify_keys2: gpp.verify_keys2.iter().map(|vk| vk.into()).collect(),
         }
     }
 }
@@ -374,6 +407,8 @@ impl Into<DkgGlobalPubParamsCurve> for &DkgGlobalPubParams {
             ga: (&self.ga).into(),
             g2a: (&self.g2a).into(),
             verify_keys,
+            #[cfg(feature = "g2shares")]
+            verify_keys2: self.verify_keys2.iter().map(|vk| vk.into()).collect(),
         }
     }
 }
@@ -25,6 +25,11 @@ interface IPseudoRand {
         bytes memory message
     ) external returns (Pairing.G1Point memory);
 
+    function hashToG1InSession(
+        bytes memory message,
+        uint sessionId
+    ) external returns (Pairing.G1Point memory);
+
     function verifyPartialEvalFast(
         Pairing.G1Point memory h,
         Pairing.G1Point memory sigma,
@@ -32,6 +37,14 @@ interface IPseudoRand {
         Pairing.G1Point memory vk
     ) external returns (bool);
 
+    function verifyPartialEvalFastInSession(
+        Pairing.G1Point memory h,
+        Pairing.G1Point memory sigma,
+        PartialEvalProof memory proof,
+        Pairing.G1Point memory vk,
+        uint sessionId
+    ) external returns (bool);
+
     function verifyPartialEval(
         bytes memory message,
         Pairing.G1Point memory sigma,
@@ -39,6 +52,14 @@ interface IPseudoRand {
         Pairing.G1Point memory vk
     ) external returns (bool);
 
+    function verifyPartialEvalInSession(
+        bytes memory message,
+        uint sessionId,
+        Pairing.G1Point memory sigma,
+        PartialEvalProof memory proof,
+        Pairing.G1Point memory vk
+    ) external returns (bool);
+
     function verifyPseudoRandFast(
         Pairing.G1Point memory h,
         Pairing.G1Point memory sigma,
@@ -51,4 +72,11 @@ interface IPseudoRand {
         Pairing.G2Point memory gpk
     ) external returns (bool);
 
+    function verifyPseudoRandInSession(
+        bytes memory message,
+        uint sessionId,
+        Pairing.G1Point memory sigma,
+        Pairing.G2Point memory gpk
+    ) external returns (bool);
+
 }
\ No newline at end of file
@@ -5,15 +5,30 @@ import {Pairing} from "./libs/Pairing.sol";
 import {Hash} from "./libs/Hash.sol";
 import {IPseudoRand} from "./IPseudoRand.sol";
 
+import "@openzeppelin/contracts/utils/Strings.sol";
+
 contract PseudoRand is IPseudoRand{
     using Pairing for *;
     using Hash for *;
+    using Strings for uint256;
 
     bytes public constant DOMAIN = bytes("DVRF pseudorandom generation 2023");
     uint public constant R = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
 
+    // session 0 keeps the original domain
+    function domain(uint sessionId) public pure returns (bytes memory) {
+        if (sessionId == 0) {
+            return DOMAIN;
+        }
+        return abi.encodePacked(DOMAIN, " ", sessionId.toHexString(32));
+    }
+
     function hashToG1(bytes memory message) public view returns (Pairing.G1Point memory) {
-        Pairing.G1Point memory h = Hash.hashToG1(DOMAIN, message);
+        return hashToG1InSession(message, 0);
+    }
+
+    function hashToG1InSession(bytes memory message, uint sessionId) public view virtual returns (Pairing.G1Point memory) {
+        Pairing.G1Point memory h = Hash.hashToG1(domain(sessionId), message);
         return h;
     }
 
@@ -23,6 +38,17 @@ contract PseudoRand is IPseudoRand{
         Pairing.G1Point memory sigma,
         PartialEvalProof memory proof,
         Pairing.G1Point memory vk
+    ) public view returns (bool)  {
+        return verifyPartialEvalFastInSession(h, sigma, proof, vk, 0);
+    }
+
+    // the session is appended to the challenge input unless it is 0
+    function verifyPartialEvalFastInSession(
+        Pairing.G1Point memory h,
+        Pairing.G1Point memory sigma,
+        PartialEvalProof memory proof,
+        Pairing.G1Point memory vk,
+        uint sessionId
     ) public view returns (bool)  {
         Pairing.G1Point memory g = Pairing.P1();
 
@@ -36,6 +62,9 @@ contract PseudoRand is IPseudoRand{
 
         bytes memory input = abi.encodePacked(g.x, g.y, h.x, h.y, r1.x, r1.y,
             r2.x, r2.y,vk.x, vk.y, sigma.x, sigma.y);
+        if (sessionId != 0) {
+            input = abi.encodePacked(input, sessionId);
+        }
         bytes32 hash = keccak256(input);
 
         uint cc = uint(hash) % R;
@@ -48,23 +77,18 @@ contract PseudoRand is IPseudoRand{
         PartialEvalProof memory proof,
         Pairing.G1Point memory vk
     ) public view returns (bool)  {
-        Pairing.G1Point memory h = Hash.hashToG1(DOMAIN, message);
-        Pairing.G1Point memory g = Pairing.P1();
-
-        Pairing.G1Point memory gz = Pairing.scalar_mul(g, proof.z);
-        Pairing.G1Point memory vkc = Pairing.scalar_mul(vk, proof.c);
-        Pairing.G1Point memory r1 = Pairing.addition(gz, vkc.negate());
-
-        Pairing.G1Point memory hz = Pairing.scalar_mul(h, proof.z);
-        Pairing.G1Point memory sc = Pairing.scalar_mul(sigma, proof.c);
-        Pairing.G1Point memory r2 = Pairing.addition(hz, sc.negate());
-
-        bytes memory input = abi.encodePacked(g.x, g.y, h.x, h.y, r1.x, r1.y,
-            r2.x, r2.y,vk.x, vk.y, sigma.x, sigma.y);
-        bytes32 hash = keccak256(input);
+        return verifyPartialEvalInSession(message, 0, sigma, proof, vk);
+    }
 
-        uint cc = uint(hash) % R;
-        return cc == proof.c;
+    function verifyPartialEvalInSession(
+        bytes memory message,
+        uint sessionId,
+        Pairing.G1Point memory sigma,
+        PartialEvalProof memory proof,
+        Pairing.G1Point memory vk
+    ) public view returns (bool)  {
+        Pairing.G1Point memory h = hashToG1InSession(message, sessionId);
+        return verifyPartialEvalFastInSession(h, sigma, proof, vk, sessionId);
     }
 
     function verifyPseudoRandFast(Pairing.G1Point memory h, Pairing.G1Point memory sigma, Pairing.G2Point memory gpk) public view returns (bool) {
@@ -75,10 +99,11 @@ contract PseudoRand is IPseudoRand{
     }
 
     function verifyPseudoRand(bytes memory message, Pairing.G1Point memory sigma, Pairing.G2Point memory gpk) public view returns (bool) {
-        Pairing.G1Point memory h = Hash.hashToG1(DOMAIN, message);
-        h = h.negate();
-        Pairing.G2Point memory g2 = Pairing.P2();
+        return verifyPseudoRandInSession(message, 0, sigma, gpk);
+    }
 
-        return Pairing.pairingProd2(h, gpk, sigma, g2);
+    function verifyPseudoRandInSession(bytes memory message, uint sessionId, Pairing.G1Point memory sigma, Pairing.G2Point memory gpk) public view returns (bool) {
+        Pairing.G1Point memory h = hashToG1InSession(message, sessionId);
+        return verifyPseudoRandFast(h, sigma, gpk);
     }
-}
\ No newline at end of file
+}
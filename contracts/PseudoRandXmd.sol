@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import {Pairing} from "./libs/Pairing.sol";
+import {Hash} from "./libs/Hash.sol";
+import {PseudoRand} from "./PseudoRand.sol";
+
+// PseudoRand with an rfc9380 hash to curve suite fixed at deployment,
+// matching HashSuite::Sha256 or HashSuite::Keccak256 in the client
+contract PseudoRandXmd is PseudoRand {
+    uint8 public immutable hashSuite;
+
+    constructor(uint8 suite) {
+        require(suite == Hash.SUITE_SHA256 || suite == Hash.SUITE_KECCAK256, "Unknown hash suite");
+        hashSuite = suite;
+    }
+
+    function suiteId() public view returns (bytes memory) {
+        if (hashSuite == Hash.SUITE_SHA256) {
+            return bytes("BN254G1_XMD:SHA-256_SVDW_RO_");
+        }
+        return bytes("BN254G1_XMD:KECCAK-256_SVDW_RO_");
+    }
+
+    // the domain tagged with the suite id
+    function dst(uint sessionId) public view returns (bytes memory) {
+        return abi.encodePacked(domain(sessionId), "-with-", suiteId());
+    }
+
+    function hashToG1InSession(bytes memory message, uint sessionId) public view override returns (Pairing.G1Point memory) {
+        return Hash.hashToG1Xmd(hashSuite, dst(sessionId), message);
+    }
+}
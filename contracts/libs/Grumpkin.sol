@@ -2,6 +2,32 @@
 pragma solidity ^0.8.0;
 
 library Grumpkin {
+    // BN254 scalar field, the base field of grumpkin
+    uint public constant P = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
+    // (p-1)/2
+    uint public constant P2 = 10944121435919637611123202872628637544274182200208017171849102093287904247808;
+    // 2^256 mod p
+    uint public constant R = 6350874878119819312338956282401532410528162663560392320966563075034087161851;
+    // b = -17
+    uint public constant B = 21888242871839275222246405745257275088548364400416034343698204186575808495600;
+    // (-1 + sqrt(-3))/2
+    uint public constant C1 = 4407920970296243842393367215006156084916469457145843978461;
+    // sqrt(-3)
+    uint public constant C2 = 8815841940592487684786734430012312169832938914291687956923;
+    // 1/3
+    uint public constant C3 = 14592161914559516814830937163504850059032242933610689562465469457717205663745;
+    // generator (1, sqrt(-16))
+    uint public constant G_X = 1;
+    uint public constant G_Y = 17631683881184975370165255887551781615748388533673675138860;
+
+    // p - 1 = 2^S * Q_ODD
+    uint constant S = 28;
+    uint constant Q_ODD = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f;
+    // (Q_ODD + 1)/2
+    uint constant Q_ODD_HALF = 0x183227397098d014dc2822db40c0ac2e9419f4243cdcb848a1f0faca0;
+    // 5^Q_ODD, 5 is a quadratic non-residue
+    uint constant Z_Q = 0x2a3c09f0a58a7e8500e0a7eb8ef62abc402d111e41112ed49bd61b6e725b19f0;
+
     struct Point {
         uint x;
         uint y;
@@ -21,4 +47,162 @@ library Grumpkin {
 
         return z == x3;
     }
+
+    function expMod(uint base, uint e) internal view returns (uint) {
+        uint[6] memory input = [uint(32), 32, 32, base, e, P];
+        uint[1] memory output;
+        bool success;
+        // solium-disable-next-line security/no-inline-assembly
+        assembly {
+            success := staticcall(gas(), 0x05, input, 0xc0, output, 0x20)
+        }
+        require(success, "modexp failed");
+        return output[0];
+    }
+
+    // tonelli-shanks, p = 1 mod 4 so the root is not unique
+    function sqrt(uint a) internal view returns (uint r, bool exist) {
+        if (a == 0) {
+            return (0, true);
+        }
+
+        uint m = S;
+        uint c = Z_Q;
+        uint t = expMod(a, Q_ODD);
+        r = expMod(a, Q_ODD_HALF);
+        while (t != 1) {
+            uint i = 0;
+            uint tt = t;
+            while (tt != 1) {
+                tt = mulmod(tt, tt, P);
+                i++;
+                // a is not a square
+                if (i == m) {
+                    return (0, false);
+                }
+            }
+
+            uint b = c;
+            for (uint j = 0; j < m - i - 1; j++) {
+                b = mulmod(b, b, P);
+            }
+            m = i;
+            c = mulmod(b, b, P);
+            t = mulmod(t, c, P);
+            r = mulmod(r, b, P);
+        }
+        exist = true;
+    }
+
+    // identity is encoded as (0, 0)
+    function add(Point memory a, Point memory b) internal view returns (Point memory) {
+        if (a.x == 0 && a.y == 0) {
+            return b;
+        }
+        if (b.x == 0 && b.y == 0) {
+            return a;
+        }
+
+        uint lambda;
+        if (a.x == b.x) {
+            if (addmod(a.y, b.y, P) == 0) {
+                return Point(0, 0);
+            }
+            // lambda = 3x^2/2y
+            uint num = mulmod(3, mulmod(a.x, a.x, P), P);
+            lambda = mulmod(num, expMod(mulmod(2, a.y, P), P - 2), P);
+        } else {
+            // lambda = (y2 - y1)/(x2 - x1)
+            uint num = addmod(b.y, P - a.y, P);
+            lambda = mulmod(num, expMod(addmod(b.x, P - a.x, P), P - 2), P);
+        }
+
+        uint x = addmod(mulmod(lambda, lambda, P), P - addmod(a.x, b.x, P), P);
+        uint y = addmod(mulmod(lambda, addmod(a.x, P - x, P), P), P - a.y, P);
+        return Point(x, y);
+    }
+
+    // same as Hash.hashToField but reduced modulo p
+    function hashToField(bytes memory domain, bytes memory message) internal view returns (uint[2] memory) {
+        uint domain_len = domain.length;
+        uint msg_len = message.length;
+
+        bytes memory input = abi.encodePacked(bytes1(0x00), bytes1(0x01), domain_len, domain, msg_len, message);
+        bytes32 hash0 = keccak256(input);
+        bytes32 hash1 = keccak256(abi.encodePacked(bytes1(0x02), bytes1(0x03), hash0));
+        bytes32 hash2 = keccak256(abi.encodePacked(bytes1(0x04), bytes1(0x05), hash1));
+        bytes32 hash3 = keccak256(abi.encodePacked(bytes1(0x06), bytes1(0x07), hash2));
+
+        uint f1 = mulmod(uint(hash0), R, P);
+        f1 = addmod(f1, uint(hash1), P);
+
+        uint f2 = mulmod(uint(hash2), R, P);
+        f2 = addmod(f2, uint(hash3), P);
+
+        return [f1, f2];
+    }
+
+    // y^2 = x^3 + b, with y set to the root that has the same sign as t
+    function tryPoint(uint x, uint t) internal view returns (Point memory, bool) {
+        uint a = mulmod(x, x, P);
+        a = mulmod(a, x, P);
+        a = addmod(a, B, P);
+        (uint y, bool exist) = sqrt(a);
+        if ((y <= P2) != (t <= P2)) {
+            y = (P - y) % P;
+        }
+        return (Point(x, y), exist);
+    }
+
+    // Fouque and Tibouchi map as in Hash.mapToG1
+    function mapToCurve(uint t) internal view returns (Point memory) {
+        require(t < P, "mapToCurve failed: invalid field element");
+        // s = (t^2 + 1 + b)^3
+        // alpha = 1/(t^2 * (t^2 + 1 + b))
+        // x1 = C1 - C2 * t^4 * alpha
+        // x2 = -1 - x1
+        // x3 = 1 - s * alpha/3
+        uint t_square = mulmod(t, t, P);
+        uint t4 = mulmod(t_square, t_square, P);
+        uint r = addmod(t_square, addmod(1, B, P), P);
+        uint s = mulmod(r, mulmod(r, r, P), P);
+        // 0 is mapped to 0
+        uint alpha = expMod(mulmod(t_square, r, P), P - 2);
+
+        uint x1 = mulmod(t4, alpha, P);
+        x1 = mulmod(C2, x1, P);
+        x1 = addmod(C1, P - x1, P);
+        (Point memory p1, bool e1) = tryPoint(x1, t);
+        if (e1) {
+            return p1;
+        }
+
+        uint x2 = (P - addmod(x1, 1, P)) % P;
+        (Point memory p2, bool e2) = tryPoint(x2, t);
+        if (e2) {
+            return p2;
+        }
+
+        uint x3 = mulmod(s, alpha, P);
+        x3 = mulmod(x3, C3, P);
+        x3 = addmod(P - x3, 1, P);
+        (Point memory p3, bool e3) = tryPoint(x3, t);
+        require(e3, "map to point failed");
+        return p3;
+    }
+
+    // keccak based hash to grumpkin, matches hash_to_curve_grumpkin_evm in rust.
+    // test vectors:
+    // hashToCurve("DVRF pseudorandom generation 2023", "hello world") =
+    //   (0x28ed2615657a4d525e56135c76b5d5f9492ee16744fdb12c7d9f36673f6c3642,
+    //    0x2f3d2b9c50f15fb2e020985b5818e32a9d66d2407155e703132be9ab395bf2a6)
+    // hashToCurve("another generator", "") =
+    //   (0x1dccb3657213352d6b75b8f5cc21a73179baa3dc11ef9d08835e1c4886964b4a,
+    //    0x0368a122beb95b21f170e0941b0b18f9606f0f48e2b85464cc2493039139f018)
+    function hashToCurve(bytes memory domain, bytes memory message) internal view returns (Point memory) {
+        uint[2] memory f = hashToField(domain, message);
+        Point memory g = mapToCurve(f[0]);
+        Point memory h = mapToCurve(f[1]);
+        return add(g, h);
+    }
 }
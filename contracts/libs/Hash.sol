@@ -21,6 +21,15 @@ library Hash {
     // g(1) = 1^3 + b
    // uint public constant C4 = 4;
 
+    // hash suites of rfc9380 with expand_message_xmd and the svdw map
+    uint8 public constant SUITE_SHA256 = 1;
+    uint8 public constant SUITE_KECCAK256 = 2;
+    // svdw constants with Z = 1
+    // sqrt(-g(Z) * 3Z^2) = sqrt(-12) with sgn0 = 0
+    uint public constant SVDW_C3 = 8815841940592487685674414971303048083897117035520822607866;
+    // -4g(Z)/(3Z^2) = -16/3
+    uint public constant SVDW_C4 = 7296080957279758407415468581752425029565437052432607887563012631548408736189;
+
     function sqrt(uint a) internal view returns (uint r, bool exist) {
         r = ModexpSqrt.run(a);
         exist = mulmod(r, r, Q) == a;
@@ -141,4 +150,94 @@ library Hash {
         g = Pairing.addition(g, h);
         return g;
     }
-}
\ No newline at end of file
+
+    function xmdHash(uint8 suite, bytes memory input) internal pure returns (bytes32) {
+        if (suite == SUITE_SHA256) {
+            return sha256(input);
+        }
+        return keccak256(input);
+    }
+
+    // expand_message_xmd of rfc9380 section 5.3.1 with len_in_bytes = 96
+    function expandMessageXmd(uint8 suite, bytes memory dst, bytes memory message) internal pure returns (bytes32[3] memory b) {
+        if (dst.length > 255) {
+            dst = abi.encodePacked(xmdHash(suite, abi.encodePacked("H2C-OVERSIZE-DST-", dst)));
+        }
+        bytes memory dstPrime = abi.encodePacked(dst, uint8(dst.length));
+        // block size of the hash
+        uint rInBytes = suite == SUITE_SHA256 ? 64 : 136;
+
+        bytes32 b0 = xmdHash(suite, abi.encodePacked(new bytes(rInBytes), message, uint16(96), uint8(0), dstPrime));
+        b[0] = xmdHash(suite, abi.encodePacked(b0, uint8(1), dstPrime));
+        b[1] = xmdHash(suite, abi.encodePacked(b0 ^ b[0], uint8(2), dstPrime));
+        b[2] = xmdHash(suite, abi.encodePacked(b0 ^ b[1], uint8(3), dstPrime));
+    }
+
+    // two field elements of 48 bytes each: b0 || b1[0..16] and b1[16..32] || b2
+    function hashToFieldXmd(uint8 suite, bytes memory dst, bytes memory message) internal pure returns (uint[2] memory) {
+        bytes32[3] memory b = expandMessageXmd(suite, dst, message);
+
+        uint f1 = mulmod(uint(b[0]), 1 << 128, Q);
+        f1 = addmod(f1, uint(b[1]) >> 128, Q);
+
+        uint f2 = mulmod(uint(b[1]) & ((1 << 128) - 1), R, Q);
+        f2 = addmod(f2, uint(b[2]), Q);
+
+        return [f1, f2];
+    }
+
+    // y^2 = x^3 + 3 with sgn0(y) = sgn0(u)
+    function trySvdwPoint(uint x, uint u) internal view returns (Pairing.G1Point memory, bool) {
+        uint a = mulmod(x, x, Q);
+        a = mulmod(a, x, Q);
+        a = addmod(a, 3, Q);
+        (uint y, bool exist) = sqrt(a);
+        if ((y & 1) != (u & 1)) {
+            y = (Q - y) % Q;
+        }
+        return (Pairing.G1Point(x, y), exist);
+    }
+
+    // shallue-van de woestijne map with Z = 1 (rfc9380 section 6.6.1)
+    function mapToG1Svdw(uint u) internal view returns (Pairing.G1Point memory) {
+        require(u < Q, "mapToG1Svdw failed: invalid field element");
+        // tv1 = 1 - 4u^2, tv2 = 1 + 4u^2, tv3 = 1/(tv1 * tv2)
+        // tv4 = u * tv1 * tv3 * C3
+        // x1 = (q-1)/2 - tv4
+        // x2 = (q-1)/2 + tv4
+        // x3 = 1 + C4 * (tv2^2 * tv3)^2
+        uint tv1 = mulmod(mulmod(u, u, Q), 4, Q);
+        uint tv2 = addmod(1, tv1, Q);
+        tv1 = addmod(1, Q - tv1, Q);
+        // 0 is mapped to 0
+        uint tv3 = ModexpInverse.run(mulmod(tv1, tv2, Q));
+        uint tv4 = mulmod(mulmod(mulmod(u, tv1, Q), tv3, Q), SVDW_C3, Q);
+
+        uint x1 = addmod(Q2, Q - tv4, Q);
+        (Pairing.G1Point memory p1, bool e1) = trySvdwPoint(x1, u);
+        if (e1) {
+            return p1;
+        }
+
+        uint x2 = addmod(Q2, tv4, Q);
+        (Pairing.G1Point memory p2, bool e2) = trySvdwPoint(x2, u);
+        if (e2) {
+            return p2;
+        }
+
+        uint x3 = mulmod(mulmod(tv2, tv2, Q), tv3, Q);
+        x3 = mulmod(mulmod(x3, x3, Q), SVDW_C4, Q);
+        x3 = addmod(x3, 1, Q);
+        (Pairing.G1Point memory p3, bool e3) = trySvdwPoint(x3, u);
+        require(e3, "map to point failed");
+        return p3;
+    }
+
+    // rfc9380 hash to curve, matches HashSuite::hash_to_curve in rust
+    function hashToG1Xmd(uint8 suite, bytes memory dst, bytes memory message) internal view returns (Pairing.G1Point memory) {
+        uint[2] memory f = hashToFieldXmd(suite, dst, message);
+        Pairing.G1Point memory g = mapToG1Svdw(f[0]);
+        Pairing.G1Point memory h = mapToG1Svdw(f[1]);
+        return Pairing.addition(g, h);
+    }
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import {Grumpkin} from "../libs/Grumpkin.sol";
+
+// exposes the internal functions of the Grumpkin library to the contract tests
+contract GrumpkinHarness {
+    function isOnCurve(uint x, uint y) external view returns (bool) {
+        return Grumpkin.isOnCurve(Grumpkin.Point(x, y));
+    }
+
+    function add(uint ax, uint ay, uint bx, uint by) external view returns (uint x, uint y) {
+        Grumpkin.Point memory p = Grumpkin.add(Grumpkin.Point(ax, ay), Grumpkin.Point(bx, by));
+        return (p.x, p.y);
+    }
+
+    function mapToCurve(uint t) external view returns (uint x, uint y) {
+        Grumpkin.Point memory p = Grumpkin.mapToCurve(t);
+        return (p.x, p.y);
+    }
+
+    function hashToCurve(bytes memory domain, bytes memory message) external view returns (uint x, uint y) {
+        Grumpkin.Point memory p = Grumpkin.hashToCurve(domain, message);
+        return (p.x, p.y);
+    }
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import {Pairing} from "../libs/Pairing.sol";
+import {Hash} from "../libs/Hash.sol";
+
+// exposes the internal xmd functions of the Hash library to the contract tests
+contract HashHarness {
+    function expandMessageXmd(uint8 suite, bytes memory dst, bytes memory message) external pure returns (bytes32[3] memory) {
+        return Hash.expandMessageXmd(suite, dst, message);
+    }
+
+    function hashToFieldXmd(uint8 suite, bytes memory dst, bytes memory message) external pure returns (uint[2] memory) {
+        return Hash.hashToFieldXmd(suite, dst, message);
+    }
+
+    function mapToG1Svdw(uint u) external view returns (uint x, uint y) {
+        Pairing.G1Point memory p = Hash.mapToG1Svdw(u);
+        return (p.x, p.y);
+    }
+
+    function hashToG1Xmd(uint8 suite, bytes memory dst, bytes memory message) external view returns (uint x, uint y) {
+        Pairing.G1Point memory p = Hash.hashToG1Xmd(suite, dst, message);
+        return (p.x, p.y);
+    }
+}
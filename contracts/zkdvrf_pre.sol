@@ -43,6 +43,11 @@ contract zkdvrf_pre is Ownable {
 
     uint32 public memberCount;
     uint32 public threshold;
+    // slots of the dkg circuit, larger than (threshold, memberCount) for a padded circuit
+    uint32 public maxThreshold;
+    uint32 public maxNumberOfMembers;
+    // number of selector bits at the end of pp, 0 if the circuit is not padded
+    uint32 public selectorCount;
     uint32 public ppLength;
     // current count of members added
     uint32 public currentIndex;
@@ -51,6 +56,8 @@ contract zkdvrf_pre is Ownable {
     uint32 internal ppSubmissionCount;
 
     uint256 public currentRoundNum;
+    // binds dkg proofs and evaluations to this deployment; 0 leaves them unbound
+    uint256 public sessionId;
     uint256 public minNodeDeposit;
 
     uint32 public pkListIndex;
@@ -82,7 +89,9 @@ contract zkdvrf_pre is Ownable {
         require(halo2VerifierAddress != address(0) && globalPublicParamsAddress != address(0) && pseudoRandAddress != address(0), "Cannot be zero addresses");
         memberCount = numberValue;
         threshold = thresholdValue;
-        ppLength = 7 * memberCount + 14;
+        maxThreshold = thresholdValue;
+        maxNumberOfMembers = numberValue;
+        ppLength = 8 * maxNumberOfMembers + 16;
         halo2Verifier = halo2VerifierAddress;
         halo2VerifyingKey = halo2VerifyingKeyAddress;
         globalPublicParams = globalPublicParamsAddress;
@@ -124,6 +133,25 @@ contract zkdvrf_pre is Ownable {
         }
     }
 
+    // owner sets the session id before nidkg starts; it must be a scalar of bn254
+    function setSessionId(uint256 id) public onlyOwner {
+        require(contractPhase == Status.Unregistered || contractPhase == Status.Registered, "NIDKG has already been started");
+        require(id < 21888242871839275222246405745257275088548364400416034343698204186575808495617, "Invalid session id");
+        sessionId = id;
+    }
+
+    // owner sets the slots of a padded dkg circuit before nidkg starts.
+    // pp then holds maxNumberOfMembers slots per member field, followed by
+    // maxThreshold coefficient selector bits and maxNumberOfMembers member selector bits
+    function setPadding(uint32 maxThresholdValue, uint32 maxNumberValue) public onlyOwner {
+        require(contractPhase == Status.Unregistered || contractPhase == Status.Registered, "NIDKG has already been started");
+        require(threshold <= maxThresholdValue && memberCount <= maxNumberValue && maxThresholdValue <= maxNumberValue, "Invalid padding");
+        maxThreshold = maxThresholdValue;
+        maxNumberOfMembers = maxNumberValue;
+        selectorCount = maxThresholdValue + maxNumberValue;
+        ppLength = 8 * maxNumberOfMembers + 16 + selectorCount;
+    }
+
     // owner starts nidkg protocol
     // can't add members after this process
     function startNidkg() public onlyOwner {
@@ -140,6 +168,10 @@ contract zkdvrf_pre is Ownable {
         require(contractPhase == Status.Nidkg, "Contract not in NIDKG phase");
         require(!addrToNode[msg.sender].statusPP, "Node already submitted");
         require(checkPublicParams(pp), "Invalid public parameters");
+        // the ciphers in pp are bound to the dealer index placed before the session and public keys
+        uint pkOffset = publicKeyOffset();
+        require(pp[pkOffset - 2] == getIndexPlus(msg.sender), "Wrong dealer index");
+        require(pp[pkOffset - 1] == sessionId, "Wrong session id");
         require(Halo2Verifier(halo2Verifier).verifyProof(halo2VerifyingKey, zkProof, pp), "SNARK proof verification failed");
 
         addrToNode[msg.sender].statusPP = true;
@@ -176,7 +208,7 @@ contract zkdvrf_pre is Ownable {
         currentRoundNum++;
         bytes memory input = abi.encodePacked(INPUT_PREFIX, currentRoundNum.toString());
         roundInput[currentRoundNum] = string(input);
-        roundHash[currentRoundNum] = IPseudoRand(pseudoRand).hashToG1(input);
+        roundHash[currentRoundNum] = IPseudoRand(pseudoRand).hashToG1InSession(input, sessionId);
 
         emit RandomInitiated(currentRoundNum, roundInput[currentRoundNum]);
     }
@@ -192,7 +224,7 @@ contract zkdvrf_pre is Ownable {
         uint32 pkIndex = addrToNode[msg.sender].pkIndex;
         require(pEval.indexPlus == pkIndex + 1);
         Pairing.G1Point memory vkStored = vkList[pkIndex];
-        require(IPseudoRand(pseudoRand).verifyPartialEvalFast(roundHash[currentRoundNum], pEval.value, pEval.proof, vkStored), "Verification of partial eval failed");
+        require(IPseudoRand(pseudoRand).verifyPartialEvalFastInSession(roundHash[currentRoundNum], pEval.value, pEval.proof, vkStored, sessionId), "Verification of partial eval failed");
         lastSubmittedRound[msg.sender] = currentRoundNum;
         roundToEval[currentRoundNum][pkIndex] = pEval;
         roundSubmissionCount[currentRoundNum]++;
@@ -242,20 +274,33 @@ contract zkdvrf_pre is Ownable {
             return false;
         }
 
-        // check if the last 2n elements in pp are public keys
-        uint j = pp.length - 2 * memberCount;
-        for (uint i = 0; i < memberCount; i++) {
-            require(pp[j] == pkList[i].x, "Wrong public key x");
-            require(pp[j + 1] == pkList[i].y, "Wrong public key y");
-            if (pp[j] != pkList[i].x || pp[j + 1] != pkList[i].y) {
+        // check if the 2 * maxNumberOfMembers elements before the selector bits are public keys,
+        // the public keys of inactive members are the grumpkin generator
+        uint j = publicKeyOffset();
+        for (uint i = 0; i < maxNumberOfMembers; i++) {
+            Grumpkin.Point memory pk = i < memberCount ? pkList[i] : Grumpkin.Point(Grumpkin.G_X, Grumpkin.G_Y);
+            require(pp[j] == pk.x, "Wrong public key x");
+            require(pp[j + 1] == pk.y, "Wrong public key y");
+            if (pp[j] != pk.x || pp[j + 1] != pk.y) {
                 return false;
             }
             j = j + 2;
         }
 
+        // selector bits are 1 for the first threshold coefficients and the first memberCount members
+        for (uint i = 0; i < selectorCount; i++) {
+            bool active = i < maxThreshold ? i < threshold : i - maxThreshold < memberCount;
+            require(pp[j + i] == (active ? 1 : 0), "Wrong selector bit");
+        }
+
         return true;
     }
 
+    // index of the first public key in pp; the dealer index and session id are right before it
+    function publicKeyOffset() public view returns (uint) {
+        return ppLength - selectorCount - 2 * maxNumberOfMembers;
+    }
+
     function getIndexPlus(address nodeAdress) public view returns (uint32) {
         uint32 pkIndex = addrToNode[nodeAdress].pkIndex;
         return pkIndex + 1;
@@ -12,7 +12,9 @@ use rand_core::{OsRng, RngCore};
 use std::fs::{create_dir_all, File};
 use std::io::Write;
 use zkrand::dkg::DkgConfig;
-use zkrand::{load_or_create_params, load_or_create_pk, DkgMemberParams, MemberKey};
+use zkrand::{
+    load_or_create_params, load_or_create_pk, DkgCircuitParams, DkgMemberParams, MemberKey,
+};
 
 const DIR_GENERATED: &str = "./demo/contracts_generated/separate";
 
@@ -89,7 +91,7 @@ fn main() {
 
     let dkg_config = DkgConfig::new(threshold, number_of_members).unwrap();
     let (mpks, _) = mock_members(&dkg_config, &mut rng);
-    let dkg_params = DkgMemberParams::new(dkg_config, mpks, &mut rng).unwrap();
+    let dkg_params = DkgMemberParams::new(dkg_config, 1, mpks, &mut rng).unwrap();
     let circuit = dkg_params.circuit(&mut rng);
     let instance = dkg_params.instance();
     let num_instances = instance[0].len();
@@ -101,7 +103,14 @@ fn main() {
     end_timer!(start);
 
     let start = start_timer!(|| format!("kzg load or setup proving keys with degree {}", degree));
-    let pk = load_or_create_pk(dkg_config, params_dir, &general_params, degree).unwrap();
+    let pk = load_or_create_pk(
+        dkg_config,
+        DkgCircuitParams::default(),
+        params_dir,
+        &general_params,
+        degree,
+    )
+    .unwrap();
     let vk = pk.get_vk();
     end_timer!(start);
 
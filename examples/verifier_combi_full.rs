@@ -17,7 +17,8 @@ use std::io::Write;
 use zkrand::dkg::{DkgConfig, PartialEval};
 use zkrand::{
     combine_partial_evaluations, dkg_global_public_params, hash_to_curve_bn, load_or_create_params,
-    load_or_create_pk, DkgGlobalPubParams, DkgMemberParams, MemberKey, PseudoRandom, EVAL_PREFIX,
+    load_or_create_pk, DkgCircuitParams, DkgGlobalPubParams, DkgMemberParams, MemberKey,
+    PseudoRandom, EVAL_PREFIX,
 };
 
 // cargo run --release --features="g2chip" --example verifier_combi_full
@@ -199,7 +200,7 @@ fn main() {
     let (pks, members) = mock_members(&dkg_config, &mut rng);
     // member index from 1..n
     let dkgs: Vec<_> = (0..number_of_members)
-        .map(|_| DkgMemberParams::new(dkg_config, pks.clone(), &mut rng).unwrap())
+        .map(|i| DkgMemberParams::new(dkg_config, i + 1, pks.clone(), &mut rng).unwrap())
         .collect();
     let dkgs_pub: Vec<_> = dkgs.iter().map(|dkg| dkg.member_public_params()).collect();
 
@@ -225,7 +226,14 @@ fn main() {
 
         let start =
             start_timer!(|| format!("kzg load or setup proving keys with degree {}", degree));
-        let pk = load_or_create_pk(dkg_config, params_dir, &general_params, degree).unwrap();
+        let pk = load_or_create_pk(
+            dkg_config,
+            DkgCircuitParams::default(),
+            params_dir,
+            &general_params,
+            degree,
+        )
+        .unwrap();
         let vk = pk.get_vk();
         end_timer!(start);
 
@@ -0,0 +1,54 @@
+#!/bin/bash
+# Regenerate the verifier contracts and the contract test fixtures from a fresh NIDKG run.
+# Needs the kzg params from download_params.sh. Run from the repository root:
+#   sh download_params.sh && bash scripts/fixtures.sh
+set -uex
+
+input="zkRand-v1-2024:1"
+client=./target/release/client
+
+cargo build --release
+
+mkdir -p data/members data/dkg/proofs data/dkg/secrets data/dkg/shares data/random test/fixtures
+
+export THRESHOLD=3
+export NUMBER_OF_MEMBERS=5
+export DEGREE=18
+unset MAX_THRESHOLD MAX_NUMBER_OF_MEMBERS WINDOW_SIZE GRUMPKIN_WINDOW_SIZE CANONICAL_RANDOM SESSION_ID HASH_SUITE
+
+# contracts/Halo2Verifier-3-5-g2.sol, then contracts/Halo2Verifier.sol and contracts/Halo2VerifyingKey-3-5-18-g2.sol
+$client setup
+mv contracts/Halo2Verifier-3-5-18-g2.sol contracts/Halo2Verifier-3-5-g2.sol
+$client setup --split
+
+# member keys, one proven dealing per member, the shares and a pseudorandom for the first round
+$client mock -m
+for i in $(seq 1 $NUMBER_OF_MEMBERS); do
+    $client dkg prove "$i"
+    $client dkg verify "$i"
+done
+node -e '
+const fs = require("fs")
+const n = Number(process.env.NUMBER_OF_MEMBERS)
+const instances = []
+for (let i = 1; i <= n; i++) {
+    instances.push(JSON.parse(fs.readFileSync(`data/dkg/proofs/instance_${i}.json`)))
+}
+fs.writeFileSync("data/dkg/all_instances.json", JSON.stringify(instances))
+'
+for i in $(seq 1 $NUMBER_OF_MEMBERS); do
+    $client dkg derive "$i" -f "member_$i"
+    $client rand eval "$i" "$input"
+done
+$client rand combine "$input"
+npx ts-node scripts/fixtures.ts test/fixtures/zkdvrf-3-5.json "contracts/Halo2VerifyingKey-3-5-18-g2.sol" "$input"
+
+# the same members proven with a padded (4, 7) circuit; DEGREE is measured by the client
+unset DEGREE
+export MAX_THRESHOLD=4
+export MAX_NUMBER_OF_MEMBERS=7
+rm -f contracts/Halo2VerifyingKey-padded-4-7-*-g2.sol data/dkg/proofs/*
+$client setup --split
+$client dkg prove 1
+$client dkg verify 1
+npx ts-node scripts/fixtures.ts test/fixtures/zkdvrf-padded-4-7.json "$(ls contracts/Halo2VerifyingKey-padded-4-7-*-g2.sol)"
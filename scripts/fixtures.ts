@@ -0,0 +1,65 @@
+import fs from "fs";
+
+// collect the client outputs in ./data into one fixture file for the contract tests
+// usage: npx ts-node scripts/fixtures.ts <output> <verifying key contract> [input]
+// proofs and instances are read for every member that has one in ./data/dkg/proofs;
+// gpk, vks, partial evaluations and the pseudorandom are added when an input is given
+const dataDir = "./data/"
+const dkgDir = dataDir + "dkg/"
+const randDir = dataDir + "random/"
+
+function readJson(path: string): any {
+    return JSON.parse(fs.readFileSync(path, "utf8"))
+}
+
+function main() {
+    const [output, verifyingKey, input] = process.argv.slice(2)
+    if (!output || !verifyingKey) {
+        throw new Error("usage: fixtures.ts <output> <verifying key contract> [input]")
+    }
+
+    const threshold = Number(process.env.THRESHOLD)
+    const numberOfMembers = Number(process.env.NUMBER_OF_MEMBERS)
+    const maxThreshold = process.env.MAX_THRESHOLD ? Number(process.env.MAX_THRESHOLD) : undefined
+    const maxNumberOfMembers = process.env.MAX_NUMBER_OF_MEMBERS ? Number(process.env.MAX_NUMBER_OF_MEMBERS) : undefined
+
+    const publicParams: string[][] = []
+    const proofs: string[] = []
+    for (let i = 1; i <= numberOfMembers; i++) {
+        const proofPath = dkgDir + `proofs/proof_${i}.dat`
+        if (!fs.existsSync(proofPath)) {
+            break
+        }
+        proofs.push("0x" + fs.readFileSync(proofPath).toString("hex"))
+        publicParams.push(readJson(dkgDir + `proofs/instance_${i}.json`))
+    }
+
+    const fixture: any = {
+        threshold,
+        numberOfMembers,
+        maxThreshold,
+        maxNumberOfMembers,
+        verifyingKey,
+        pubKeys: readJson(dataDir + "mpks.json"),
+        publicParams,
+        proofs,
+    }
+
+    if (input) {
+        fixture.input = input
+        fixture.gpk = readJson(dkgDir + "gpk.json")
+        fixture.vks = readJson(dkgDir + "vks.json")
+        fixture.partialEvals = []
+        for (let i = 1; i <= numberOfMembers; i++) {
+            const pEval = readJson(randDir + `eval_${i}.json`)
+            fixture.partialEvals.push({indexPlus: pEval.index, value: pEval.value, proof: pEval.proof})
+        }
+        const pseudo = readJson(randDir + "pseudo.json")
+        fixture.pseudoRandom = {proof: pseudo.proof, value: "0x" + Buffer.from(pseudo.value).toString("hex")}
+    }
+
+    fs.writeFileSync(output, JSON.stringify(fixture, null, 2))
+    console.log(`fixture with ${proofs.length} proofs written to ${output}`)
+}
+
+main()
@@ -0,0 +1,326 @@
+use crate::dkg::{DkgConfig, DkgShareKey};
+use crate::encryption::DealerContext;
+use crate::error::Error;
+use crate::{check_dealer_context, DkgGlobalPubParams, DkgMemberPublicParams, MemberKey};
+#[cfg(feature = "g2shares")]
+use halo2wrong::curves::bn256::G2Affine as BnG2;
+use halo2wrong::curves::bn256::{Fr as BnScalar, G1Affine as BnG1, G1, G2};
+use halo2wrong::curves::group::prime::PrimeCurveAffine;
+use halo2wrong::curves::group::{Curve, Group};
+use halo2wrong::curves::grumpkin::G1Affine as GkG1;
+use std::collections::{BTreeMap, BTreeSet};
+
+// aggregate dealer public params one at a time, keeping running sums instead of all messages
+#[derive(Clone, Debug)]
+pub struct GlobalParamsBuilder {
+    dkg_config: DkgConfig,
+    dealers: BTreeSet<usize>,
+    ga: G1,
+    g2a: G2,
+    verify_keys: Vec<G1>,
+    #[cfg(feature = "g2shares")]
+    verify_keys2: Vec<G2>,
+}
+
+impl GlobalParamsBuilder {
+    pub fn new(dkg_config: DkgConfig) -> Self {
+        let n = dkg_config.number_of_members();
+
+        GlobalParamsBuilder {
+            dkg_config,
+            dealers: BTreeSet::new(),
+            ga: G1::identity(),
+            g2a: G2::identity(),
+            verify_keys: vec![G1::identity(); n],
+            #[cfg(feature = "g2shares")]
+            verify_keys2: vec![G2::identity(); n],
+        }
+    }
+
+    // dealers added so far in increasing order
+    pub fn dealers(&self) -> Vec<usize> {
+        self.dealers.iter().cloned().collect()
+    }
+
+    pub fn add(&mut self, dealer_index: usize, pp: &DkgMemberPublicParams) -> Result<(), Error> {
+        let n = self.dkg_config.number_of_members();
+        check_dealer_context(&self.dkg_config, dealer_index, &pp.context)?;
+        if self.dealers.contains(&dealer_index) {
+            return Err(Error::DuplicateDealer {
+                index: dealer_index,
+            });
+        }
+        if pp.public_shares.len() != n || pp.ciphers.len() != n {
+            return Err(Error::InvalidDealer {
+                index: dealer_index,
+            });
+        }
+        #[cfg(feature = "g2shares")]
+        if pp.public_shares2.len() != n {
+            return Err(Error::InvalidDealer {
+                index: dealer_index,
+            });
+        }
+
+        self.ga = self.ga + pp.ga;
+        self.g2a = self.g2a + pp.g2a;
+        for (vk, gs) in self.verify_keys.iter_mut().zip(pp.public_shares.iter()) {
+            *vk = *vk + *gs;
+        }
+        #[cfg(feature = "g2shares")]
+        for (vk2, g2s) in self.verify_keys2.iter_mut().zip(pp.public_shares2.iter()) {
+            *vk2 = *vk2 + *g2s;
+        }
+        self.dealers.insert(dealer_index);
+
+        Ok(())
+    }
+
+    // global public params from the dealers added so far
+    pub fn finalize(&self, qualified_threshold: usize) -> Result<DkgGlobalPubParams, Error> {
+        if self.dealers.is_empty() || self.dealers.len() < qualified_threshold {
+            return Err(Error::NotEnoughDealers {
+                dealers: self.dealers.len(),
+                required: qualified_threshold,
+            });
+        }
+
+        let mut verify_keys = vec![BnG1::identity(); self.verify_keys.len()];
+        G1::batch_normalize(&self.verify_keys, &mut verify_keys);
+
+        #[cfg(feature = "g2shares")]
+        let verify_keys2 = {
+            let mut vks2 = vec![BnG2::identity(); self.verify_keys2.len()];
+            G2::batch_normalize(&self.verify_keys2, &mut vks2);
+            vks2
+        };
+
+        Ok(DkgGlobalPubParams {
+            ga: self.ga.to_affine(),
+            g2a: self.g2a.to_affine(),
+            verify_keys,
+            #[cfg(feature = "g2shares")]
+            verify_keys2,
+        })
+    }
+}
+
+// decrypt and check each dealer's share as it arrives, then derive the share key for a qualified set
+#[derive(Clone, Debug)]
+pub struct ShareAccumulator {
+    dkg_config: DkgConfig,
+    member: MemberKey,
+    index: usize,
+    shares: BTreeMap<usize, BnScalar>,
+}
+
+impl ShareAccumulator {
+    pub fn new(dkg_config: DkgConfig, member: MemberKey, index: usize) -> Result<Self, Error> {
+        if index < 1 || index > dkg_config.number_of_members() {
+            return Err(Error::InvalidIndex { index });
+        }
+
+        Ok(ShareAccumulator {
+            dkg_config,
+            member,
+            index,
+            shares: BTreeMap::new(),
+        })
+    }
+
+    // dealers whose shares have been checked, in increasing order
+    pub fn dealers(&self) -> Vec<usize> {
+        self.shares.keys().cloned().collect()
+    }
+
+    // public_share is g^s published by the dealer for this member;
+    // the cipher has to be bound to the session of dkg_config, the dealer and this member
+    pub fn add(
+        &mut self,
+        dealer_index: usize,
+        gr: &GkG1,
+        cipher: &BnScalar,
+        tag: &BnScalar,
+        public_share: &BnG1,
+    ) -> Result<(), Error> {
+        if dealer_index < 1 || dealer_index > self.dkg_config.number_of_members() {
+            return Err(Error::InvalidIndex {
+                index: dealer_index,
+            });
+        }
+        if self.shares.contains_key(&dealer_index) {
+            return Err(Error::DuplicateDealer {
+                index: dealer_index,
+            });
+        }
+
+        let context = DealerContext::new(self.dkg_config.session(), dealer_index);
+        let s = self
+            .member
+            .decrypt_share(&context, self.index, gr, cipher, tag)?;
+        let g = BnG1::generator();
+        if (g * s).to_affine() != *public_share {
+            return Err(Error::InvalidShare {
+                index: dealer_index,
+            });
+        }
+        self.shares.insert(dealer_index, s);
+
+        Ok(())
+    }
+
+    pub fn add_params(
+        &mut self,
+        dealer_index: usize,
+        pp: &DkgMemberPublicParams,
+    ) -> Result<(), Error> {
+        check_dealer_context(&self.dkg_config, dealer_index, &pp.context)?;
+        let k = self.index - 1;
+        if pp.ciphers.len() <= k || pp.tags.len() <= k || pp.public_shares.len() <= k {
+            return Err(Error::InvalidDealer {
+                index: dealer_index,
+            });
+        }
+
+        self.add(
+            dealer_index,
+            &pp.gr,
+            &pp.ciphers[k],
+            &pp.tags[k],
+            &pp.public_shares[k],
+        )
+    }
+
+    // sum the shares of the qualified dealers, all of which must have been added
+    pub fn finalize(&self, qualified: &[usize]) -> Result<DkgShareKey, Error> {
+        if qualified.is_empty() {
+            return Err(Error::NotEnoughDealers {
+                dealers: 0,
+                required: 1,
+            });
+        }
+
+        let mut sk = BnScalar::zero();
+        let mut seen = BTreeSet::new();
+        for dealer_index in qualified.iter() {
+            if !seen.insert(*dealer_index) {
+                return Err(Error::DuplicateDealer {
+                    index: *dealer_index,
+                });
+            }
+            match self.shares.get(dealer_index) {
+                Some(s) => sk += s,
+                None => {
+                    return Err(Error::MissingDealer {
+                        index: *dealer_index,
+                    })
+                }
+            }
+        }
+
+        let g = BnG1::generator();
+        let vk = (g * sk).to_affine();
+
+        Ok(DkgShareKey::new(self.index, sk, vk).with_session(self.dkg_config.session()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dkg_global_public_params, DkgMemberParams, MemberKey, SessionId};
+    use halo2wrong::halo2::arithmetic::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_global_params_builder() {
+        let mut rng = OsRng;
+
+        let dkg_config = DkgConfig::new(3, 5).unwrap();
+        let pks: Vec<_> = (0..dkg_config.number_of_members())
+            .map(|_| MemberKey::random(&mut rng).public_key())
+            .collect();
+        let dkgs: Vec<_> = (0..dkg_config.number_of_members())
+            .map(|i| DkgMemberParams::new(dkg_config, i + 1, pks.clone(), &mut rng).unwrap())
+            .collect();
+        let dkgs_pub: Vec<_> = dkgs.iter().map(|dkg| dkg.member_public_params()).collect();
+
+        let mut builder = GlobalParamsBuilder::new(dkg_config);
+        // dealers arrive out of order
+        for i in [4, 3] {
+            builder.add(i + 1, dkgs_pub[i]).unwrap();
+        }
+        assert!(builder.finalize(4).is_err());
+        assert!(builder.add(2, dkgs_pub[1]).is_ok());
+        assert!(builder.add(2, dkgs_pub[1]).is_err());
+        assert!(builder.add(6, dkgs_pub[0]).is_err());
+        // a dealing replayed under another dealer index or in another session is rejected
+        assert!(builder.add(1, dkgs_pub[1]).is_err());
+        let mut replayed = dkgs_pub[0].clone();
+        replayed.context = DealerContext::new(SessionId::new(BnScalar::one()), 1);
+        assert!(builder.add(1, &replayed).is_err());
+        assert_eq!(builder.dealers(), vec![2, 4, 5]);
+
+        let pp = builder.finalize(3).unwrap();
+        let expected = dkg_global_public_params(&[dkgs_pub[1], dkgs_pub[3], dkgs_pub[4]]);
+        assert_eq!(pp.ga, expected.ga);
+        assert_eq!(pp.g2a, expected.g2a);
+        assert_eq!(pp.verify_keys, expected.verify_keys);
+    }
+
+    #[test]
+    fn test_share_accumulator() {
+        let mut rng = OsRng;
+
+        let session = SessionId::new(BnScalar::random(&mut rng));
+        let dkg_config = DkgConfig::new(3, 5).unwrap().with_session(session);
+        let members: Vec<_> = (0..dkg_config.number_of_members())
+            .map(|_| MemberKey::random(&mut rng))
+            .collect();
+        let pks: Vec<_> = members.iter().map(|m| m.public_key()).collect();
+        let dkgs: Vec<_> = (0..dkg_config.number_of_members())
+            .map(|i| {
+                let context = DealerContext::new(session, i + 1);
+                DkgMemberParams::new_with_context(dkg_config, context, pks.clone(), &mut rng)
+                    .unwrap()
+            })
+            .collect();
+        let dkgs_pub: Vec<_> = dkgs.iter().map(|dkg| dkg.member_public_params()).collect();
+
+        let index = 2;
+        let k = index - 1;
+        let mut acc = ShareAccumulator::new(dkg_config, members[k].clone(), index).unwrap();
+        for i in [3, 0, 4] {
+            acc.add_params(i + 1, dkgs_pub[i]).unwrap();
+        }
+        assert!(acc.add_params(1, dkgs_pub[0]).is_err());
+
+        // a cipher that does not match its tag is rejected
+        let pp = dkgs_pub[1];
+        let forged = pp.ciphers[k] + BnScalar::one();
+        assert!(acc
+            .add(2, &pp.gr, &forged, &pp.tags[k], &pp.public_shares[k])
+            .is_err());
+        // so is a cipher from another dealer, or from the same dealer in another session
+        assert!(acc.add_params(3, dkgs_pub[1]).is_err());
+        let mut replayed = dkgs_pub[1].clone();
+        replayed.context = DealerContext::new(session, 3);
+        assert!(acc.add_params(2, &replayed).is_err());
+        let unbound = DkgConfig::new(3, 5).unwrap();
+        let mut other = ShareAccumulator::new(unbound, members[k].clone(), index).unwrap();
+        assert!(other.add_params(2, dkgs_pub[1]).is_err());
+        assert_eq!(acc.dealers(), vec![1, 4, 5]);
+
+        assert!(acc.finalize(&[1, 2, 4]).is_err());
+        let key = acc.finalize(&[1, 4, 5]).unwrap();
+
+        let qualified = [dkgs_pub[0], dkgs_pub[3], dkgs_pub[4]];
+        let expected = members[k]
+            .dkg_share_key(&dkg_config, index, &qualified)
+            .unwrap();
+        assert_eq!(key.secret_key(), expected.secret_key());
+
+        let pp = dkg_global_public_params(&qualified);
+        key.verify(&dkg_config, &pp.verify_keys).unwrap();
+    }
+}
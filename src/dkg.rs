@@ -1,18 +1,111 @@
+use crate::dkg_circuit::{DkgCircuit, DkgCircuitParams};
 use crate::error::Error;
 use crate::hash_to_curve_evm::from_be_bytes;
-use crate::utils::hash_to_curve_bn;
+use crate::hash_to_curve_rfc::HashSuite;
 use halo2_ecc::halo2::halo2curves::bn256::G2Prepared;
 use halo2_maingate::halo2::halo2curves::bn256::multi_miller_loop;
-use halo2wrong::curves::bn256::{Fr as BnScalar, G1Affine as BnG1, G2Affine as BnG2};
+use halo2wrong::curves::bn256::{Fr as BnScalar, G1Affine as BnG1, G2Affine as BnG2, G1};
+use halo2wrong::curves::ff::PrimeField;
 use halo2wrong::curves::group::{Curve, Group};
 use halo2wrong::curves::pairing::MillerLoopResult;
-use halo2wrong::halo2::arithmetic::Field;
+use halo2wrong::halo2::arithmetic::{best_multiexp, Field};
 use rand_core::RngCore;
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Keccak256};
 
+mod batch;
+mod bundle;
+mod combiner;
+mod g2;
+mod prepared;
+
+pub use batch::{combine_many, BatchPartialEval};
+pub use bundle::{PseudoRandomBundle, VerifyPath};
+pub use combiner::{Combiner, MAX_CACHED_INDEX_SETS};
+pub use g2::{combine_partial_evaluations_g2, PartialEvalG2, PseudoRandomG2, EVAL_PREFIX_G2};
+pub use prepared::PreparedGroupKey;
+
 pub const EVAL_PREFIX: &str = "DVRF pseudorandom generation 2023";
 
+// identifies one dkg run, e.g. chain id and epoch packed into a scalar, and the suite
+// its inputs are hashed to the curve with; the zero session keeps the original domain and challenge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "SessionIdSerde")]
+pub struct SessionId {
+    id: [u8; 32],
+    suite: HashSuite,
+}
+
+// serialised session id, only accepted if the id is a canonical scalar
+#[derive(Deserialize)]
+struct SessionIdSerde {
+    id: [u8; 32],
+    #[serde(default)]
+    suite: HashSuite,
+}
+
+impl TryFrom<SessionIdSerde> for SessionId {
+    type Error = Error;
+
+    fn try_from(session: SessionIdSerde) -> Result<Self, Self::Error> {
+        if bool::from(BnScalar::from_repr(session.id).is_none()) {
+            return Err(Error::InvalidSessionId);
+        }
+
+        Ok(SessionId {
+            id: session.id,
+            suite: session.suite,
+        })
+    }
+}
+
+impl SessionId {
+    pub fn new(value: BnScalar) -> Self {
+        SessionId {
+            id: value.to_repr(),
+            suite: HashSuite::default(),
+        }
+    }
+
+    // hash suite fixed per deployment, it has to match the suite of the PseudoRand contract
+    pub fn with_suite(self, suite: HashSuite) -> Self {
+        SessionId { suite, ..self }
+    }
+
+    pub fn suite(&self) -> HashSuite {
+        self.suite
+    }
+
+    // ids are canonical: they are built from a scalar or checked when deserialised
+    pub fn value(&self) -> BnScalar {
+        Option::<BnScalar>::from(BnScalar::from_repr(self.id))
+            .expect("session id is not a canonical scalar")
+    }
+
+    pub fn is_bound(&self) -> bool {
+        self.id != [0u8; 32]
+    }
+
+    // prefix followed by the session as 32-byte hex, same as
+    // abi.encodePacked(prefix, " ", Strings.toHexString(sessionId, 32)) in solidity
+    pub fn domain(&self, prefix: &str) -> String {
+        if !self.is_bound() {
+            return prefix.to_string();
+        }
+
+        let mut bytes = self.id;
+        bytes.reverse();
+        format!("{prefix} 0x{}", hex::encode(bytes))
+    }
+
+    // hash to curve with the pseudorandom domain and the suite of this session
+    pub fn hasher(&self) -> Box<dyn Fn(&[u8]) -> G1> {
+        let domain = self.domain(EVAL_PREFIX);
+        let suite = self.suite;
+        Box::new(move |input| suite.hash_to_curve(&domain, input))
+    }
+}
+
 // evaluate a polynomial at index i
 fn evaluate_poly(coeffs: &[BnScalar], i: usize) -> BnScalar {
     assert!(coeffs.len() >= 1);
@@ -29,10 +122,17 @@ fn evaluate_poly(coeffs: &[BnScalar], i: usize) -> BnScalar {
     eval
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DkgConfig {
     threshold: usize,
     number_of_members: usize,
+    // circuit shape (t_max, n_max) in padded mode; zero if the circuit is not padded
+    #[serde(default)]
+    max_threshold: usize,
+    #[serde(default)]
+    max_number_of_members: usize,
+    #[serde(default)]
+    session: SessionId,
 }
 
 impl DkgConfig {
@@ -41,6 +141,9 @@ impl DkgConfig {
             return Ok(DkgConfig {
                 threshold,
                 number_of_members,
+                max_threshold: 0,
+                max_number_of_members: 0,
+                session: SessionId::default(),
             });
         };
 
@@ -50,6 +153,41 @@ impl DkgConfig {
         });
     }
 
+    // (threshold, number_of_members) proven with a circuit of fixed shape (max_threshold, max_number_of_members);
+    // one proving key serves every smaller committee
+    pub fn new_padded(
+        threshold: usize,
+        number_of_members: usize,
+        max_threshold: usize,
+        max_number_of_members: usize,
+    ) -> Result<DkgConfig, Error> {
+        let config = DkgConfig::new(threshold, number_of_members)?;
+        if threshold <= max_threshold
+            && number_of_members <= max_number_of_members
+            && max_threshold <= max_number_of_members
+        {
+            return Ok(DkgConfig {
+                max_threshold,
+                max_number_of_members,
+                ..config
+            });
+        }
+
+        return Err(Error::InvalidPadding {
+            max_threshold,
+            max_number_of_members,
+        });
+    }
+
+    // bind proofs and evaluations to one dkg run
+    pub fn with_session(self, session: SessionId) -> Self {
+        DkgConfig { session, ..self }
+    }
+
+    pub fn session(&self) -> SessionId {
+        self.session
+    }
+
     pub fn threshold(&self) -> usize {
         return self.threshold;
     }
@@ -58,17 +196,81 @@ impl DkgConfig {
         return self.number_of_members;
     }
 
+    pub fn is_padded(&self) -> bool {
+        self.max_number_of_members > 0
+    }
+
+    // number of coefficient slots in the circuit
+    pub fn max_threshold(&self) -> usize {
+        if self.is_padded() {
+            self.max_threshold
+        } else {
+            self.threshold
+        }
+    }
+
+    // number of member slots in the circuit
+    pub fn max_number_of_members(&self) -> usize {
+        if self.is_padded() {
+            self.max_number_of_members
+        } else {
+            self.number_of_members
+        }
+    }
+
+    // coefficient selector bits followed by member selector bits; empty if not padded
+    pub fn selectors(&self) -> Vec<BnScalar> {
+        if !self.is_padded() {
+            return vec![];
+        }
+
+        let coeff_bits = (0..self.max_threshold).map(|j| j < self.threshold);
+        let member_bits = (0..self.max_number_of_members).map(|i| i < self.number_of_members);
+        coeff_bits
+            .chain(member_bits)
+            .map(|b| BnScalar::from(b as u64))
+            .collect()
+    }
+
+    // smallest degree k such that the dkg circuit fits into 2^k rows, measured on a dummy circuit
+    pub fn min_degree(&self) -> Result<u32, Error> {
+        self.min_degree_with_params(DkgCircuitParams::default())
+    }
+
+    pub fn min_degree_with_params(&self, circuit_params: DkgCircuitParams) -> Result<u32, Error> {
+        DkgCircuit::dummy(*self)
+            .with_circuit_params(circuit_params)
+            .degree()
+            .map_err(Error::Circuit)
+    }
+
     pub fn instance_size(&self) -> usize {
-        let mut length = 7 * self.number_of_members() + 6;
+        let number_of_members = self.max_number_of_members();
+        // ga, g^s, cipher, tag and pk per member, g^r, dealer index and session
+        let mut length = 8 * number_of_members + 8;
         #[cfg(feature = "g2chip")]
         {
             length += 8;
         }
+        #[cfg(feature = "g2shares")]
+        {
+            length += 8 * number_of_members;
+        }
+        if self.is_padded() {
+            length += self.max_threshold + self.max_number_of_members;
+        }
 
         length
     }
 }
 
+// required by circuit params; same as the default client config
+impl Default for DkgConfig {
+    fn default() -> Self {
+        DkgConfig::new(3, 5).unwrap()
+    }
+}
+
 // compute secret shares for n parties
 pub fn shares(number_of_members: usize, coeffs: &[BnScalar]) -> Vec<BnScalar> {
     let mut shares = vec![];
@@ -87,12 +289,27 @@ pub struct DkgShareKey {
     index: usize,
     sk: BnScalar,
     vk: BnG1,
+    session: SessionId,
 }
 
 impl DkgShareKey {
     pub fn new(index: usize, sk: BnScalar, vk: BnG1) -> Self {
-        DkgShareKey { index, sk, vk }
+        DkgShareKey {
+            index,
+            sk,
+            vk,
+            session: SessionId::default(),
+        }
+    }
+
+    pub fn with_session(self, session: SessionId) -> Self {
+        DkgShareKey { session, ..self }
     }
+
+    pub fn session(&self) -> SessionId {
+        self.session
+    }
+
     pub fn secret_key(&self) -> BnScalar {
         self.sk
     }
@@ -118,48 +335,103 @@ impl DkgShareKey {
     }
 
     // compute H(x)^sk to create partial evaluation and create a schnorr style proof
-    pub fn evaluate(&self, input: &[u8], mut rng: impl RngCore) -> PartialEval {
-        let hasher = hash_to_curve_bn(EVAL_PREFIX);
+    pub fn evaluate(&self, input: &[u8], rng: impl RngCore) -> PartialEval {
+        let hasher = self.session.hasher();
         let h: BnG1 = hasher(input).to_affine();
-        let v = (h * self.sk).to_affine();
-
-        let g = BnG1::generator();
-        let r = BnScalar::random(&mut rng);
-        let cap_r_1 = (g * r).to_affine();
-        let cap_r_2 = (h * r).to_affine();
-
-        let mut bytes = v.y.to_bytes().to_vec();
-        bytes.extend(v.x.to_bytes());
-        bytes.extend(self.vk.y.to_bytes());
-        bytes.extend(self.vk.x.to_bytes());
-        bytes.extend(cap_r_2.y.to_bytes());
-        bytes.extend(cap_r_2.x.to_bytes());
-        bytes.extend(cap_r_1.y.to_bytes());
-        bytes.extend(cap_r_1.x.to_bytes());
-        bytes.extend(h.y.to_bytes());
-        bytes.extend(h.x.to_bytes());
-        bytes.extend(g.y.to_bytes());
-        bytes.extend(g.x.to_bytes());
-        bytes.reverse();
-
-        let hash_state: [u8; 32] = Keccak256::new()
-            .chain_update(&bytes)
-            .finalize()
-            .to_vec()
-            .try_into()
-            .unwrap();
-        let c = BnScalar::from_raw(from_be_bytes(&hash_state));
-        let z = c * self.sk + r;
-        let proof = PartialEvalProof { z, c };
+        let (value, proof) = dleq_prove(&h, &self.sk, &self.vk, &self.session, rng);
 
         PartialEval {
             index: self.index,
-            value: v,
+            value,
             proof,
         }
     }
 }
 
+// hash (g, h, R1, R2, vk, v, session) to the challenge of a dleq proof; an unbound session is left out
+// reverse order to match solidity version
+fn dleq_challenge(
+    h: &BnG1,
+    vk: &BnG1,
+    v: &BnG1,
+    cap_r_1: &BnG1,
+    cap_r_2: &BnG1,
+    session: &SessionId,
+) -> BnScalar {
+    let g = BnG1::generator();
+
+    let mut bytes = if session.is_bound() {
+        session.id.to_vec()
+    } else {
+        vec![]
+    };
+    bytes.extend(v.y.to_bytes());
+    bytes.extend(v.x.to_bytes());
+    bytes.extend(vk.y.to_bytes());
+    bytes.extend(vk.x.to_bytes());
+    bytes.extend(cap_r_2.y.to_bytes());
+    bytes.extend(cap_r_2.x.to_bytes());
+    bytes.extend(cap_r_1.y.to_bytes());
+    bytes.extend(cap_r_1.x.to_bytes());
+    bytes.extend(h.y.to_bytes());
+    bytes.extend(h.x.to_bytes());
+    bytes.extend(g.y.to_bytes());
+    bytes.extend(g.x.to_bytes());
+    bytes.reverse();
+
+    let hash_state: [u8; 32] = Keccak256::new()
+        .chain_update(&bytes)
+        .finalize()
+        .to_vec()
+        .try_into()
+        .unwrap();
+    BnScalar::from_raw(from_be_bytes(&hash_state))
+}
+
+// compute v = h^sk and a schnorr style proof that log_g(vk) == log_h(v)
+pub(crate) fn dleq_prove(
+    h: &BnG1,
+    sk: &BnScalar,
+    vk: &BnG1,
+    session: &SessionId,
+    mut rng: impl RngCore,
+) -> (BnG1, PartialEvalProof) {
+    let v = (h * sk).to_affine();
+
+    let g = BnG1::generator();
+    let r = BnScalar::random(&mut rng);
+    let cap_r_1 = (g * r).to_affine();
+    let cap_r_2 = (h * r).to_affine();
+
+    let c = dleq_challenge(h, vk, &v, &cap_r_1, &cap_r_2, session);
+    let z = c * sk + r;
+
+    (v, PartialEvalProof { z, c })
+}
+
+// verify the schnorr style proof that log_g(vk) == log_h(v)
+pub(crate) fn dleq_verify(
+    h: &BnG1,
+    vk: &BnG1,
+    v: &BnG1,
+    proof: &PartialEvalProof,
+    session: &SessionId,
+) -> Result<(), Error> {
+    let g = BnG1::generator();
+    let z = proof.z;
+    let c = proof.c;
+
+    let cap_r_1 = ((g * z) - (vk * c)).to_affine();
+    let cap_r_2 = ((h * z) - (v * c)).to_affine();
+
+    let c_tilde = dleq_challenge(h, vk, v, &cap_r_1, &cap_r_2, session);
+    if c != c_tilde {
+        return Err(Error::VerifyFailed);
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct PartialEvalProof {
     pub z: BnScalar,
@@ -179,48 +451,15 @@ impl PartialEval {
             return Err(Error::InvalidIndex { index: self.index });
         };
 
-        let hasher = hash_to_curve_bn(EVAL_PREFIX);
+        let session = dkg_config.session();
+        let hasher = session.hasher();
         let h: BnG1 = hasher(input).to_affine();
 
-        let g = BnG1::generator();
-        let z = self.proof.z;
-        let c = self.proof.c;
-        let v = self.value;
-
-        let cap_r_1 = ((g * z) - (vk * c)).to_affine();
-        let cap_r_2 = ((h * z) - (v * c)).to_affine();
-
-        // reverse order to match solidity version
-        let mut bytes = v.y.to_bytes().to_vec();
-        bytes.extend(v.x.to_bytes());
-        bytes.extend(vk.y.to_bytes());
-        bytes.extend(vk.x.to_bytes());
-        bytes.extend(cap_r_2.y.to_bytes());
-        bytes.extend(cap_r_2.x.to_bytes());
-        bytes.extend(cap_r_1.y.to_bytes());
-        bytes.extend(cap_r_1.x.to_bytes());
-        bytes.extend(h.y.to_bytes());
-        bytes.extend(h.x.to_bytes());
-        bytes.extend(g.y.to_bytes());
-        bytes.extend(g.x.to_bytes());
-        bytes.reverse();
-
-        let hash_state: [u8; 32] = Keccak256::new()
-            .chain_update(&bytes)
-            .finalize()
-            .to_vec()
-            .try_into()
-            .unwrap();
-        let c_tilde = BnScalar::from_raw(from_be_bytes(&hash_state));
-
-        if c != c_tilde {
-            return Err(Error::VerifyFailed);
-        }
-
-        Ok(())
+        dleq_verify(&h, vk, &self.value, &self.proof, &session)
     }
 }
 
+#[derive(Debug, Clone)]
 pub struct PseudoRandom {
     pub proof: BnG1,
     pub value: Vec<u8>,
@@ -242,48 +481,79 @@ fn check_indices(number_of_members: usize, indices: &[usize]) -> Result<(), Erro
     Ok(())
 }
 
-// obtain final random
-pub fn combine_partial_evaluations(
-    dkg_config: &DkgConfig,
-    sigmas: &[PartialEval],
-) -> Result<PseudoRandom, Error> {
-    assert_eq!(sigmas.len(), dkg_config.threshold);
+// invert all values with a single field inversion (montgomery's trick)
+pub(crate) fn batch_invert(values: &mut [BnScalar]) {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = BnScalar::one();
+    for v in values.iter() {
+        prefix.push(acc);
+        acc = acc * v;
+    }
 
-    let indices: Vec<_> = sigmas.iter().map(|sigma| sigma.index).collect();
-    check_indices(dkg_config.number_of_members, &indices)?;
+    let mut inv = acc.invert().expect("cannot divide zero");
+    for (v, p) in values.iter_mut().zip(prefix.iter()).rev() {
+        let next = inv * *v;
+        *v = inv * p;
+        inv = next;
+    }
+}
 
-    // compute Lagrange coefficients
+// compute Lagrange coefficients at zero for a set of distinct indices
+// lambda_i = prod_k x_k / (x_i * prod_{k != i} (x_k - x_i))
+pub(crate) fn lagrange_coefficients(indices: &[usize]) -> Vec<BnScalar> {
     let indices: Vec<_> = indices.iter().map(|i| BnScalar::from(*i as u64)).collect();
-    let mut lambdas = vec![];
-    for i in indices.iter() {
-        let mut numerator = BnScalar::one();
-        let mut denominator = BnScalar::one();
-        for k in indices.iter() {
-            if !k.eq(i) {
-                numerator = numerator * k;
-                denominator = denominator * (k - i);
-            }
-        }
-        let lambda = numerator * denominator.invert().expect("cannot divide zero");
-        lambdas.push(lambda);
-    }
+    let product = indices.iter().fold(BnScalar::one(), |acc, k| acc * k);
 
-    // compute pi
-    let pis: Vec<_> = sigmas
+    let mut denominators: Vec<_> = indices
         .iter()
-        .zip(lambdas.iter())
-        .map(|(sigma, lambda)| sigma.value * lambda)
+        .map(|i| {
+            indices
+                .iter()
+                .filter(|k| !k.eq(&i))
+                .fold(*i, |acc, k| acc * (k - i))
+        })
         .collect();
-    let sum = pis.iter().skip(1).fold(pis[0], |sum, p| sum + p);
+    batch_invert(&mut denominators);
+
+    denominators.iter().map(|d| product * d).collect()
+}
+
+// interpolate sum(lambda_i * v_i) from t (index, value) pairs
+pub(crate) fn combine_values(
+    dkg_config: &DkgConfig,
+    indices: &[usize],
+    values: &[BnG1],
+) -> Result<BnG1, Error> {
+    assert_eq!(indices.len(), dkg_config.threshold);
+    assert_eq!(values.len(), dkg_config.threshold);
+    check_indices(dkg_config.number_of_members, indices)?;
+
+    let lambdas = lagrange_coefficients(indices);
 
-    let proof = sum.to_affine();
+    Ok(best_multiexp(&lambdas, values).to_affine())
+}
 
+// keccak hash of the proof point as the final random value
+fn pseudo_random_value(proof: &BnG1) -> Vec<u8> {
     // reverse order to match solidity version
     let mut bytes = proof.y.to_bytes().to_vec();
     bytes.extend(proof.x.to_bytes());
     bytes.reverse();
 
-    let value = Keccak256::new().chain_update(bytes).finalize().to_vec();
+    Keccak256::new().chain_update(bytes).finalize().to_vec()
+}
+
+// obtain final random
+pub fn combine_partial_evaluations(
+    dkg_config: &DkgConfig,
+    sigmas: &[PartialEval],
+) -> Result<PseudoRandom, Error> {
+    assert_eq!(sigmas.len(), dkg_config.threshold);
+
+    let indices: Vec<_> = sigmas.iter().map(|sigma| sigma.index).collect();
+    let values: Vec<_> = sigmas.iter().map(|sigma| sigma.value).collect();
+    let proof = combine_values(dkg_config, &indices, &values)?;
+    let value = pseudo_random_value(&proof);
 
     Ok(PseudoRandom { proof, value })
 }
@@ -302,34 +572,43 @@ impl PseudoRandom {
     }
 
     pub fn verify(&self, input: &[u8], gpk: &BnG2) -> Result<(), Error> {
-        let g2 = BnG2::generator();
+        self.verify_in_session(&SessionId::default(), input, gpk)
+    }
 
-        let hasher = hash_to_curve_bn(EVAL_PREFIX);
+    pub fn verify_in_session(
+        &self,
+        session: &SessionId,
+        input: &[u8],
+        gpk: &BnG2,
+    ) -> Result<(), Error> {
+        let hasher = session.hasher();
         let h: BnG1 = hasher(input).to_affine();
+        verify_pairing(&h, &self.proof, gpk)?;
 
-        let gpk_prepared = G2Prepared::from_affine(gpk.clone());
-        let g2_prepared = G2Prepared::from_affine(g2);
-
-        let t = multi_miller_loop(&[(&-h, &gpk_prepared), (&self.proof, &g2_prepared)])
-            .final_exponentiation();
-
-        if !bool::from(t.is_identity()) {
+        let value = pseudo_random_value(&self.proof);
+        if !self.value.as_slice().eq(&value) {
             return Err(Error::VerifyFailed);
         }
 
-        // reverse order to match solidity version
-        let mut bytes = self.proof.y.to_bytes().to_vec();
-        bytes.extend(self.proof.x.to_bytes());
-        bytes.reverse();
+        Ok(())
+    }
+}
 
-        let value = Keccak256::new().chain_update(bytes).finalize().to_vec();
+// check e(h, gpk) == e(sigma, g2)
+pub(crate) fn verify_pairing(h: &BnG1, sigma: &BnG1, gpk: &BnG2) -> Result<(), Error> {
+    let g2 = BnG2::generator();
 
-        if !self.value.as_slice().eq(&value) {
-            return Err(Error::VerifyFailed);
-        }
+    let gpk_prepared = G2Prepared::from_affine(gpk.clone());
+    let g2_prepared = G2Prepared::from_affine(g2);
 
-        Ok(())
+    let t =
+        multi_miller_loop(&[(&-*h, &gpk_prepared), (sigma, &g2_prepared)]).final_exponentiation();
+
+    if !bool::from(t.is_identity()) {
+        return Err(Error::VerifyFailed);
     }
+
+    Ok(())
 }
 
 pub fn keygen(mut rng: impl RngCore) -> (BnScalar, BnG1) {
@@ -364,6 +643,17 @@ mod tests {
     use rand_chacha::ChaCha20Rng;
     use rand_core::{OsRng, SeedableRng};
 
+    #[test]
+    fn test_min_degree() {
+        let small = DkgConfig::new(3, 5).unwrap().min_degree().unwrap();
+        let large = DkgConfig::new(9, 16).unwrap().min_degree().unwrap();
+        assert!(small < large);
+
+        // a padded circuit needs at least the rows of its largest committee
+        let padded = DkgConfig::new_padded(3, 5, 9, 16).unwrap();
+        assert!(padded.min_degree().unwrap() >= large);
+    }
+
     #[test]
     fn test_partial_evaluation() {
         //let mut rng = ChaCha20Rng::seed_from_u64(42);
@@ -371,7 +661,7 @@ mod tests {
         let dkg_config = DkgConfig::new(9, 16).unwrap(); // can be any numbers here
         let index = 1;
         let (sk, vk) = keygen(&mut rng);
-        let key = DkgShareKey { index, sk, vk };
+        let key = DkgShareKey::new(index, sk, vk);
         let x = b"the first random 20230626";
 
         let start = start_timer!(|| format!("partial evaluations {:?}", dkg_config));
@@ -381,6 +671,85 @@ mod tests {
         sigma.verify(&dkg_config, x, &vk).unwrap();
     }
 
+    #[test]
+    fn test_session_id() {
+        let mut rng = OsRng;
+
+        let session = SessionId::new(BnScalar::from(0x2a));
+        assert_eq!(SessionId::default().domain(EVAL_PREFIX), EVAL_PREFIX);
+        assert_eq!(
+            session.domain("prefix"),
+            format!("prefix 0x{}2a", "0".repeat(62))
+        );
+
+        // a serialised session id has to be a canonical scalar
+        let json = serde_json::to_string(&session).unwrap();
+        assert_eq!(serde_json::from_str::<SessionId>(&json).unwrap(), session);
+        let modulus = serde_json::to_string(&SessionId {
+            id: [0xff; 32],
+            suite: HashSuite::Evm,
+        })
+        .unwrap();
+        assert!(serde_json::from_str::<SessionId>(&modulus).is_err());
+
+        let unbound = DkgConfig::new(3, 5).unwrap();
+        let dkg_config = unbound.with_session(session);
+        assert_eq!(dkg_config.session().value(), BnScalar::from(0x2a));
+
+        let g = BnG1::generator();
+        let g2 = BnG2::generator();
+        let coeffs: Vec<_> = (0..dkg_config.threshold())
+            .map(|_| BnScalar::random(&mut rng))
+            .collect();
+        let keys: Vec<_> = shares(dkg_config.number_of_members(), &coeffs)
+            .iter()
+            .enumerate()
+            .map(|(i, s)| DkgShareKey::new(i + 1, *s, (g * s).to_affine()).with_session(session))
+            .collect();
+        let gpk = (g2 * coeffs[0]).to_affine();
+        let input = b"session random";
+
+        let evals: Vec<_> = keys
+            .iter()
+            .map(|key| key.evaluate(input, &mut rng))
+            .collect();
+        for (e, key) in evals.iter().zip(keys.iter()) {
+            e.verify(&dkg_config, input, &key.verify_key()).unwrap();
+            // an evaluation from this session is not accepted in another one
+            assert!(e.verify(&unbound, input, &key.verify_key()).is_err());
+        }
+        let key = DkgShareKey::new(1, keys[0].secret_key(), keys[0].verify_key());
+        let e = key.evaluate(input, &mut rng);
+        assert!(e.verify(&dkg_config, input, &key.verify_key()).is_err());
+
+        let pseudo = combine_partial_evaluations(&dkg_config, &evals[..3]).unwrap();
+        pseudo.verify_in_session(&session, input, &gpk).unwrap();
+        assert!(pseudo.verify(input, &gpk).is_err());
+
+        // the same session with another hash suite gives another pseudorandom
+        let rfc_session = session.with_suite(HashSuite::Sha256);
+        let rfc_config = unbound.with_session(rfc_session);
+        let evals: Vec<_> = keys
+            .iter()
+            .map(|key| {
+                DkgShareKey::new(key.index(), key.secret_key(), key.verify_key())
+                    .with_session(rfc_session)
+                    .evaluate(input, &mut rng)
+            })
+            .collect();
+        evals[0]
+            .verify(&rfc_config, input, &keys[0].verify_key())
+            .unwrap();
+        assert!(evals[0]
+            .verify(&dkg_config, input, &keys[0].verify_key())
+            .is_err());
+        let rfc_pseudo = combine_partial_evaluations(&rfc_config, &evals[..3]).unwrap();
+        rfc_pseudo
+            .verify_in_session(&rfc_session, input, &gpk)
+            .unwrap();
+        assert_ne!(rfc_pseudo.value, pseudo.value);
+    }
+
     fn pseudo_random(threshold: usize, number_of_members: usize) {
         //let mut rng = ChaCha20Rng::seed_from_u64(42);
         let mut rng = OsRng;
@@ -396,11 +765,7 @@ mod tests {
         let keys: Vec<_> = shares
             .iter()
             .enumerate()
-            .map(|(i, s)| DkgShareKey {
-                index: i + 1,
-                sk: *s,
-                vk: (g * s).to_affine(),
-            })
+            .map(|(i, s)| DkgShareKey::new(i + 1, *s, (g * s).to_affine()))
             .collect();
         let vks: Vec<_> = keys.iter().map(|key| key.vk).collect();
 
@@ -0,0 +1,331 @@
+use super::{
+    dleq_challenge, pseudo_random_value, Combiner, DkgConfig, DkgShareKey, PartialEval,
+    PartialEvalProof, PseudoRandom, SessionId,
+};
+use crate::error::Error;
+use halo2wrong::curves::bn256::{Fr as BnScalar, G1Affine as BnG1, G1};
+use halo2wrong::curves::ff::PrimeField;
+use halo2wrong::curves::group::prime::PrimeCurveAffine;
+use halo2wrong::curves::group::{Curve, Group};
+use halo2wrong::halo2::arithmetic::{best_multiexp, Field};
+use lazy_static::lazy_static;
+use rand_core::RngCore;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+const TABLE_WINDOW: usize = 4;
+
+lazy_static! {
+    // fixed-base table for the generator g shared by all batch operations
+    static ref G_TABLE: FixedBaseTable = FixedBaseTable::new(&BnG1::generator());
+}
+
+// table[j][k] = k * 2^(w*j) * base for k in 0..2^w
+struct FixedBaseTable {
+    windows: Vec<Vec<BnG1>>,
+}
+
+impl FixedBaseTable {
+    fn new(base: &BnG1) -> Self {
+        let size = 1 << TABLE_WINDOW;
+        let num_windows = (BnScalar::NUM_BITS as usize + TABLE_WINDOW - 1) / TABLE_WINDOW;
+
+        let mut windows = vec![];
+        let mut b = base.to_curve();
+        for _ in 0..num_windows {
+            let mut row = vec![G1::identity()];
+            for k in 1..size {
+                row.push(row[k - 1] + b);
+            }
+            let mut row_affine = vec![BnG1::identity(); size];
+            G1::batch_normalize(&row, &mut row_affine);
+            windows.push(row_affine);
+
+            for _ in 0..TABLE_WINDOW {
+                b = b.double();
+            }
+        }
+
+        FixedBaseTable { windows }
+    }
+
+    // scan the whole row for each window so that the lookup does not depend on the scalar
+    fn mul(&self, scalar: &BnScalar) -> G1 {
+        let repr = scalar.to_repr();
+        let mut acc = G1::identity();
+        for (j, row) in self.windows.iter().enumerate() {
+            let byte = repr.as_ref()[j * TABLE_WINDOW / 8];
+            let digit = (byte >> ((j * TABLE_WINDOW) % 8)) & ((1 << TABLE_WINDOW) - 1);
+
+            let mut p = BnG1::identity();
+            for (k, q) in row.iter().enumerate() {
+                p = BnG1::conditional_select(&p, q, (k as u8).ct_eq(&digit));
+            }
+            acc = acc + p;
+        }
+
+        acc
+    }
+}
+
+fn hash_inputs(session: &SessionId, inputs: &[&[u8]]) -> Vec<BnG1> {
+    let hasher = session.hasher();
+    let hs: Vec<_> = inputs.iter().map(|input| hasher(*input)).collect();
+    let mut hs_affine = vec![BnG1::identity(); hs.len()];
+    G1::batch_normalize(&hs, &mut hs_affine);
+
+    hs_affine
+}
+
+// partial evaluation together with the commitments R1 = g^r and R2 = h^r of its proof.
+// the challenge can be recomputed from R1 and R2 directly, so the proof equations of many
+// evaluations are checked at once with a random linear combination
+#[derive(Debug, Clone)]
+pub struct BatchPartialEval {
+    pub eval: PartialEval,
+    pub cap_r_1: BnG1,
+    pub cap_r_2: BnG1,
+}
+
+impl DkgShareKey {
+    // partial evaluations for many inputs sharing the fixed-base table and a single normalisation
+    pub fn evaluate_many(&self, inputs: &[&[u8]], mut rng: impl RngCore) -> Vec<BatchPartialEval> {
+        let n = inputs.len();
+        let hs = hash_inputs(&self.session, inputs);
+        let rs: Vec<_> = (0..n).map(|_| BnScalar::random(&mut rng)).collect();
+
+        // [v_1, ..., v_n, R1_1, ..., R1_n, R2_1, ..., R2_n]
+        let mut points = Vec::with_capacity(3 * n);
+        points.extend(hs.iter().map(|h| h * self.sk));
+        points.extend(rs.iter().map(|r| G_TABLE.mul(r)));
+        points.extend(hs.iter().zip(rs.iter()).map(|(h, r)| h * r));
+        let mut points_affine = vec![BnG1::identity(); 3 * n];
+        G1::batch_normalize(&points, &mut points_affine);
+
+        (0..n)
+            .map(|i| {
+                let v = points_affine[i];
+                let cap_r_1 = points_affine[n + i];
+                let cap_r_2 = points_affine[2 * n + i];
+
+                let c = dleq_challenge(&hs[i], &self.vk, &v, &cap_r_1, &cap_r_2, &self.session);
+                let z = c * self.sk + rs[i];
+
+                BatchPartialEval {
+                    eval: PartialEval {
+                        index: self.index,
+                        value: v,
+                        proof: PartialEvalProof { z, c },
+                    },
+                    cap_r_1,
+                    cap_r_2,
+                }
+            })
+            .collect()
+    }
+}
+
+impl PartialEval {
+    // verify partial evaluations of one member for many inputs.
+    // every challenge is recomputed from the R1 and R2 sent with it, then with random weights rho_i
+    // and delta the equations g^z_i = R1_i * vk^c_i and h_i^z_i = R2_i * v_i^c_i are checked together:
+    // sum_i rho_i * (z_i * g - c_i * vk - R1_i + delta * (z_i * h_i - c_i * v_i - R2_i)) == 0
+    pub fn verify_many(
+        dkg_config: &DkgConfig,
+        inputs: &[&[u8]],
+        evals: &[BatchPartialEval],
+        vk: &BnG1,
+        mut rng: impl RngCore,
+    ) -> Result<(), Error> {
+        if inputs.len() != evals.len() {
+            return Err(Error::LengthMismatch {
+                left: inputs.len(),
+                right: evals.len(),
+            });
+        }
+        for batch in evals.iter() {
+            if batch.eval.index > dkg_config.number_of_members() || batch.eval.index < 1 {
+                return Err(Error::InvalidIndex {
+                    index: batch.eval.index,
+                });
+            };
+        }
+
+        let session = dkg_config.session();
+        let hs = hash_inputs(&session, inputs);
+
+        for (h, batch) in hs.iter().zip(evals.iter()) {
+            let c = dleq_challenge(
+                h,
+                vk,
+                &batch.eval.value,
+                &batch.cap_r_1,
+                &batch.cap_r_2,
+                &session,
+            );
+            if c != batch.eval.proof.c {
+                return Err(Error::VerifyFailed);
+            }
+        }
+
+        let delta = BnScalar::random(&mut rng);
+        let mut z_sum = BnScalar::zero();
+        let mut c_sum = BnScalar::zero();
+        let mut scalars = vec![];
+        let mut bases = vec![];
+        for (h, batch) in hs.iter().zip(evals.iter()) {
+            let rho = BnScalar::random(&mut rng);
+            let rho_z = rho * batch.eval.proof.z;
+            let rho_c = rho * batch.eval.proof.c;
+            z_sum += rho_z;
+            c_sum += rho_c;
+
+            scalars.extend([-rho, delta * rho_z, -delta * rho_c, -delta * rho]);
+            bases.extend([batch.cap_r_1, *h, batch.eval.value, batch.cap_r_2]);
+        }
+        scalars.extend([z_sum, -c_sum]);
+        bases.extend([BnG1::generator(), *vk]);
+
+        if !bool::from(best_multiexp(&scalars, &bases).is_identity()) {
+            return Err(Error::VerifyFailed);
+        }
+
+        Ok(())
+    }
+}
+
+// combine partial evaluations for many inputs, reusing lagrange coefficients for repeated index sets
+pub fn combine_many(
+    dkg_config: &DkgConfig,
+    sigmas: &[Vec<PartialEval>],
+) -> Result<Vec<PseudoRandom>, Error> {
+    let mut combiner = Combiner::new(*dkg_config);
+
+    let mut proofs = vec![];
+    for evals in sigmas.iter() {
+        let indices: Vec<_> = evals.iter().map(|eval| eval.index).collect();
+        let values: Vec<_> = evals.iter().map(|eval| eval.value).collect();
+        let lambdas = combiner.lambdas(&indices)?;
+        proofs.push(best_multiexp(lambdas, &values));
+    }
+
+    let mut proofs_affine = vec![BnG1::identity(); proofs.len()];
+    G1::batch_normalize(&proofs, &mut proofs_affine);
+
+    let randoms = proofs_affine
+        .into_iter()
+        .map(|proof| {
+            let value = pseudo_random_value(&proof);
+            PseudoRandom { proof, value }
+        })
+        .collect();
+
+    Ok(randoms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dkg::{combine_partial_evaluations, shares};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_fixed_base_table() {
+        let mut rng = OsRng;
+        let g = BnG1::generator();
+        for _ in 0..10 {
+            let s = BnScalar::random(&mut rng);
+            assert_eq!(G_TABLE.mul(&s).to_affine(), (g * s).to_affine());
+        }
+        assert_eq!(G_TABLE.mul(&BnScalar::zero()), G1::identity());
+    }
+
+    #[test]
+    fn test_batch_evaluation() {
+        let mut rng = OsRng;
+
+        let g = BnG1::generator();
+        let dkg_config = DkgConfig::new(3, 5).unwrap();
+        let coeffs: Vec<_> = (0..dkg_config.threshold())
+            .map(|_| BnScalar::random(&mut rng))
+            .collect();
+        let shares = shares(dkg_config.number_of_members(), &coeffs);
+        let keys: Vec<_> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, s)| DkgShareKey::new(i + 1, *s, (g * s).to_affine()))
+            .collect();
+
+        let inputs: Vec<Vec<u8>> = (0..6)
+            .map(|i| format!("batch random {i}").into_bytes())
+            .collect();
+        let inputs: Vec<&[u8]> = inputs.iter().map(|input| input.as_slice()).collect();
+
+        // batches[member][input]
+        let batches: Vec<_> = keys
+            .iter()
+            .map(|key| key.evaluate_many(&inputs, &mut rng))
+            .collect();
+        for (key, member_batches) in keys.iter().zip(batches.iter()) {
+            PartialEval::verify_many(
+                &dkg_config,
+                &inputs,
+                member_batches,
+                &key.verify_key(),
+                &mut rng,
+            )
+            .unwrap();
+            // batch proofs are ordinary proofs
+            for (input, batch) in inputs.iter().zip(member_batches.iter()) {
+                batch
+                    .eval
+                    .verify(&dkg_config, input, &key.verify_key())
+                    .unwrap();
+            }
+        }
+        let vk = keys[0].verify_key();
+
+        // a swapped input is rejected
+        let mut swapped = batches[0].clone();
+        swapped.swap(0, 1);
+        assert!(PartialEval::verify_many(&dkg_config, &inputs, &swapped, &vk, &mut rng).is_err());
+
+        // a wrong response is caught by the combined equation even though every challenge matches
+        let mut wrong_z = batches[0].clone();
+        wrong_z[2].eval.proof.z += BnScalar::one();
+        assert!(matches!(
+            PartialEval::verify_many(&dkg_config, &inputs, &wrong_z, &vk, &mut rng),
+            Err(Error::VerifyFailed)
+        ));
+
+        // a commitment that was not hashed into the challenge is rejected
+        let mut wrong_r = batches[0].clone();
+        wrong_r[3].cap_r_2 = wrong_r[4].cap_r_2;
+        assert!(PartialEval::verify_many(&dkg_config, &inputs, &wrong_r, &vk, &mut rng).is_err());
+
+        // every input needs an evaluation
+        let res = PartialEval::verify_many(&dkg_config, &inputs, &batches[0][1..], &vk, &mut rng);
+        assert!(matches!(
+            res,
+            Err(Error::LengthMismatch { left: 6, right: 5 })
+        ));
+
+        let evals: Vec<Vec<_>> = batches
+            .iter()
+            .map(|member_batches| member_batches.iter().map(|b| b.eval.clone()).collect())
+            .collect();
+
+        // alternate between two index sets
+        let sigmas: Vec<Vec<PartialEval>> = (0..inputs.len())
+            .map(|j| {
+                let members = if j % 2 == 0 { [0, 1, 2] } else { [1, 3, 4] };
+                members.iter().map(|&i| evals[i][j].clone()).collect()
+            })
+            .collect();
+        let randoms = combine_many(&dkg_config, &sigmas).unwrap();
+        for (sigma, random) in sigmas.iter().zip(randoms.iter()) {
+            let expected = combine_partial_evaluations(&dkg_config, sigma).unwrap();
+            assert_eq!(random.proof(), expected.proof());
+            assert_eq!(random.value(), expected.value());
+        }
+    }
+}
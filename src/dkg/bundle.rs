@@ -0,0 +1,171 @@
+use super::{
+    combine_partial_evaluations, combine_values, dleq_verify, pseudo_random_value, DkgConfig,
+    PartialEval, PseudoRandom,
+};
+use crate::error::Error;
+use halo2wrong::curves::bn256::{G1Affine as BnG1, G2Affine as BnG2};
+use halo2wrong::curves::group::Curve;
+
+// which check was used to accept a pseudorandom value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyPath {
+    // e(h, gpk) == e(proof, g2)
+    Pairing,
+    // dleq proofs of the partial evaluations against verify_keys and their lagrange combination
+    PartialEvals,
+}
+
+// pseudorandom value shipped with the t partial evaluations it was combined from,
+// so that it can be checked with G1 arithmetic only
+#[derive(Debug, Clone)]
+pub struct PseudoRandomBundle {
+    pub pseudo: PseudoRandom,
+    pub evals: Vec<PartialEval>,
+}
+
+impl PseudoRandomBundle {
+    pub fn new(pseudo: PseudoRandom, evals: Vec<PartialEval>) -> Self {
+        Self { pseudo, evals }
+    }
+
+    // combine t partial evaluations and keep them for pairing-free verification
+    pub fn combine(dkg_config: &DkgConfig, evals: &[PartialEval]) -> Result<Self, Error> {
+        let pseudo = combine_partial_evaluations(dkg_config, evals)?;
+
+        Ok(Self {
+            pseudo,
+            evals: evals.to_vec(),
+        })
+    }
+
+    pub fn pseudo_random(&self) -> &PseudoRandom {
+        &self.pseudo
+    }
+
+    // check the dleq proofs against verify_keys and recompute the lagrange combination
+    pub fn verify_partial_evals(
+        &self,
+        dkg_config: &DkgConfig,
+        input: &[u8],
+        verify_keys: &[BnG1],
+    ) -> Result<(), Error> {
+        if self.evals.len() != dkg_config.threshold()
+            || verify_keys.len() != dkg_config.number_of_members()
+        {
+            return Err(Error::VerifyFailed);
+        }
+
+        let session = dkg_config.session();
+        let hasher = session.hasher();
+        let h: BnG1 = hasher(input).to_affine();
+
+        for eval in self.evals.iter() {
+            if eval.index > dkg_config.number_of_members() || eval.index < 1 {
+                return Err(Error::InvalidIndex { index: eval.index });
+            }
+            dleq_verify(
+                &h,
+                &verify_keys[eval.index - 1],
+                &eval.value,
+                &eval.proof,
+                &session,
+            )?;
+        }
+
+        let indices: Vec<_> = self.evals.iter().map(|eval| eval.index).collect();
+        let values: Vec<_> = self.evals.iter().map(|eval| eval.value).collect();
+        let proof = combine_values(dkg_config, &indices, &values)?;
+        if proof != self.pseudo.proof {
+            return Err(Error::VerifyFailed);
+        }
+
+        let value = pseudo_random_value(&proof);
+        if !self.pseudo.value.as_slice().eq(&value) {
+            return Err(Error::VerifyFailed);
+        }
+
+        Ok(())
+    }
+
+    // use the partial evaluations if present, otherwise fall back to the pairing check with gpk
+    pub fn verify(
+        &self,
+        dkg_config: &DkgConfig,
+        input: &[u8],
+        verify_keys: &[BnG1],
+        gpk: Option<&BnG2>,
+    ) -> Result<VerifyPath, Error> {
+        if !self.evals.is_empty() {
+            self.verify_partial_evals(dkg_config, input, verify_keys)?;
+            return Ok(VerifyPath::PartialEvals);
+        }
+
+        match gpk {
+            Some(gpk) => {
+                self.pseudo
+                    .verify_in_session(&dkg_config.session(), input, gpk)?;
+                Ok(VerifyPath::Pairing)
+            }
+            None => Err(Error::VerifyFailed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dkg::{shares, DkgShareKey};
+    use halo2wrong::curves::bn256::Fr as BnScalar;
+    use halo2wrong::halo2::arithmetic::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_pseudo_random_bundle() {
+        let mut rng = OsRng;
+
+        let g = BnG1::generator();
+        let g2 = BnG2::generator();
+
+        let dkg_config = DkgConfig::new(4, 7).unwrap();
+        let coeffs: Vec<_> = (0..dkg_config.threshold())
+            .map(|_| BnScalar::random(&mut rng))
+            .collect();
+        let shares = shares(dkg_config.number_of_members(), &coeffs);
+        let keys: Vec<_> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, s)| DkgShareKey::new(i + 1, *s, (g * s).to_affine()))
+            .collect();
+        let vks: Vec<_> = keys.iter().map(|key| key.verify_key()).collect();
+        let gpk = (g2 * coeffs[0]).to_affine();
+
+        let input = b"bundle random";
+        let evals: Vec<_> = keys
+            .iter()
+            .map(|key| key.evaluate(input, &mut rng))
+            .collect();
+
+        let bundle =
+            PseudoRandomBundle::combine(&dkg_config, &evals[2..2 + dkg_config.threshold()])
+                .unwrap();
+        let path = bundle.verify(&dkg_config, input, &vks, None).unwrap();
+        assert_eq!(path, VerifyPath::PartialEvals);
+        bundle.pseudo_random().verify(input, &gpk).unwrap();
+        assert!(bundle
+            .verify(&dkg_config, b"another input", &vks, None)
+            .is_err());
+
+        // a tampered evaluation is rejected
+        let mut forged = bundle.clone();
+        forged.evals[0].value = (forged.evals[0].value + g).to_affine();
+        assert!(forged.verify(&dkg_config, input, &vks, None).is_err());
+
+        // without evaluations the bundle needs the group key
+        let bare = PseudoRandomBundle::new(bundle.pseudo.clone(), vec![]);
+        assert!(bare.verify(&dkg_config, input, &vks, None).is_err());
+        assert_eq!(
+            bare.verify(&dkg_config, input, &vks, Some(&gpk)).unwrap(),
+            VerifyPath::Pairing
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use super::{
+    check_indices, lagrange_coefficients, pseudo_random_value, DkgConfig, PartialEval, PseudoRandom,
+};
+use crate::error::Error;
+use halo2wrong::curves::bn256::{Fr as BnScalar, G1Affine as BnG1};
+use halo2wrong::curves::group::Curve;
+use halo2wrong::halo2::arithmetic::best_multiexp;
+use std::collections::HashMap;
+
+// number of index sets whose lagrange coefficients a combiner keeps by default
+pub const MAX_CACHED_INDEX_SETS: usize = 64;
+
+// combines partial evaluations for a fixed dkg config, caching lagrange coefficients per index set.
+// at most max_cached index sets are kept; the least recently used one is evicted first
+#[derive(Debug, Clone)]
+pub struct Combiner {
+    dkg_config: DkgConfig,
+    max_cached: usize,
+    clock: u64,
+    // index set -> (lagrange coefficients, last use)
+    lambdas: HashMap<Vec<usize>, (Vec<BnScalar>, u64)>,
+}
+
+impl Combiner {
+    pub fn new(dkg_config: DkgConfig) -> Self {
+        Self::with_max_cached(dkg_config, MAX_CACHED_INDEX_SETS)
+    }
+
+    pub fn with_max_cached(dkg_config: DkgConfig, max_cached: usize) -> Self {
+        Combiner {
+            dkg_config,
+            max_cached: max_cached.max(1),
+            clock: 0,
+            lambdas: HashMap::new(),
+        }
+    }
+
+    pub fn dkg_config(&self) -> &DkgConfig {
+        &self.dkg_config
+    }
+
+    // lagrange coefficients at zero for a sorted index set of size threshold
+    pub fn lambdas(&mut self, indices: &[usize]) -> Result<&[BnScalar], Error> {
+        self.clock += 1;
+        let clock = self.clock;
+
+        if let Some(entry) = self.lambdas.get_mut(indices) {
+            entry.1 = clock;
+        } else {
+            if indices.len() != self.dkg_config.threshold() {
+                return Err(Error::LengthMismatch {
+                    left: indices.len(),
+                    right: self.dkg_config.threshold(),
+                });
+            }
+            check_indices(self.dkg_config.number_of_members(), indices)?;
+
+            if self.lambdas.len() >= self.max_cached {
+                let oldest = self
+                    .lambdas
+                    .iter()
+                    .min_by_key(|(_, (_, used))| *used)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    self.lambdas.remove(&oldest);
+                }
+            }
+
+            let lambdas = lagrange_coefficients(indices);
+            self.lambdas.insert(indices.to_vec(), (lambdas, clock));
+        }
+
+        Ok(&self.lambdas[indices].0)
+    }
+
+    // sum(lambda_i * v_i) with a multi-scalar multiplication
+    pub fn combine_values(&mut self, indices: &[usize], values: &[BnG1]) -> Result<BnG1, Error> {
+        if values.len() != indices.len() {
+            return Err(Error::LengthMismatch {
+                left: values.len(),
+                right: indices.len(),
+            });
+        }
+        let lambdas = self.lambdas(indices)?;
+
+        Ok(best_multiexp(lambdas, values).to_affine())
+    }
+
+    pub fn combine(&mut self, sigmas: &[PartialEval]) -> Result<PseudoRandom, Error> {
+        let indices: Vec<_> = sigmas.iter().map(|sigma| sigma.index).collect();
+        let values: Vec<_> = sigmas.iter().map(|sigma| sigma.value).collect();
+        let proof = self.combine_values(&indices, &values)?;
+        let value = pseudo_random_value(&proof);
+
+        Ok(PseudoRandom { proof, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dkg::{combine_partial_evaluations, shares, DkgShareKey};
+    use halo2wrong::halo2::arithmetic::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_combiner() {
+        let mut rng = OsRng;
+
+        let g = BnG1::generator();
+        let dkg_config = DkgConfig::new(5, 9).unwrap();
+        let coeffs: Vec<_> = (0..dkg_config.threshold())
+            .map(|_| BnScalar::random(&mut rng))
+            .collect();
+        let shares = shares(dkg_config.number_of_members(), &coeffs);
+        let keys: Vec<_> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, s)| DkgShareKey::new(i + 1, *s, (g * s).to_affine()))
+            .collect();
+
+        let input = b"combiner random";
+        let evals: Vec<_> = keys
+            .iter()
+            .map(|key| key.evaluate(input, &mut rng))
+            .collect();
+
+        let mut combiner = Combiner::new(dkg_config);
+        let expected = combine_partial_evaluations(&dkg_config, &evals[0..5]).unwrap();
+        for _ in 0..2 {
+            let res = combiner.combine(&evals[0..5]).unwrap();
+            assert_eq!(res.proof(), expected.proof());
+            assert_eq!(res.value(), expected.value());
+        }
+
+        let res = combiner.combine(&evals[4..9]).unwrap();
+        assert_eq!(res.proof(), expected.proof());
+
+        // unsorted indices are rejected
+        let mut unsorted = evals[0..5].to_vec();
+        unsorted.swap(0, 1);
+        assert!(combiner.combine(&unsorted).is_err());
+
+        // index sets and values of the wrong length are rejected
+        assert!(matches!(
+            combiner.combine(&evals[0..4]),
+            Err(Error::LengthMismatch { left: 4, right: 5 })
+        ));
+        let values: Vec<_> = evals[0..4].iter().map(|eval| eval.value).collect();
+        assert!(matches!(
+            combiner.combine_values(&[1, 2, 3, 4, 5], &values),
+            Err(Error::LengthMismatch { left: 4, right: 5 })
+        ));
+
+        // the cache keeps the most recently used index sets only
+        let mut combiner = Combiner::with_max_cached(dkg_config, 2);
+        combiner.combine(&evals[0..5]).unwrap();
+        combiner.combine(&evals[4..9]).unwrap();
+        combiner.combine(&evals[0..5]).unwrap();
+        let res = combiner.combine(&evals[2..7]).unwrap();
+        assert_eq!(res.proof(), expected.proof());
+        assert_eq!(combiner.lambdas.len(), 2);
+        assert!(combiner.lambdas.contains_key(&vec![1, 2, 3, 4, 5]));
+        assert!(combiner.lambdas.contains_key(&vec![3, 4, 5, 6, 7]));
+        assert!(!combiner.lambdas.contains_key(&vec![5, 6, 7, 8, 9]));
+    }
+}
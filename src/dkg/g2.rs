@@ -0,0 +1,297 @@
+use super::{
+    check_indices, lagrange_coefficients, DkgConfig, DkgShareKey, PartialEvalProof, SessionId,
+};
+use crate::error::Error;
+use crate::hash_to_curve_evm::from_be_bytes;
+use crate::utils::hash_to_curve_bn_g2;
+use halo2_ecc::halo2::halo2curves::bn256::G2Prepared;
+use halo2_maingate::halo2::halo2curves::bn256::multi_miller_loop;
+use halo2wrong::curves::bn256::{Fr as BnScalar, G1Affine as BnG1, G2Affine as BnG2};
+use halo2wrong::curves::group::{Curve, Group};
+use halo2wrong::curves::pairing::MillerLoopResult;
+use halo2wrong::halo2::arithmetic::{best_multiexp, Field};
+use rand_core::RngCore;
+use sha3::{Digest, Keccak256};
+
+pub const EVAL_PREFIX_G2: &str = "DVRF pseudorandom generation 2023 G2";
+
+// partial evaluation H(x)^sk in G2, verified against the member's G2 verification key g2^sk
+#[derive(Debug, Clone)]
+pub struct PartialEvalG2 {
+    pub index: usize,
+    pub value: BnG2,
+    pub proof: PartialEvalProof,
+}
+
+// combined proof H(x)^a in G2, verified against ga in G1
+#[derive(Debug, Clone)]
+pub struct PseudoRandomG2 {
+    pub proof: BnG2,
+    pub value: Vec<u8>,
+}
+
+impl DkgShareKey {
+    pub fn verify_key_g2(&self) -> BnG2 {
+        (BnG2::generator() * self.sk).to_affine()
+    }
+
+    // compute H(x)^sk in G2 and a schnorr style proof against g2^sk
+    pub fn evaluate_g2(&self, input: &[u8], rng: impl RngCore) -> PartialEvalG2 {
+        let h = hash_to_g2(&self.session, input);
+        let vk = self.verify_key_g2();
+        let (value, proof) = dleq_prove_g2(&h, &self.sk, &vk, &self.session, rng);
+
+        PartialEvalG2 {
+            index: self.index,
+            value,
+            proof,
+        }
+    }
+}
+
+// hash to G2 under the pseudorandom domain of the session, as SessionId::hasher does in G1
+fn hash_to_g2(session: &SessionId, input: &[u8]) -> BnG2 {
+    let domain = session.domain(EVAL_PREFIX_G2);
+    let hasher = hash_to_curve_bn_g2(&domain);
+    hasher(input).to_affine()
+}
+
+// big endian encoding (x.c1, x.c0, y.c1, y.c0) as used by the evm pairing precompile
+fn g2_to_bytes(p: &BnG2) -> Vec<u8> {
+    let mut bytes = vec![];
+    for c in [p.x.c1, p.x.c0, p.y.c1, p.y.c0] {
+        let mut b = c.to_bytes();
+        b.reverse();
+        bytes.extend(b);
+    }
+
+    bytes
+}
+
+// hash (session, g2, h, R1, R2, vk, v) to the challenge of a dleq proof in G2;
+// an unbound session is left out
+fn dleq_challenge_g2(
+    h: &BnG2,
+    vk: &BnG2,
+    v: &BnG2,
+    cap_r_1: &BnG2,
+    cap_r_2: &BnG2,
+    session: &SessionId,
+) -> BnScalar {
+    let g2 = BnG2::generator();
+
+    let mut hasher = Keccak256::new();
+    if session.is_bound() {
+        hasher.update(session.id);
+    }
+    for p in [&g2, h, cap_r_1, cap_r_2, vk, v] {
+        hasher.update(g2_to_bytes(p));
+    }
+    let hash_state: [u8; 32] = hasher.finalize().to_vec().try_into().unwrap();
+
+    BnScalar::from_raw(from_be_bytes(&hash_state))
+}
+
+// compute v = h^sk and a schnorr style proof that log_g2(vk) == log_h(v)
+fn dleq_prove_g2(
+    h: &BnG2,
+    sk: &BnScalar,
+    vk: &BnG2,
+    session: &SessionId,
+    mut rng: impl RngCore,
+) -> (BnG2, PartialEvalProof) {
+    let v = (h * sk).to_affine();
+
+    let g2 = BnG2::generator();
+    let r = BnScalar::random(&mut rng);
+    let cap_r_1 = (g2 * r).to_affine();
+    let cap_r_2 = (h * r).to_affine();
+
+    let c = dleq_challenge_g2(h, vk, &v, &cap_r_1, &cap_r_2, session);
+    let z = c * sk + r;
+
+    (v, PartialEvalProof { z, c })
+}
+
+fn dleq_verify_g2(
+    h: &BnG2,
+    vk: &BnG2,
+    v: &BnG2,
+    proof: &PartialEvalProof,
+    session: &SessionId,
+) -> Result<(), Error> {
+    let g2 = BnG2::generator();
+    let z = proof.z;
+    let c = proof.c;
+
+    let cap_r_1 = ((g2 * z) - (vk * c)).to_affine();
+    let cap_r_2 = ((h * z) - (v * c)).to_affine();
+
+    let c_tilde = dleq_challenge_g2(h, vk, v, &cap_r_1, &cap_r_2, session);
+    if c != c_tilde {
+        return Err(Error::VerifyFailed);
+    }
+
+    Ok(())
+}
+
+impl PartialEvalG2 {
+    pub fn verify(&self, dkg_config: &DkgConfig, input: &[u8], vk: &BnG2) -> Result<(), Error> {
+        if self.index > dkg_config.number_of_members() || self.index < 1 {
+            return Err(Error::InvalidIndex { index: self.index });
+        };
+
+        let session = dkg_config.session();
+        let h = hash_to_g2(&session, input);
+
+        dleq_verify_g2(&h, vk, &self.value, &self.proof, &session)
+    }
+}
+
+// keccak hash of the proof point as the final random value
+fn pseudo_random_value_g2(proof: &BnG2) -> Vec<u8> {
+    Keccak256::new()
+        .chain_update(g2_to_bytes(proof))
+        .finalize()
+        .to_vec()
+}
+
+// obtain final random from t partial evaluations in G2
+pub fn combine_partial_evaluations_g2(
+    dkg_config: &DkgConfig,
+    sigmas: &[PartialEvalG2],
+) -> Result<PseudoRandomG2, Error> {
+    assert_eq!(sigmas.len(), dkg_config.threshold());
+
+    let indices: Vec<_> = sigmas.iter().map(|sigma| sigma.index).collect();
+    check_indices(dkg_config.number_of_members(), &indices)?;
+
+    let lambdas = lagrange_coefficients(&indices);
+    let values: Vec<_> = sigmas.iter().map(|sigma| sigma.value).collect();
+
+    let proof = best_multiexp(&lambdas, &values).to_affine();
+    let value = pseudo_random_value_g2(&proof);
+
+    Ok(PseudoRandomG2 { proof, value })
+}
+
+impl PseudoRandomG2 {
+    pub fn new(proof: BnG2, value: Vec<u8>) -> Self {
+        Self { proof, value }
+    }
+
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    pub fn proof(&self) -> &BnG2 {
+        &self.proof
+    }
+
+    pub fn verify(&self, input: &[u8], ga: &BnG1) -> Result<(), Error> {
+        self.verify_in_session(&SessionId::default(), input, ga)
+    }
+
+    // check e(g, sigma) == e(ga, h) with h hashed in the domain of the session
+    pub fn verify_in_session(
+        &self,
+        session: &SessionId,
+        input: &[u8],
+        ga: &BnG1,
+    ) -> Result<(), Error> {
+        let h = hash_to_g2(session, input);
+
+        let g = BnG1::generator();
+        let sigma_prepared = G2Prepared::from_affine(self.proof);
+        let h_prepared = G2Prepared::from_affine(h);
+
+        let t =
+            multi_miller_loop(&[(&-g, &sigma_prepared), (ga, &h_prepared)]).final_exponentiation();
+        if !bool::from(t.is_identity()) {
+            return Err(Error::VerifyFailed);
+        }
+
+        let value = pseudo_random_value_g2(&self.proof);
+        if !self.value.as_slice().eq(&value) {
+            return Err(Error::VerifyFailed);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dkg::shares;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_pseudo_random_g2() {
+        let mut rng = OsRng;
+
+        let g = BnG1::generator();
+
+        let dkg_config = DkgConfig::new(4, 6).unwrap();
+        let coeffs: Vec<_> = (0..dkg_config.threshold())
+            .map(|_| BnScalar::random(&mut rng))
+            .collect();
+        let shares = shares(dkg_config.number_of_members(), &coeffs);
+        let keys: Vec<_> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, s)| DkgShareKey::new(i + 1, *s, (g * s).to_affine()))
+            .collect();
+        let vks2: Vec<_> = keys.iter().map(|key| key.verify_key_g2()).collect();
+        let ga = (g * coeffs[0]).to_affine();
+
+        let input = b"test first random in g2";
+        let evals: Vec<_> = keys
+            .iter()
+            .map(|key| key.evaluate_g2(input, &mut rng))
+            .collect();
+
+        for (e, vk) in evals.iter().zip(vks2.iter()) {
+            e.verify(&dkg_config, input, vk).unwrap();
+        }
+        // a partial evaluation does not verify against another member's key
+        assert!(evals[0].verify(&dkg_config, input, &vks2[1]).is_err());
+
+        let pseudo_random =
+            combine_partial_evaluations_g2(&dkg_config, &evals[1..1 + dkg_config.threshold()])
+                .unwrap();
+        pseudo_random.verify(input, &ga).unwrap();
+        assert!(pseudo_random.verify(b"another input", &ga).is_err());
+
+        // any t evaluations give the same value
+        let other =
+            combine_partial_evaluations_g2(&dkg_config, &evals[0..dkg_config.threshold()]).unwrap();
+        assert_eq!(other.value(), pseudo_random.value());
+
+        // keys bound to a session hash into the session domain and bind the proofs to the session
+        let session = SessionId::new(BnScalar::random(&mut rng));
+        let bound_config = dkg_config.with_session(session);
+        let bound: Vec<_> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, s)| DkgShareKey::new(i + 1, *s, (g * s).to_affine()).with_session(session))
+            .collect();
+        let evals: Vec<_> = bound
+            .iter()
+            .map(|key| key.evaluate_g2(input, &mut rng))
+            .collect();
+        evals[0].verify(&bound_config, input, &vks2[0]).unwrap();
+        assert!(evals[0].verify(&dkg_config, input, &vks2[0]).is_err());
+        let other_config = dkg_config.with_session(SessionId::new(BnScalar::one()));
+        assert!(evals[0].verify(&other_config, input, &vks2[0]).is_err());
+
+        let bound_random =
+            combine_partial_evaluations_g2(&bound_config, &evals[0..dkg_config.threshold()])
+                .unwrap();
+        bound_random
+            .verify_in_session(&session, input, &ga)
+            .unwrap();
+        assert!(bound_random.verify(input, &ga).is_err());
+        assert_ne!(bound_random.value(), pseudo_random.value());
+    }
+}
@@ -0,0 +1,157 @@
+use super::{pseudo_random_value, PseudoRandom, SessionId, EVAL_PREFIX};
+use crate::error::Error;
+use crate::utils::hash_to_curve_bn;
+use halo2_ecc::halo2::halo2curves::bn256::G2Prepared;
+use halo2_maingate::halo2::halo2curves::bn256::multi_miller_loop;
+use halo2wrong::curves::bn256::{Fr as BnScalar, G1Affine as BnG1, G2Affine as BnG2, G1};
+use halo2wrong::curves::group::prime::PrimeCurveAffine;
+use halo2wrong::curves::group::{Curve, Group};
+use halo2wrong::curves::pairing::MillerLoopResult;
+use halo2wrong::halo2::arithmetic::{best_multiexp, Field};
+use rand_core::RngCore;
+
+// group key with prepared pairing lines for gpk and g2, reused across verifications
+pub struct PreparedGroupKey {
+    gpk: BnG2,
+    gpk_prepared: G2Prepared,
+    g2_prepared: G2Prepared,
+    hasher: Box<dyn Fn(&[u8]) -> G1>,
+}
+
+impl PreparedGroupKey {
+    pub fn new(gpk: &BnG2) -> Self {
+        PreparedGroupKey {
+            gpk: *gpk,
+            gpk_prepared: G2Prepared::from_affine(*gpk),
+            g2_prepared: G2Prepared::from_affine(BnG2::generator()),
+            hasher: hash_to_curve_bn(EVAL_PREFIX),
+        }
+    }
+
+    // hash inputs with the pseudorandom domain of a session
+    pub fn with_session(mut self, session: &SessionId) -> Self {
+        self.hasher = session.hasher();
+        self
+    }
+
+    pub fn gpk(&self) -> &BnG2 {
+        &self.gpk
+    }
+
+    // check e(h, gpk) == e(sigma, g2)
+    fn check_pairing(&self, h: &BnG1, sigma: &BnG1) -> Result<(), Error> {
+        let t = multi_miller_loop(&[(&-*h, &self.gpk_prepared), (sigma, &self.g2_prepared)])
+            .final_exponentiation();
+
+        if !bool::from(t.is_identity()) {
+            return Err(Error::VerifyFailed);
+        }
+
+        Ok(())
+    }
+
+    fn check_value(pseudo: &PseudoRandom) -> Result<(), Error> {
+        let value = pseudo_random_value(&pseudo.proof);
+        if !pseudo.value.as_slice().eq(&value) {
+            return Err(Error::VerifyFailed);
+        }
+
+        Ok(())
+    }
+
+    pub fn verify(&self, input: &[u8], pseudo: &PseudoRandom) -> Result<(), Error> {
+        let h: BnG1 = (self.hasher)(input).to_affine();
+        self.check_pairing(&h, &pseudo.proof)?;
+
+        Self::check_value(pseudo)
+    }
+
+    // check e(sum(rho_i * h_i), gpk) == e(sum(rho_i * sigma_i), g2) with random rho_i
+    pub fn verify_batch(
+        &self,
+        outputs: &[(&[u8], &PseudoRandom)],
+        mut rng: impl RngCore,
+    ) -> Result<(), Error> {
+        if outputs.is_empty() {
+            return Ok(());
+        }
+
+        for (_, pseudo) in outputs.iter() {
+            Self::check_value(pseudo)?;
+        }
+
+        let rhos: Vec<_> = outputs.iter().map(|_| BnScalar::random(&mut rng)).collect();
+        let hs: Vec<_> = outputs
+            .iter()
+            .map(|(input, _)| (self.hasher)(*input))
+            .collect();
+        let mut hs_affine = vec![BnG1::identity(); hs.len()];
+        G1::batch_normalize(&hs, &mut hs_affine);
+        let sigmas: Vec<_> = outputs.iter().map(|(_, pseudo)| pseudo.proof).collect();
+
+        let h = best_multiexp(&rhos, &hs_affine).to_affine();
+        let sigma = best_multiexp(&rhos, &sigmas).to_affine();
+
+        self.check_pairing(&h, &sigma)
+    }
+
+    // check if ga has the same exponent as gpk
+    pub fn is_dl_equal(&self, ga: &BnG1) -> Result<(), Error> {
+        let g = BnG1::generator();
+        self.check_pairing(&g, ga)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dkg::keygen;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_prepared_group_key() {
+        let mut rng = OsRng;
+
+        let (a, ga) = keygen(&mut rng);
+        let g2 = BnG2::generator();
+        let gpk = (g2 * a).to_affine();
+        let prepared = PreparedGroupKey::new(&gpk);
+        prepared.is_dl_equal(&ga).unwrap();
+
+        let hasher = hash_to_curve_bn(EVAL_PREFIX);
+        let inputs: Vec<Vec<u8>> = (0..5)
+            .map(|i| format!("prepared random {i}").into_bytes())
+            .collect();
+        let randoms: Vec<_> = inputs
+            .iter()
+            .map(|input| {
+                let proof = (hasher(input).to_affine() * a).to_affine();
+                PseudoRandom::new(proof, pseudo_random_value(&proof))
+            })
+            .collect();
+
+        for (input, random) in inputs.iter().zip(randoms.iter()) {
+            prepared.verify(input, random).unwrap();
+        }
+
+        let outputs: Vec<_> = inputs
+            .iter()
+            .map(|input| input.as_slice())
+            .zip(randoms.iter())
+            .collect();
+        prepared.verify_batch(&outputs, &mut rng).unwrap();
+
+        // one output for the wrong input fails the whole batch
+        let mut forged = outputs.clone();
+        forged[2].0 = b"another input";
+        assert!(prepared.verify_batch(&forged, &mut rng).is_err());
+
+        // a key bound to a session hashes into the session domain
+        let session = SessionId::new(BnScalar::from(7));
+        let bound = PreparedGroupKey::new(&gpk).with_session(&session);
+        let proof = (session.hasher()(&inputs[0]).to_affine() * a).to_affine();
+        let random = PseudoRandom::new(proof, pseudo_random_value(&proof));
+        bound.verify(&inputs[0], &random).unwrap();
+        assert!(prepared.verify(&inputs[0], &random).is_err());
+    }
+}
@@ -2,11 +2,13 @@ use crate::dkg::DkgConfig;
 #[cfg(feature = "g2chip")]
 use crate::ecc_chip::FixedPoint2Chip;
 use crate::ecc_chip::FixedPointChip;
+use crate::encryption::{DealerContext, INDEX_BITS};
+use crate::error::Error;
 use crate::grumpkin_chip::GrumpkinChip;
 use crate::poseidon::P128Pow5T3Bn;
 use crate::{
-    BIT_LEN_LIMB, NUMBER_OF_LIMBS, POSEIDON_LEN, POSEIDON_RATE, POSEIDON_WIDTH, WINDOW_SIZE,
-    WRAP_LEN,
+    BIT_LEN_LIMB, GRUMPKIN_WINDOW_SIZE, NUMBER_OF_LIMBS, POSEIDON_KEY_LEN, POSEIDON_LEN,
+    POSEIDON_RATE, POSEIDON_WIDTH, WINDOW_SIZE, WRAP_LEN,
 };
 use halo2_ecc::integer::rns::Rns;
 #[cfg(feature = "g2chip")]
@@ -17,7 +19,7 @@ use halo2_gadgets::poseidon::{
     primitives::ConstantLength, Hash as PoseidonHash, Pow5Chip, Pow5Config,
 };
 use halo2_maingate::{
-    MainGate, MainGateConfig, MainGateInstructions, RangeChip, RangeConfig, RangeInstructions,
+    MainGate, MainGateConfig, MainGateInstructions, RangeChip, RangeConfig, RangeInstructions, Term,
 };
 #[cfg(feature = "g2chip")]
 use halo2wrong::curves::bn256::G2Affine as BnG2;
@@ -30,6 +32,65 @@ use halo2wrong::halo2::{
     circuit::{Layouter, SimpleFloorPlanner, Value},
     plonk::{Circuit, ConstraintSystem, Error as PlonkError},
 };
+use halo2wrong::utils::DimensionMeasurement;
+use serde::{Deserialize, Serialize};
+
+// windowed multiplication needs at least two bits per window
+pub const MIN_WINDOW_SIZE: usize = 2;
+pub const MAX_WINDOW_SIZE: usize = 8;
+
+// circuit options that change the proving key but not the dkg protocol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DkgCircuitParams {
+    // window size of the fixed base multiplications in G1 and G2
+    pub window_size: usize,
+    // decompose r into canonical bits checked against the scalar modulus
+    #[serde(default)]
+    pub canonical_random: bool,
+    // window size of the fixed base multiplication g^r on grumpkin
+    #[serde(default = "default_grumpkin_window_size")]
+    pub grumpkin_window_size: usize,
+}
+
+fn default_grumpkin_window_size() -> usize {
+    GRUMPKIN_WINDOW_SIZE
+}
+
+impl DkgCircuitParams {
+    pub fn new(window_size: usize) -> Result<Self, Error> {
+        if window_size < MIN_WINDOW_SIZE || window_size > MAX_WINDOW_SIZE {
+            return Err(Error::InvalidWindowSize { window_size });
+        }
+
+        Ok(DkgCircuitParams {
+            window_size,
+            ..Default::default()
+        })
+    }
+
+    pub fn with_grumpkin_window_size(self, grumpkin_window_size: usize) -> Result<Self, Error> {
+        if grumpkin_window_size < MIN_WINDOW_SIZE || grumpkin_window_size > MAX_WINDOW_SIZE {
+            return Err(Error::InvalidWindowSize {
+                window_size: grumpkin_window_size,
+            });
+        }
+
+        Ok(DkgCircuitParams {
+            grumpkin_window_size,
+            ..self
+        })
+    }
+}
+
+impl Default for DkgCircuitParams {
+    fn default() -> Self {
+        DkgCircuitParams {
+            window_size: WINDOW_SIZE,
+            canonical_random: false,
+            grumpkin_window_size: GRUMPKIN_WINDOW_SIZE,
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct DkgCircuitConfig {
@@ -107,6 +168,12 @@ pub struct DkgCircuit {
     random: Value<BnScalar>,
     public_keys: Vec<Value<GkG1>>,
     grumpkin_aux_generator: Value<GkG1>,
+    // coefficient and member selector bits of a padded circuit
+    selectors: Vec<Value<BnScalar>>,
+    // dealer index and session the ciphers are bound to
+    dealer_index: Value<BnScalar>,
+    session: Value<BnScalar>,
+    circuit_params: DkgCircuitParams,
 }
 
 impl DkgCircuit {
@@ -117,8 +184,14 @@ impl DkgCircuit {
         public_keys: Vec<Value<GkG1>>,
         grumpkin_aux_generator: Value<GkG1>,
     ) -> Self {
-        assert_eq!(coeffs.len(), dkg_config.threshold());
-        assert_eq!(public_keys.len(), dkg_config.number_of_members());
+        assert_eq!(coeffs.len(), dkg_config.max_threshold());
+        assert_eq!(public_keys.len(), dkg_config.max_number_of_members());
+
+        let selectors: Vec<_> = dkg_config
+            .selectors()
+            .into_iter()
+            .map(Value::known)
+            .collect();
 
         DkgCircuit {
             dkg_config,
@@ -126,18 +199,27 @@ impl DkgCircuit {
             random,
             public_keys,
             grumpkin_aux_generator,
+            selectors,
+            dealer_index: Value::known(BnScalar::zero()),
+            session: Value::known(dkg_config.session().value()),
+            circuit_params: DkgCircuitParams::default(),
         }
     }
 
     pub fn dummy(dkg_config: DkgConfig) -> Self {
-        let coeffs: Vec<_> = (0..dkg_config.threshold())
+        let coeffs: Vec<_> = (0..dkg_config.max_threshold())
             .map(|_| Value::unknown())
             .collect();
         let random = Value::unknown();
-        let public_keys: Vec<_> = (0..dkg_config.number_of_members())
+        let public_keys: Vec<_> = (0..dkg_config.max_number_of_members())
             .map(|_| Value::unknown())
             .collect();
         let grumpkin_aux_generator = Value::unknown();
+        let selectors: Vec<_> = dkg_config
+            .selectors()
+            .iter()
+            .map(|_| Value::unknown())
+            .collect();
 
         DkgCircuit {
             dkg_config,
@@ -145,26 +227,115 @@ impl DkgCircuit {
             random,
             public_keys,
             grumpkin_aux_generator,
+            selectors,
+            dealer_index: Value::unknown(),
+            session: Value::unknown(),
+            circuit_params: DkgCircuitParams::default(),
         }
     }
 
+    // number of coefficient slots, which is t_max in padded mode
     pub fn threshold(&self) -> usize {
-        self.dkg_config.threshold()
+        self.dkg_config.max_threshold()
     }
 
+    // number of member slots, which is n_max in padded mode
     pub fn number_of_members(&self) -> usize {
-        self.dkg_config.number_of_members()
+        self.dkg_config.max_number_of_members()
+    }
+
+    pub fn is_padded(&self) -> bool {
+        self.dkg_config.is_padded()
     }
+
+    pub fn with_circuit_params(mut self, circuit_params: DkgCircuitParams) -> Self {
+        self.circuit_params = circuit_params;
+        self
+    }
+
+    pub fn circuit_params(&self) -> DkgCircuitParams {
+        self.circuit_params
+    }
+
+    pub fn with_context(mut self, context: DealerContext) -> Self {
+        self.dealer_index = Value::known(BnScalar::from(context.dealer_index() as u64));
+        self.session = Value::known(context.session().value());
+        self
+    }
+
+    // smallest degree k such that the circuit fits into 2^k rows
+    pub fn degree(&self) -> Result<u32, PlonkError> {
+        let dimension = DimensionMeasurement::measure(self)?;
+        Ok(dimension.k())
+    }
+}
+
+// measure the circuit for window sizes MIN_WINDOW_SIZE..=max_window_size and return the
+// smallest degree, preferring the smaller window if several windows give the same degree.
+// the grumpkin window is then chosen the same way with the best G1 and G2 window
+pub fn recommend_circuit_params(
+    dkg_config: DkgConfig,
+    max_window_size: usize,
+) -> Result<(DkgCircuitParams, u32), PlonkError> {
+    assert!(max_window_size >= MIN_WINDOW_SIZE && max_window_size <= MAX_WINDOW_SIZE);
+
+    let mut best: Option<(DkgCircuitParams, u32)> = None;
+    for window_size in MIN_WINDOW_SIZE..=max_window_size {
+        let circuit_params = DkgCircuitParams {
+            window_size,
+            ..Default::default()
+        };
+        let degree = DkgCircuit::dummy(dkg_config)
+            .with_circuit_params(circuit_params)
+            .degree()?;
+        log::info!("window size {window_size}: degree {degree}");
+
+        if best.map_or(true, |(_, k)| degree < k) {
+            best = Some((circuit_params, degree));
+        }
+    }
+
+    let (window_params, _) = best.unwrap();
+    for grumpkin_window_size in MIN_WINDOW_SIZE..=max_window_size {
+        let circuit_params = DkgCircuitParams {
+            grumpkin_window_size,
+            ..window_params
+        };
+        let degree = DkgCircuit::dummy(dkg_config)
+            .with_circuit_params(circuit_params)
+            .degree()?;
+        log::info!("grumpkin window size {grumpkin_window_size}: degree {degree}");
+
+        if best.map_or(true, |(_, k)| degree < k) {
+            best = Some((circuit_params, degree));
+        }
+    }
+
+    Ok(best.unwrap())
 }
 
 impl Circuit<BnScalar> for DkgCircuit {
     type Config = DkgCircuitConfig;
     type FloorPlanner = SimpleFloorPlanner;
     #[cfg(feature = "circuit-params")]
-    type Params = ();
+    type Params = (DkgConfig, DkgCircuitParams);
 
     fn without_witnesses(&self) -> Self {
-        unimplemented!()
+        DkgCircuit::dummy(self.dkg_config).with_circuit_params(self.circuit_params)
+    }
+
+    #[cfg(feature = "circuit-params")]
+    fn params(&self) -> Self::Params {
+        (self.dkg_config, self.circuit_params)
+    }
+
+    // the columns do not depend on params; the shape is fixed by the circuit when synthesizing
+    #[cfg(feature = "circuit-params")]
+    fn configure_with_params(
+        meta: &mut ConstraintSystem<BnScalar>,
+        _params: Self::Params,
+    ) -> Self::Config {
+        DkgCircuitConfig::new(meta)
     }
 
     fn configure(meta: &mut ConstraintSystem<BnScalar>) -> Self::Config {
@@ -190,8 +361,10 @@ impl Circuit<BnScalar> for DkgCircuit {
 
         let main_gate = MainGate::<BnScalar>::new(config.main_gate_config.clone());
         let mut grumpkin_chip = GrumpkinChip::new(config.main_gate_config.clone());
+        grumpkin_chip
+            .prepare_fixed_point(&GkG1::generator(), self.circuit_params.grumpkin_window_size)?;
 
-        let (shares, a) = layouter.assign_region(
+        let (shares, exponents, a, coeff_bits, member_bits) = layouter.assign_region(
             || "region compute shares from coefficients",
             |region| {
                 let offset = 0;
@@ -203,6 +376,37 @@ impl Circuit<BnScalar> for DkgCircuit {
                     coeffs.push(a_assigned);
                 }
 
+                // selector bits for padding; assign_bit constrains each bit to be boolean
+                let mut selectors = vec![];
+                for b in self.selectors.iter() {
+                    let b_assigned = main_gate.assign_bit(ctx, *b)?;
+                    selectors.push(b_assigned);
+                }
+                let member_bits = selectors.split_off(selectors.len().min(self.threshold()));
+                let coeff_bits = selectors;
+
+                if self.is_padded() {
+                    // at least one coefficient is active
+                    main_gate.assert_one(ctx, &coeff_bits[0])?;
+
+                    for j in 0..self.threshold() {
+                        // coeff * (1 - c) = 0
+                        let masked = main_gate.mul(ctx, &coeffs[j], &coeff_bits[j])?;
+                        main_gate.assert_equal(ctx, &masked, &coeffs[j])?;
+                        // c <= b, i.e., threshold <= number of members
+                        let c = main_gate.mul(ctx, &coeff_bits[j], &member_bits[j])?;
+                        main_gate.assert_equal(ctx, &c, &coeff_bits[j])?;
+                    }
+
+                    // active slots form a prefix: bit_{j+1} * (1 - bit_j) = 0
+                    for bits in [&coeff_bits, &member_bits] {
+                        for j in 1..bits.len() {
+                            let b = main_gate.mul(ctx, &bits[j], &bits[j - 1])?;
+                            main_gate.assert_equal(ctx, &b, &bits[j])?;
+                        }
+                    }
+                }
+
                 let mut shares = vec![];
 
                 // compute s0
@@ -222,7 +426,26 @@ impl Circuit<BnScalar> for DkgCircuit {
                     shares.push(s);
                 }
 
-                Ok((shares, coeffs[0].clone()))
+                // inactive members expose g^1 instead of g^s
+                let exponents = if self.is_padded() {
+                    let one = main_gate.assign_constant(ctx, BnScalar::one())?;
+                    let mut exponents = vec![];
+                    for (s, b) in shares.iter().zip(member_bits.iter()) {
+                        let e = main_gate.select(ctx, s, &one, b)?;
+                        exponents.push(e);
+                    }
+                    exponents
+                } else {
+                    shares.clone()
+                };
+
+                Ok((
+                    shares,
+                    exponents,
+                    coeffs[0].clone(),
+                    coeff_bits,
+                    member_bits,
+                ))
             },
         )?;
 
@@ -232,12 +455,12 @@ impl Circuit<BnScalar> for DkgCircuit {
                 let offset = 0;
                 let ctx = &mut RegionCtx::new(region, offset);
                 let g = BnG1::generator();
-                fixed_chip.assign_fixed_point(ctx, &g, WINDOW_SIZE)?;
+                fixed_chip.assign_fixed_point(ctx, &g, self.circuit_params.window_size)?;
 
                 #[cfg(feature = "g2chip")]
                 let g2 = BnG2::generator();
                 #[cfg(feature = "g2chip")]
-                fixed2_chip.assign_fixed_point(ctx, &g2, WINDOW_SIZE)?;
+                fixed2_chip.assign_fixed_point(ctx, &g2, self.circuit_params.window_size)?;
 
                 Ok(())
             },
@@ -285,7 +508,7 @@ impl Circuit<BnScalar> for DkgCircuit {
                     let ctx = &mut RegionCtx::new(region, offset);
 
                     // gs = g^s
-                    let gs = fixed_chip.mul(ctx, &shares[i])?;
+                    let gs = fixed_chip.mul(ctx, &exponents[i])?;
                     // normalise for public inputs
                     let gs = fixed_chip.normalize(ctx, &gs)?;
                     Ok(gs)
@@ -321,22 +544,54 @@ impl Circuit<BnScalar> for DkgCircuit {
             layouter.namespace(|| "bn256 G2 point g2^a"),
             g2a,
             WRAP_LEN,
-            Some(assigned_base),
+            Some(assigned_base.clone()),
             &mut instance_offset,
         )?;
 
+        // compute g2^s for verifying partial evaluations in G2
+        #[cfg(feature = "g2shares")]
+        for i in 0..self.number_of_members() {
+            let g2s = layouter.assign_region(
+                || "region mul g2^s",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let g2s = fixed2_chip.mul(ctx, &exponents[i])?;
+                    let g2s = fixed2_chip.normalize(ctx, &g2s)?;
+
+                    Ok(g2s)
+                },
+            )?;
+
+            fixed2_chip.expose_public_optimal(
+                layouter.namespace(|| "bn256 G2 point g2^s"),
+                g2s,
+                WRAP_LEN,
+                Some(assigned_base.clone()),
+                &mut instance_offset,
+            )?;
+        }
+
         let (bits, gr) = layouter.assign_region(
             || "region grumpkin ecc mul g^r",
             |region| {
                 let offset = 0;
                 let ctx = &mut RegionCtx::new(region, offset);
 
-                let g = grumpkin_chip.assign_constant(ctx, GkG1::generator())?;
-
-                // we don't care about the value of r; if r==0, add will fail
-                let bits = grumpkin_chip.to_bits_unsafe(ctx, &self.random)?;
-                // gr = g^r
-                let gr = grumpkin_chip.mul_bits(ctx, &g, &bits)?;
+                let bits = if self.circuit_params.canonical_random {
+                    // r is a bn256 scalar, which is smaller than the grumpkin group order,
+                    // so canonical bits of a nonzero r give a nonzero grumpkin scalar
+                    let r = main_gate.assign_value(ctx, self.random)?;
+                    main_gate.assert_not_zero(ctx, &r)?;
+                    grumpkin_chip.to_bits_canonical(ctx, &r)?
+                } else {
+                    // we don't care about the value of r since the same bits are used for g^r and pk^r;
+                    // if r==0, add will fail
+                    grumpkin_chip.to_bits_unsafe(ctx, &self.random)?
+                };
+                // gr = g^r with the windowed table of the generator
+                let gr = grumpkin_chip.mul_fixed_bits(ctx, &bits)?;
 
                 Ok((bits, gr))
             },
@@ -348,32 +603,67 @@ impl Circuit<BnScalar> for DkgCircuit {
             &mut instance_offset,
         )?;
 
-        let mut assigned_pks = vec![];
-        for i in 0..self.number_of_members() {
-            let (pkr, pk) = layouter.assign_region(
-                || "region grumpkin ecc mul encryption",
-                |region| {
-                    let offset = 0;
-                    let ctx = &mut RegionCtx::new(region, offset);
+        let (pkrs, assigned_pks) = layouter.assign_region(
+            || "region grumpkin ecc mul encryption",
+            |region| {
+                let offset = 0;
+                let ctx = &mut RegionCtx::new(region, offset);
 
-                    let pk = grumpkin_chip.assign_point(ctx, self.public_keys[i])?;
-                    // pkr = pk^r
-                    let pkr = grumpkin_chip.mul_bits(ctx, &pk, &bits)?;
+                let pks = self
+                    .public_keys
+                    .iter()
+                    .map(|pk| grumpkin_chip.assign_point(ctx, *pk))
+                    .collect::<Result<Vec<_>, PlonkError>>()?;
+                // reject duplicate keys and keys colliding with aux; only active members are
+                // checked in a padded circuit, where unused slots all take the generator
+                let active = self.is_padded().then_some(member_bits.as_slice());
+                grumpkin_chip.assert_distinct(ctx, &pks, active)?;
+                // pkr = pk^r for all members with the same bits of r
+                let pkrs = grumpkin_chip.mul_bits_many(ctx, &pks, &bits)?;
+
+                Ok((pkrs, pks))
+            },
+        )?;
 
-                    Ok((pkr, pk))
-                },
-            )?;
+        // nonce_i = dealer_index * 2^INDEX_BITS + i for recipient index i;
+        // the dealer index is decomposed into INDEX_BITS bits, so nonces cannot collide across dealers
+        let (dealer_index, session, nonces) = layouter.assign_region(
+            || "region dealer context",
+            |region| {
+                let offset = 0;
+                let ctx = &mut RegionCtx::new(region, offset);
 
-            assigned_pks.push(pk);
+                let dealer_index = main_gate.assign_value(ctx, self.dealer_index)?;
+                main_gate.to_bits(ctx, &dealer_index, INDEX_BITS)?;
+                let session = main_gate.assign_value(ctx, self.session)?;
+                let nonces = (1..=self.number_of_members())
+                    .map(|i| {
+                        main_gate.compose(
+                            ctx,
+                            &[Term::Assigned(
+                                &dealer_index,
+                                BnScalar::from(1u64 << INDEX_BITS),
+                            )],
+                            BnScalar::from(i as u64),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, PlonkError>>()?;
+
+                Ok((dealer_index, session, nonces))
+            },
+        )?;
 
-            let message = [pkr.x, pkr.y];
+        let mut tags = vec![];
+        for (i, (pkr, nonce)) in pkrs.into_iter().zip(nonces.into_iter()).enumerate() {
+            // mask = Poseidon(pk^r, session, nonce)
+            let message = [pkr.x, pkr.y, session.clone(), nonce];
 
             let poseidon_chip = Pow5Chip::construct(config.poseidon_config.clone());
             let hasher = PoseidonHash::<
                 _,
                 _,
                 P128Pow5T3Bn,
-                ConstantLength<POSEIDON_LEN>,
+                ConstantLength<POSEIDON_KEY_LEN>,
                 POSEIDON_WIDTH,
                 POSEIDON_RATE,
             >::init(poseidon_chip, layouter.namespace(|| "poseidon init"))?;
@@ -384,10 +674,43 @@ impl Circuit<BnScalar> for DkgCircuit {
                 |region| {
                     let offset = 0;
                     let ctx = &mut RegionCtx::new(region, offset);
-                    main_gate.add(ctx, &shares[i], &key)
+                    let cipher = main_gate.add(ctx, &shares[i], &key)?;
+                    // ciphers of inactive members are zero
+                    if self.is_padded() {
+                        main_gate.mul(ctx, &cipher, &member_bits[i])
+                    } else {
+                        Ok(cipher)
+                    }
                 },
             )?;
 
+            // tag = Poseidon(mask, cipher)
+            let poseidon_chip = Pow5Chip::construct(config.poseidon_config.clone());
+            let hasher =
+                PoseidonHash::<
+                    _,
+                    _,
+                    P128Pow5T3Bn,
+                    ConstantLength<POSEIDON_LEN>,
+                    POSEIDON_WIDTH,
+                    POSEIDON_RATE,
+                >::init(poseidon_chip, layouter.namespace(|| "poseidon init tag"))?;
+            let tag = hasher.hash(layouter.namespace(|| "hash tag"), [key, cipher.clone()])?;
+            // tags of inactive members are zero
+            let tag = if self.is_padded() {
+                layouter.assign_region(
+                    || "region tag",
+                    |region| {
+                        let offset = 0;
+                        let ctx = &mut RegionCtx::new(region, offset);
+                        main_gate.mul(ctx, &tag, &member_bits[i])
+                    },
+                )?
+            } else {
+                tag
+            };
+            tags.push(tag);
+
             main_gate.expose_public(
                 layouter.namespace(|| "cipher main"),
                 cipher,
@@ -396,10 +719,26 @@ impl Circuit<BnScalar> for DkgCircuit {
             instance_offset += 1;
         }
 
+        for tag in tags.into_iter() {
+            main_gate.expose_public(layouter.namespace(|| "tag"), tag, instance_offset)?;
+            instance_offset += 1;
+        }
+
+        for value in [dealer_index, session] {
+            main_gate.expose_public(layouter.namespace(|| "context"), value, instance_offset)?;
+            instance_offset += 1;
+        }
+
+        // public keys of inactive members are fixed to the generator by the instance
         for pk in assigned_pks.into_iter() {
             grumpkin_chip.expose_public(layouter.namespace(|| "pk"), pk, &mut instance_offset)?;
         }
 
+        for bit in coeff_bits.into_iter().chain(member_bits.into_iter()) {
+            main_gate.expose_public(layouter.namespace(|| "selector"), bit, instance_offset)?;
+            instance_offset += 1;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,258 @@
+use crate::dkg::SessionId;
+use crate::error::Error;
+use crate::hash_to_curve_evm::from_be_bytes;
+use crate::poseidon::P128Pow5T3Bn;
+use crate::{POSEIDON_KEY_LEN, POSEIDON_LEN, POSEIDON_RATE, POSEIDON_WIDTH};
+use halo2_gadgets::poseidon::primitives::{ConstantLength, Hash};
+use halo2wrong::curves::bn256::Fr as BnScalar;
+use halo2wrong::curves::ff::{Field, PrimeField};
+use halo2wrong::curves::group::prime::PrimeCurveAffine;
+use halo2wrong::curves::group::Curve;
+use halo2wrong::curves::grumpkin::{Fr as GkScalar, G1Affine as GkG1};
+use halo2wrong::curves::CurveAffine;
+use rand_core::RngCore;
+use sha3::{Digest, Keccak256};
+
+// dealer and recipient indices are packed into one nonce, each below 2^INDEX_BITS
+pub const INDEX_BITS: usize = 32;
+
+// context every share encryption of a dealing is bound to;
+// dealer index 0 means the dealer is not bound
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DealerContext {
+    session: SessionId,
+    dealer_index: usize,
+}
+
+impl DealerContext {
+    pub fn new(session: SessionId, dealer_index: usize) -> Self {
+        assert!(dealer_index < 1 << INDEX_BITS);
+        DealerContext {
+            session,
+            dealer_index,
+        }
+    }
+
+    // read back from the dealer index and session in a dkg instance
+    pub fn from_public(dealer_index: BnScalar, session: BnScalar) -> Result<Self, Error> {
+        let repr = dealer_index.to_repr();
+        let (low, high) = repr.as_ref().split_at(8);
+        let index = u64::from_le_bytes(low.try_into().unwrap());
+        if high.iter().any(|b| *b != 0) || index >= 1 << INDEX_BITS {
+            return Err(Error::InvalidIndex {
+                index: index as usize,
+            });
+        }
+
+        Ok(Self::new(SessionId::new(session), index as usize))
+    }
+
+    pub fn session(&self) -> SessionId {
+        self.session
+    }
+
+    pub fn dealer_index(&self) -> usize {
+        self.dealer_index
+    }
+
+    // dealer_index * 2^INDEX_BITS + recipient_index
+    pub fn nonce(&self, recipient_index: usize) -> BnScalar {
+        assert!(recipient_index < 1 << INDEX_BITS);
+        BnScalar::from(self.dealer_index as u64) * BnScalar::from(1u64 << INDEX_BITS)
+            + BnScalar::from(recipient_index as u64)
+    }
+
+    // mask = Poseidon(pk^r, session, nonce), so ciphers cannot be moved across
+    // sessions, dealers or recipients
+    pub fn mask(&self, pkr: &GkG1, recipient_index: usize) -> BnScalar {
+        let poseidon = Hash::<
+            _,
+            P128Pow5T3Bn,
+            ConstantLength<POSEIDON_KEY_LEN>,
+            POSEIDON_WIDTH,
+            POSEIDON_RATE,
+        >::init();
+        poseidon.hash([
+            pkr.x,
+            pkr.y,
+            self.session.value(),
+            self.nonce(recipient_index),
+        ])
+    }
+
+    // cipher = share + mask and tag = Poseidon(mask, cipher)
+    pub fn encrypt(
+        &self,
+        pkr: &GkG1,
+        recipient_index: usize,
+        share: &BnScalar,
+    ) -> (BnScalar, BnScalar) {
+        let mask = self.mask(pkr, recipient_index);
+        let cipher = share + mask;
+
+        (cipher, encryption_tag(&mask, &cipher))
+    }
+
+    pub fn decrypt(
+        &self,
+        pkr: &GkG1,
+        recipient_index: usize,
+        cipher: &BnScalar,
+        tag: &BnScalar,
+    ) -> Result<BnScalar, Error> {
+        let mask = self.mask(pkr, recipient_index);
+        if encryption_tag(&mask, cipher) != *tag {
+            return Err(Error::InvalidTag {
+                index: self.dealer_index,
+            });
+        }
+
+        Ok(cipher - mask)
+    }
+}
+
+// pk^r revealed by a recipient with a chaum-pedersen proof that log_g(pk) == log_gr(pk^r),
+// so anyone can decrypt the cipher without learning the recipient's secret key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecryptionProof {
+    pub pkr: GkG1,
+    pub c: GkScalar,
+    pub z: GkScalar,
+}
+
+// keccak of (g, pk, gr, pk^r, R1, R2, cipher) in big endian, same as abi.encodePacked in solidity,
+// reduced modulo the grumpkin order
+fn decryption_challenge(
+    pk: &GkG1,
+    gr: &GkG1,
+    pkr: &GkG1,
+    cap_r_1: &GkG1,
+    cap_r_2: &GkG1,
+    cipher: &BnScalar,
+) -> GkScalar {
+    let g = GkG1::generator();
+
+    let mut hasher = Keccak256::new();
+    for p in [&g, pk, gr, pkr, cap_r_1, cap_r_2] {
+        for c in [p.x, p.y] {
+            let mut bytes = c.to_repr();
+            bytes.reverse();
+            hasher.update(bytes);
+        }
+    }
+    let mut bytes = cipher.to_repr();
+    bytes.reverse();
+    hasher.update(bytes);
+    let hash_state: [u8; 32] = hasher.finalize().to_vec().try_into().unwrap();
+
+    GkScalar::from_raw(from_be_bytes(&hash_state))
+}
+
+impl DecryptionProof {
+    pub fn prove(
+        sk: &GkScalar,
+        gr: &GkG1,
+        cipher: &BnScalar,
+        mut rng: impl RngCore,
+    ) -> DecryptionProof {
+        let g = GkG1::generator();
+        let pk = (g * sk).to_affine();
+        let pkr = (gr * sk).to_affine();
+
+        let k = GkScalar::random(&mut rng);
+        let cap_r_1 = (g * k).to_affine();
+        let cap_r_2 = (gr * k).to_affine();
+
+        let c = decryption_challenge(&pk, gr, &pkr, &cap_r_1, &cap_r_2, cipher);
+        let z = k + c * sk;
+
+        DecryptionProof { pkr, c, z }
+    }
+
+    pub fn verify(&self, pk: &GkG1, gr: &GkG1, cipher: &BnScalar) -> Result<(), Error> {
+        for p in [pk, gr, &self.pkr] {
+            if !bool::from(p.is_on_curve()) || bool::from(p.is_identity()) {
+                return Err(Error::VerifyFailed);
+            }
+        }
+
+        let g = GkG1::generator();
+        let cap_r_1 = ((g * self.z) - (pk * self.c)).to_affine();
+        let cap_r_2 = ((gr * self.z) - (self.pkr * self.c)).to_affine();
+
+        let c = decryption_challenge(pk, gr, &self.pkr, &cap_r_1, &cap_r_2, cipher);
+        if c != self.c {
+            return Err(Error::VerifyFailed);
+        }
+
+        Ok(())
+    }
+}
+
+fn encryption_tag(mask: &BnScalar, cipher: &BnScalar) -> BnScalar {
+    let poseidon =
+        Hash::<_, P128Pow5T3Bn, ConstantLength<POSEIDON_LEN>, POSEIDON_WIDTH, POSEIDON_RATE>::init(
+        );
+    poseidon.hash([*mask, *cipher])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_dealer_context() {
+        let mut rng = OsRng;
+
+        let pkr = (GkG1::generator() * GkScalar::random(&mut rng)).to_affine();
+        let share = BnScalar::random(&mut rng);
+        let session = SessionId::new(BnScalar::from(7));
+        let context = DealerContext::new(session, 2);
+
+        let (cipher, tag) = context.encrypt(&pkr, 3, &share);
+        assert_eq!(context.decrypt(&pkr, 3, &cipher, &tag).unwrap(), share);
+
+        // another recipient, dealer or session does not accept the cipher
+        assert!(context.decrypt(&pkr, 4, &cipher, &tag).is_err());
+        let other_dealer = DealerContext::new(session, 1);
+        assert!(other_dealer.decrypt(&pkr, 3, &cipher, &tag).is_err());
+        let other_session = DealerContext::new(SessionId::new(BnScalar::from(8)), 2);
+        assert!(other_session.decrypt(&pkr, 3, &cipher, &tag).is_err());
+        assert!(context
+            .decrypt(&pkr, 3, &(cipher + BnScalar::one()), &tag)
+            .is_err());
+
+        let read = DealerContext::from_public(BnScalar::from(2), BnScalar::from(7)).unwrap();
+        assert_eq!(read, context);
+        // dealer indices at or above 2^INDEX_BITS are rejected
+        let too_large = BnScalar::from(1u64 << INDEX_BITS);
+        assert!(DealerContext::from_public(too_large, BnScalar::from(7)).is_err());
+        assert!(DealerContext::from_public(-BnScalar::one(), BnScalar::from(7)).is_err());
+    }
+
+    #[test]
+    fn test_decryption_proof() {
+        let mut rng = OsRng;
+
+        let g = GkG1::generator();
+        let sk = GkScalar::random(&mut rng);
+        let pk = (g * sk).to_affine();
+        let gr = (g * GkScalar::random(&mut rng)).to_affine();
+        let cipher = BnScalar::random(&mut rng);
+
+        let proof = DecryptionProof::prove(&sk, &gr, &cipher, &mut rng);
+        assert_eq!(proof.pkr, (gr * sk).to_affine());
+        proof.verify(&pk, &gr, &cipher).unwrap();
+
+        // the proof is bound to the cipher, the key and pk^r
+        assert!(proof.verify(&pk, &gr, &(cipher + BnScalar::one())).is_err());
+        let other = (g * GkScalar::random(&mut rng)).to_affine();
+        assert!(proof.verify(&other, &gr, &cipher).is_err());
+        let forged = DecryptionProof {
+            pkr: other,
+            ..proof
+        };
+        assert!(forged.verify(&pk, &gr, &cipher).is_err());
+    }
+}
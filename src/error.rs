@@ -8,10 +8,43 @@ pub enum Error {
         threshold: usize,
         number_of_members: usize,
     },
+    #[error("invalid padding ({max_threshold:?}, {max_number_of_members:?})")]
+    InvalidPadding {
+        max_threshold: usize,
+        max_number_of_members: usize,
+    },
+    #[error("invalid window size {window_size:?}")]
+    InvalidWindowSize { window_size: usize },
+    #[error("invalid public key of member {index:?}")]
+    InvalidPublicKey { index: usize },
+    #[error("duplicate public key of member {index:?}")]
+    DuplicatePublicKey { index: usize },
     #[error("invalid index {index:?}")]
     InvalidIndex { index: usize },
     #[error("invalid index order {index:?}")]
     InvalidOrder { index: usize },
+    #[error("duplicate dealer {index:?}")]
+    DuplicateDealer { index: usize },
+    #[error("invalid public params from dealer {index:?}")]
+    InvalidDealer { index: usize },
+    #[error("share from dealer {index:?} does not match its public share")]
+    InvalidShare { index: usize },
+    #[error("cipher from dealer {index:?} does not match its tag")]
+    InvalidTag { index: usize },
+    #[error("dealer {index:?} is bound to another session")]
+    InvalidSession { index: usize },
+    #[error("missing share from dealer {index:?}")]
+    MissingDealer { index: usize },
+    #[error("not enough dealers ({dealers:?} < {required:?})")]
+    NotEnoughDealers { dealers: usize, required: usize },
+    #[error("length mismatch ({left:?} != {right:?})")]
+    LengthMismatch { left: usize, right: usize },
+    #[error("invalid instance at position {position:?}")]
+    InvalidInstance { position: usize },
+    #[error("session id is not a canonical scalar")]
+    InvalidSessionId,
+    #[error("invalid domain separation tag {dst:?}")]
+    InvalidDomain { dst: String },
     #[error("verification failed")]
     VerifyFailed,
     #[error("circuit error {0:?}")]
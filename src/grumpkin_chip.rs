@@ -10,6 +10,7 @@ use halo2wrong::utils::decompose;
 use halo2wrong::RegionCtx;
 
 mod add;
+mod fix_mul;
 mod mul;
 
 #[derive(Clone, Debug)]
@@ -45,6 +46,8 @@ pub struct GrumpkinChip {
     main_gate: MainGate<Base>,
     aux_generator: Option<AssignedPoint>,
     aux_correction: Option<AssignedPoint>,
+    fixed_table: Option<Vec<Vec<Point>>>,
+    fixed_window_size: Option<usize>,
 }
 
 impl GrumpkinChip {
@@ -54,6 +57,8 @@ impl GrumpkinChip {
             main_gate,
             aux_generator: None,
             aux_correction: None,
+            fixed_table: None,
+            fixed_window_size: None,
         }
     }
 
@@ -179,6 +184,36 @@ impl GrumpkinChip {
         Ok(AssignedPoint::new(p.x().clone(), y_neg))
     }
 
+    // assert that the x coordinates of the points are pairwise distinct and differ from that of aux,
+    // which also rules out a point next to its negation; with active bits, a point whose bit is
+    // zero is skipped, assuming active bits are a prefix as for the members of a padded circuit;
+    // the product of all differences takes n^2 rows, small next to n scalar multiplications
+    pub fn assert_distinct(
+        &self,
+        ctx: &mut RegionCtx<'_, Base>,
+        points: &[AssignedPoint],
+        active: Option<&[AssignedCondition<Base>]>,
+    ) -> Result<(), PlonkError> {
+        let aux = match &self.aux_generator {
+            Some(assigned) => assigned,
+            None => return Err(PlonkError::Synthesis),
+        };
+        let main_gate = self.main_gate();
+
+        let mut product = main_gate.assign_constant(ctx, Base::one())?;
+        for (j, p) in points.iter().enumerate() {
+            for q in points[..j].iter().chain(std::iter::once(aux)) {
+                let mut diff = main_gate.sub(ctx, p.x(), q.x())?;
+                if let Some(active) = active {
+                    diff = main_gate.select_or_assign(ctx, &diff, Base::one(), &active[j])?;
+                }
+                product = main_gate.mul(ctx, &product, &diff)?;
+            }
+        }
+
+        main_gate.assert_not_zero(ctx, &product)
+    }
+
     // select p1 if c is true, or p2 if c is false
     pub fn select(
         &self,
@@ -218,6 +253,50 @@ impl GrumpkinChip {
         Ok(decomposed)
     }
 
+    // bits of value in [0, p) where p is the base field modulus; the decomposition from to_bits
+    // only holds modulo p, so value + p would also compose to value if it fits into NUM_BITS bits
+    pub fn to_bits_canonical(
+        &self,
+        ctx: &mut RegionCtx<'_, Base>,
+        value: &AssignedValue<Base>,
+    ) -> Result<Vec<AssignedCondition<Base>>, PlonkError> {
+        let bits = self.to_bits(ctx, value)?;
+        self.assert_canonical_bits(ctx, &bits)?;
+
+        Ok(bits)
+    }
+
+    // assert that little endian bits represent an integer at most p - 1;
+    // from the most significant bit, once the prefix equals that of p - 1,
+    // a bit can only be set where p - 1 has a one
+    pub fn assert_canonical_bits(
+        &self,
+        ctx: &mut RegionCtx<'_, Base>,
+        bits: &[AssignedCondition<Base>],
+    ) -> Result<(), PlonkError> {
+        let main_gate = self.main_gate();
+        let number_of_bits = Base::NUM_BITS as usize;
+        assert_eq!(bits.len(), number_of_bits);
+
+        let max = (-Base::one()).to_repr();
+        let max_bits: Vec<_> = (0..number_of_bits)
+            .map(|i| (max.as_ref()[i / 8] >> (i % 8)) & 1 == 1)
+            .collect();
+
+        // eq is one if the bits so far are equal to those of p - 1
+        let mut eq = main_gate.assign_constant(ctx, Base::one())?;
+        for (bit, max_bit) in bits.iter().zip(max_bits.iter()).rev() {
+            let eq_bit = main_gate.mul(ctx, &eq, bit)?;
+            if *max_bit {
+                eq = eq_bit;
+            } else {
+                main_gate.assert_zero(ctx, &eq_bit)?;
+            }
+        }
+
+        Ok(())
+    }
+
     // convert into bits without checking the bits compose back to the original value
     pub fn to_bits_unsafe(
         &self,
@@ -245,15 +324,18 @@ impl GrumpkinChip {
 mod tests {
     use super::*;
     use halo2wrong::curves::ff::PrimeField;
+    use halo2wrong::curves::group::prime::PrimeCurveAffine;
     use halo2wrong::curves::group::Curve;
     use halo2wrong::curves::grumpkin::Fr as Scalar;
 
+    use halo2_maingate::Term;
     use halo2wrong::halo2::arithmetic::Field;
     use halo2wrong::halo2::circuit::SimpleFloorPlanner;
+    use halo2wrong::halo2::dev::MockProver;
     use halo2wrong::halo2::plonk::{Circuit, ConstraintSystem};
     use halo2wrong::utils::{mock_prover_verify, DimensionMeasurement};
     use rand_chacha::ChaCha20Rng;
-    use rand_core::{OsRng, SeedableRng};
+    use rand_core::{OsRng, RngCore, SeedableRng};
 
     #[derive(Clone, Debug, Default)]
     struct TestGrumpkin;
@@ -436,4 +518,346 @@ mod tests {
 
         mock_prover_verify(&circuit, instance);
     }
+
+    // g^r with the windowed fixed point table, or with mul_bits if there is no window
+    #[derive(Clone, Debug, Default)]
+    struct TestMulFix {
+        window_size: Option<usize>,
+    }
+
+    impl Circuit<Base> for TestMulFix {
+        type Config = MainGateConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Base>) -> Self::Config {
+            MainGate::<Base>::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Base>,
+        ) -> Result<(), PlonkError> {
+            let mut ecc = GrumpkinChip::new(config);
+
+            let mut rng = OsRng;
+
+            let g = Point::generator();
+            let r = Base::random(&mut rng);
+            let rr = Scalar::from_repr(r.to_repr()).unwrap();
+            let gr = (g * rr).to_affine();
+            let aux = Point::random(&mut rng);
+
+            layouter.assign_region(
+                || "region aux generator",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    ecc.assign_aux_generator(ctx, Value::known(aux))?;
+                    ecc.assign_aux_correction(ctx)?;
+
+                    Ok(())
+                },
+            )?;
+
+            if let Some(window_size) = self.window_size {
+                ecc.prepare_fixed_point(&g, window_size)?;
+            }
+
+            layouter.assign_region(
+                || "region fixed point mul",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let bits = ecc.to_bits_unsafe(ctx, &Value::known(r))?;
+                    let d = match self.window_size {
+                        Some(_) => ecc.mul_fixed_bits(ctx, &bits)?,
+                        None => {
+                            let g_assigned = ecc.assign_constant(ctx, g)?;
+                            ecc.mul_bits(ctx, &g_assigned, &bits)?
+                        }
+                    };
+                    let gr_assigned = ecc.assign_point(ctx, Value::known(gr))?;
+                    ecc.assert_equal(ctx, &gr_assigned, &d)?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_grumpkin_mul_fix() {
+        let circuit = TestMulFix { window_size: None };
+        mock_prover_verify(&circuit, vec![vec![]]);
+
+        for window_size in 2..6 {
+            let circuit = TestMulFix {
+                window_size: Some(window_size),
+            };
+            mock_prover_verify(&circuit, vec![vec![]]);
+        }
+    }
+
+    // points^r with shared bits, checked against the native results;
+    // a forged result is checked for the member at forged
+    #[derive(Clone, Debug, Default)]
+    struct TestMulMany {
+        points: Vec<Point>,
+        aux: Point,
+        r: Base,
+        many: bool,
+        forged: Option<usize>,
+    }
+
+    impl Circuit<Base> for TestMulMany {
+        type Config = MainGateConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Base>) -> Self::Config {
+            MainGate::<Base>::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Base>,
+        ) -> Result<(), PlonkError> {
+            let mut ecc = GrumpkinChip::new(config);
+
+            let rr = Scalar::from_repr(self.r.to_repr()).unwrap();
+            let expected: Vec<_> = self
+                .points
+                .iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    let pr = (p * rr).to_affine();
+                    if self.forged == Some(i) {
+                        (pr + Point::generator()).to_affine()
+                    } else {
+                        pr
+                    }
+                })
+                .collect();
+
+            layouter.assign_region(
+                || "region aux generator",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    ecc.assign_aux_generator(ctx, Value::known(self.aux))?;
+                    ecc.assign_aux_correction(ctx)?;
+
+                    Ok(())
+                },
+            )?;
+
+            layouter.assign_region(
+                || "region point mul many",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let points = self
+                        .points
+                        .iter()
+                        .map(|p| ecc.assign_point(ctx, Value::known(*p)))
+                        .collect::<Result<Vec<_>, PlonkError>>()?;
+                    let bits = ecc.to_bits_unsafe(ctx, &Value::known(self.r))?;
+
+                    let results = if self.many {
+                        ecc.mul_bits_many(ctx, &points, &bits)?
+                    } else {
+                        points
+                            .iter()
+                            .map(|p| ecc.mul_bits(ctx, p, &bits))
+                            .collect::<Result<Vec<_>, PlonkError>>()?
+                    };
+
+                    for (d, e) in results.iter().zip(expected.iter()) {
+                        let e = ecc.assign_point(ctx, Value::known(*e))?;
+                        ecc.assert_equal(ctx, d, &e)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_grumpkin_mul_many() {
+        let mut rng = OsRng;
+
+        let g = Point::generator();
+        let aux = Point::random(&mut rng);
+        let r = Base::random(&mut rng);
+        // the same point twice and the generator are fine
+        let p = Point::random(&mut rng);
+        let points = vec![p, Point::random(&mut rng), p, g];
+
+        let prover = |points: Vec<Point>, many, forged| {
+            let circuit = TestMulMany {
+                points,
+                aux,
+                r,
+                many,
+                forged,
+            };
+            let k = DimensionMeasurement::measure(&circuit).unwrap().k();
+            MockProver::run(k, &circuit, vec![vec![]]).unwrap().verify()
+        };
+
+        assert!(prover(points.clone(), true, None).is_ok());
+        assert!(prover(points.clone(), true, Some(1)).is_err());
+
+        // a point equal to aux makes the first complete addition fail
+        assert!(prover(vec![p, aux], true, None).is_err());
+        // the identity is not on curve
+        assert!(prover(vec![p, Point::identity()], true, None).is_err());
+
+        // sharing the bits of r never needs a larger circuit than one decomposition per point
+        let k: Vec<_> = [false, true]
+            .into_iter()
+            .map(|many| {
+                let circuit = TestMulMany {
+                    points: points.clone(),
+                    aux,
+                    r,
+                    many,
+                    forged: None,
+                };
+                DimensionMeasurement::measure(&circuit).unwrap().k()
+            })
+            .collect();
+        assert!(k[1] <= k[0]);
+    }
+
+    // bits of r, or of the integer r + p when forged
+    #[derive(Clone, Debug, Default)]
+    struct TestCanonical {
+        r: Base,
+        forged: bool,
+        canonical: bool,
+    }
+
+    impl Circuit<Base> for TestCanonical {
+        type Config = MainGateConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Base>) -> Self::Config {
+            MainGate::<Base>::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Base>,
+        ) -> Result<(), PlonkError> {
+            let ecc = GrumpkinChip::new(config);
+
+            layouter.assign_region(
+                || "region canonical bits",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    let main_gate = ecc.main_gate();
+                    let number_of_bits = Base::NUM_BITS as usize;
+
+                    let r = main_gate.assign_value(ctx, Value::known(self.r))?;
+
+                    // little endian bytes of r or of the integer r + p = r + (p - 1) + 1
+                    let mut bytes = self.r.to_repr();
+                    if self.forged {
+                        let max = (-Base::one()).to_repr();
+                        let mut carry = 1u16;
+                        for (b, m) in bytes.as_mut().iter_mut().zip(max.as_ref().iter()) {
+                            let sum = *b as u16 + *m as u16 + carry;
+                            *b = sum as u8;
+                            carry = sum >> 8;
+                        }
+                    }
+                    let bits: Vec<_> = (0..number_of_bits)
+                        .map(|i| {
+                            let bit = (bytes.as_ref()[i / 8] >> (i % 8)) & 1;
+                            main_gate.assign_bit(ctx, Value::known(Base::from(bit as u64)))
+                        })
+                        .collect::<Result<_, _>>()?;
+
+                    // both decompositions compose back to r in the field
+                    let mut power = Base::one();
+                    let mut terms = vec![];
+                    for bit in bits.iter() {
+                        terms.push(Term::Assigned(bit, power));
+                        power = power.double();
+                    }
+                    let composed = main_gate.compose(ctx, &terms, Base::zero())?;
+                    main_gate.assert_equal(ctx, &composed, &r)?;
+
+                    if self.canonical {
+                        ecc.assert_canonical_bits(ctx, &bits)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_canonical_bits() {
+        let mut rng = OsRng;
+
+        // r + p fits into 254 bits for a small r
+        let r = Base::from(rng.next_u64());
+        let prover = |forged, canonical| {
+            let circuit = TestCanonical {
+                r,
+                forged,
+                canonical,
+            };
+            let k = DimensionMeasurement::measure(&circuit).unwrap().k();
+            MockProver::run(k, &circuit, vec![vec![]]).unwrap().verify()
+        };
+
+        assert!(prover(false, true).is_ok());
+        // without the range check a non-canonical decomposition is accepted
+        assert!(prover(true, false).is_ok());
+        assert!(prover(true, true).is_err());
+
+        // the largest canonical value p - 1 passes
+        let circuit = TestCanonical {
+            r: -Base::one(),
+            forged: false,
+            canonical: true,
+        };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
 }
@@ -0,0 +1,181 @@
+use crate::grumpkin_chip::{AssignedPoint, Base, GrumpkinChip, PlonkError};
+use crate::utils::hash_to_curve_grumpkin;
+use halo2_maingate::{AssignedCondition, AssignedValue, MainGateInstructions, Term};
+use halo2wrong::curves::ff::PrimeField;
+use halo2wrong::curves::group::prime::PrimeCurveAffine;
+use halo2wrong::curves::group::{Curve, GroupEncoding};
+use halo2wrong::curves::grumpkin::{Fr as Scalar, G1Affine as Point, G1};
+use halo2wrong::curves::CurveAffine;
+use halo2wrong::RegionCtx;
+
+const AUX_PREFIX: &str = "zkrand grumpkin fixed point aux";
+
+// windowed scalar mul for fixed point on grumpkin curve, same table as FixedPointChip
+impl GrumpkinChip {
+    fn prepare_fixed_point_table(window_size: usize, fixed_point: &Point) -> Vec<Vec<Point>> {
+        // The algorithm cannot be applied when the window_size = 1 due to the lack of monotonicity.
+        assert!(window_size > 1);
+
+        let num_bits = Base::NUM_BITS as usize;
+        let number_of_windows = (num_bits + window_size - 1) / window_size;
+        let mut last = num_bits % window_size;
+        if last == 0 {
+            last = window_size;
+        }
+        let window: usize = 1 << window_size;
+        let window_last: usize = 1 << last;
+
+        // T[0..n)[0..2^w): T[i][k]=[(k+2)⋅(2^w)^i]P
+        let fp = fixed_point.to_curve();
+        let t: Vec<G1> = (0..window)
+            .map(|k| fp * Scalar::from((k + 2) as u64))
+            .collect();
+        let mut table = vec![t];
+
+        for i in 1..number_of_windows {
+            let w = if i == number_of_windows - 1 {
+                window_last
+            } else {
+                window
+            };
+
+            let t: Vec<_> = table[i - 1][..w]
+                .iter()
+                .map(|p| (0..window_size).fold(*p, |p, _| p + p))
+                .collect();
+            table.push(t);
+        }
+
+        // for the last two rows, we use auxiliary generator:
+        // T[n-2][k]=[(k+2)⋅(2^w)^{n-2}]P + aux
+        // T[n-1][k]=[(k+2)⋅(2^w)^{n-1}]P + C where C = -[\sum_{j=0}^{n-1} 2^{wj+1}]P - aux
+        let aux_generator = hash_to_curve_grumpkin(AUX_PREFIX)(fixed_point.to_bytes().as_ref());
+
+        let correction = -table
+            .iter()
+            .fold(aux_generator, |correction, t| correction + t[0]);
+
+        for p in table[number_of_windows - 2].iter_mut() {
+            *p = *p + aux_generator;
+        }
+        for p in table[number_of_windows - 1].iter_mut() {
+            *p = *p + correction;
+        }
+
+        table
+            .iter()
+            .map(|t| {
+                let mut affine = vec![Point::identity(); t.len()];
+                G1::batch_normalize(t, &mut affine);
+                affine
+            })
+            .collect()
+    }
+
+    // the table is only used as constants, so nothing is assigned here
+    pub fn prepare_fixed_point(
+        &mut self,
+        fixed_point: &Point,
+        window_size: usize,
+    ) -> Result<(), PlonkError> {
+        if !bool::from(fixed_point.is_on_curve()) || bool::from(fixed_point.is_identity()) {
+            return Err(PlonkError::Synthesis);
+        };
+
+        let table = Self::prepare_fixed_point_table(window_size, fixed_point);
+        self.fixed_table = Some(table);
+        self.fixed_window_size = Some(window_size);
+
+        Ok(())
+    }
+
+    // table[k] for k = sum(b_j * 2^j) written as a multilinear polynomial in the bits;
+    // the coefficients are constants, so only the products of bits take a mul gate
+    fn select_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, Base>,
+        bits: &[AssignedCondition<Base>],
+        table: &[Point],
+    ) -> Result<AssignedPoint, PlonkError> {
+        let main_gate = self.main_gate();
+        let number_of_points = table.len();
+        assert_eq!(number_of_points, 1 << bits.len());
+
+        // monomials[s-1] is the product of the bits in the nonzero subset s
+        let mut monomials: Vec<AssignedValue<Base>> = vec![];
+        for s in 1..number_of_points {
+            let j = s.trailing_zeros() as usize;
+            let rest = s & (s - 1);
+            let monomial = if rest == 0 {
+                bits[j].clone()
+            } else {
+                main_gate.mul(ctx, &monomials[rest - 1], &bits[j])?
+            };
+            monomials.push(monomial);
+        }
+
+        let mut coords = vec![];
+        for coord in [
+            table.iter().map(|p| p.x).collect::<Vec<_>>(),
+            table.iter().map(|p| p.y).collect::<Vec<_>>(),
+        ] {
+            // coefficients of the monomials by the inverse of the subset sum
+            let mut coeffs = coord;
+            for j in 0..bits.len() {
+                for s in 0..number_of_points {
+                    if s & (1 << j) != 0 {
+                        coeffs[s] = coeffs[s] - coeffs[s ^ (1 << j)];
+                    }
+                }
+            }
+
+            let terms: Vec<_> = monomials
+                .iter()
+                .zip(coeffs.iter().skip(1))
+                .map(|(monomial, coeff)| Term::Assigned(monomial, *coeff))
+                .collect();
+            coords.push(main_gate.compose(ctx, &terms, coeffs[0])?);
+        }
+        let y = coords.pop().unwrap();
+        let x = coords.pop().unwrap();
+
+        Ok(AssignedPoint::new(x, y))
+    }
+
+    // fixed_point^bits with little endian bits of length Base::NUM_BITS
+    pub fn mul_fixed_bits(
+        &self,
+        ctx: &mut RegionCtx<'_, Base>,
+        bits: &[AssignedCondition<Base>],
+    ) -> Result<AssignedPoint, PlonkError> {
+        let (table, window_size) = match (&self.fixed_table, self.fixed_window_size) {
+            (Some(table), Some(window_size)) => (table, window_size),
+            // fixed point is not prepared yet
+            _ => return Err(PlonkError::Synthesis),
+        };
+        assert_eq!(bits.len(), Base::NUM_BITS as usize);
+
+        let windows: Vec<_> = bits.chunks(window_size).collect();
+        let number_of_windows = windows.len();
+        assert_eq!(number_of_windows, table.len());
+        assert!(number_of_windows > 2);
+
+        // the partial sums before the last two rows are multiples of P smaller than the next
+        // table entry, so their x coordinates differ and incomplete addition is safe
+        let mut rows = windows.iter().zip(table.iter());
+        let (window, t) = rows.next().unwrap();
+        let mut acc = self.select_constant(ctx, window, t)?;
+        for (window, t) in rows.by_ref().take(number_of_windows - 3) {
+            let q = self.select_constant(ctx, window, t)?;
+            acc = self.add_incomplete(ctx, &acc, &q)?;
+        }
+
+        // the last two rows with aux generator and correction
+        for (window, t) in rows {
+            let q = self.select_constant(ctx, window, t)?;
+            acc = self.add(ctx, &acc, &q)?;
+        }
+
+        Ok(acc)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::grumpkin_chip::{AssignedPoint, Base, GrumpkinChip, PlonkError};
-use halo2_maingate::{AssignedCondition, AssignedValue, MainGateInstructions};
+use halo2_maingate::{AssignedCondition, AssignedValue, MainGateInstructions, Term};
 use halo2wrong::curves::ff::PrimeField;
 use halo2wrong::RegionCtx;
 
@@ -43,4 +43,57 @@ impl GrumpkinChip {
         res = self.add(ctx, &res, &aux_neg)?;
         Ok(res)
     }
+
+    // points[i]^bits for many points sharing the same bits;
+    // each bit b_j for j >= 1 is recoded once into a sign d_j = 2b_j - 1 shared by all points, and
+    // k*P = sum_{j>=1} d_j * 2^(j-1) * P + 2^(m-1) * P - (1 - b_0) * P,
+    // so every step adds (x, d_j * y) of the doubled point instead of selecting between two sums;
+    // the additions are incomplete: they assert distinct x and cannot handle doubling or the identity,
+    // so a point equal to aux or related to another point can only make the proof fail, never pass;
+    // the identity is not on curve and grumpkin has prime order, so there are no small order points
+    pub fn mul_bits_many(
+        &self,
+        ctx: &mut RegionCtx<'_, Base>,
+        points: &[AssignedPoint],
+        bits: &[AssignedCondition<Base>],
+    ) -> Result<Vec<AssignedPoint>, PlonkError> {
+        let aux = match self.aux_generator.clone() {
+            Some(assigned) => Ok(assigned),
+            None => Err(PlonkError::Synthesis),
+        }?;
+        let aux_neg = match self.aux_correction.clone() {
+            Some(assigned) => Ok(assigned),
+            None => Err(PlonkError::Synthesis),
+        }?;
+        assert!(bits.len() > 1);
+
+        let main_gate = self.main_gate();
+        let signs = bits[1..]
+            .iter()
+            .map(|bit| main_gate.compose(ctx, &[Term::Assigned(bit, Base::from(2))], -Base::one()))
+            .collect::<Result<Vec<_>, PlonkError>>()?;
+
+        let mut results = vec![];
+        for point in points.iter() {
+            let mut res = aux.clone();
+            let mut double = point.clone();
+            for sign in signs.iter() {
+                let y = main_gate.mul(ctx, sign, double.y())?;
+                let signed = AssignedPoint::new(double.x().clone(), y);
+                res = self.add(ctx, &res, &signed)?;
+                double = self.double_incomplete(ctx, &double)?;
+            }
+
+            // add 2^(m-1) * P and subtract P if b_0 is zero
+            res = self.add(ctx, &res, &double)?;
+            let point_neg = self.neg(ctx, point)?;
+            let t = self.add(ctx, &res, &point_neg)?;
+            res = self.select(ctx, &bits[0], &res, &t)?;
+
+            res = self.add(ctx, &res, &aux_neg)?;
+            results.push(res);
+        }
+
+        Ok(results)
+    }
 }
@@ -1,13 +1,15 @@
 #![allow(clippy::op_ref)]
 
 use halo2_ecc::halo2::arithmetic::CurveExt;
-use halo2wrong::curves::bn256::{Fq, G1};
-use halo2wrong::curves::ff::Field;
+use halo2wrong::curves::bn256::{Fq, Fq2, G1, G2};
+use halo2wrong::curves::ff::{Field, PrimeField};
+use halo2wrong::curves::group::{Curve, Group};
+use halo2wrong::curves::grumpkin::{Fq as GkBase, G1 as GkG1};
 use sha3::{Digest, Keccak256};
-use subtle::ConditionallySelectable;
+use subtle::{Choice, ConditionallySelectable};
 
 /// (q-1)/2 = 0x183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3
-const Q2: Fq = Fq::from_raw([
+pub(crate) const Q2: Fq = Fq::from_raw([
     0x9e10460b6c3e7ea3,
     0xcbc0b548b438e546,
     0xdc2822db40c0ac2e,
@@ -23,7 +25,7 @@ const MINUS_ONE: Fq = Fq::from_raw([
 ]);
 
 /// R = 2^256 mod q = 0xe0a77c19a07df2f666ea36f7879462c0a78eb28f5c70b3dd35d438dc58f0d9d
-const R: Fq = Fq::from_raw([
+pub(crate) const R: Fq = Fq::from_raw([
     0xd35d438dc58f0d9d,
     0x0a78eb28f5c70b3d,
     0x666ea36f7879462c,
@@ -54,6 +56,172 @@ const C3: Fq = Fq::from_raw([
     0x2042def740cbc01b,
 ]);
 
+/// G2 twist b' = 3/(9+u)
+/// c0 = 0x2b149d40ceb8aaae81be18991be06ac3b5b4c5e559dbefa33267e6dc24a138e5
+/// c1 = 0x009713b03af0fed4cd2cafadeed8fdf4a74fa084e52d1852e4a2bd0685c315d2
+const B2: Fq2 = Fq2 {
+    c0: Fq::from_raw([
+        0x3267e6dc24a138e5,
+        0xb5b4c5e559dbefa3,
+        0x81be18991be06ac3,
+        0x2b149d40ceb8aaae,
+    ]),
+    c1: Fq::from_raw([
+        0xe4a2bd0685c315d2,
+        0xa74fa084e52d1852,
+        0xcd2cafadeed8fdf4,
+        0x009713b03af0fed4,
+    ]),
+};
+
+/// svdw constants for G2 with Z = 1
+/// D1 = g(Z) = 1 + b'
+const D1: Fq2 = Fq2 {
+    c0: Fq::from_raw([
+        0x3267e6dc24a138e6,
+        0xb5b4c5e559dbefa3,
+        0x81be18991be06ac3,
+        0x2b149d40ceb8aaae,
+    ]),
+    c1: Fq::from_raw([
+        0xe4a2bd0685c315d2,
+        0xa74fa084e52d1852,
+        0xcd2cafadeed8fdf4,
+        0x009713b03af0fed4,
+    ]),
+};
+
+/// D2 = -Z/2 = (q-1)/2
+const D2: Fq2 = Fq2 {
+    c0: Q2,
+    c1: Fq::ZERO,
+};
+
+/// D3 = sqrt(-g(Z) * 3Z^2) with sgn0(D3) = 0
+/// c0 = 0x29fd332ab7260112b801fa95b21af64e2e6da55f90a3e510fcbe57377b5ca1ec
+/// c1 = 0x303d1eff1426764bf8408aee24ba0b865e76f77b1267a846b1e9154d01565034
+const D3: Fq2 = Fq2 {
+    c0: Fq::from_raw([
+        0xfcbe57377b5ca1ec,
+        0x2e6da55f90a3e510,
+        0xb801fa95b21af64e,
+        0x29fd332ab7260112,
+    ]),
+    c1: Fq::from_raw([
+        0xb1e9154d01565034,
+        0x5e76f77b1267a846,
+        0xf8408aee24ba0b86,
+        0x303d1eff1426764b,
+    ]),
+};
+
+/// D4 = -4g(Z)/(3Z^2)
+/// c0 = 0x17365bbe63b1d2078632fe0eb2ac5a41b4e6a9c08b98676721010b008d4eaf99
+/// c1 = 0x0f57ffe5fc79e19cd689d7aa4209cad8fe164d7f4694786b388732a995d03755
+const D4: Fq2 = Fq2 {
+    c0: Fq::from_raw([
+        0x21010b008d4eaf99,
+        0xb4e6a9c08b986767,
+        0x8632fe0eb2ac5a41,
+        0x17365bbe63b1d207,
+    ]),
+    c1: Fq::from_raw([
+        0x388732a995d03755,
+        0xfe164d7f4694786b,
+        0xd689d7aa4209cad8,
+        0x0f57ffe5fc79e19c,
+    ]),
+};
+
+/// bn254 parameter x = 0x44e992b44a6909f1, the cofactor of G2 is h = 2q - r with q = 36x^4 + 36x^3 + 24x^2 + 6x + 1
+const BN_X: u64 = 0x44e992b44a6909f1;
+
+/// psi(x, y) = (conj(x) * PSI_X, conj(y) * PSI_Y) with xi = 9 + u
+/// PSI_X = xi^((q-1)/3)
+/// c0 = 0x2fb347984f7911f74c0bec3cf559b143b78cc310c2c3330c99e39557176f553d
+/// c1 = 0x16c9e55061ebae204ba4cc8bd75a079432ae2a1d0b7c9dce1665d51c640fcba2
+const PSI_X: Fq2 = Fq2 {
+    c0: Fq::from_raw([
+        0x99e39557176f553d,
+        0xb78cc310c2c3330c,
+        0x4c0bec3cf559b143,
+        0x2fb347984f7911f7,
+    ]),
+    c1: Fq::from_raw([
+        0x1665d51c640fcba2,
+        0x32ae2a1d0b7c9dce,
+        0x4ba4cc8bd75a0794,
+        0x16c9e55061ebae20,
+    ]),
+};
+
+/// PSI_Y = xi^((q-1)/2)
+/// c0 = 0x063cf305489af5dcdc5ec698b6e2f9b9dbaae0eda9c95998dc54014671a0135a
+/// c1 = 0x07c03cbcac41049a0704b5a7ec796f2b21807dc98fa25bd282d37f632623b0e3
+const PSI_Y: Fq2 = Fq2 {
+    c0: Fq::from_raw([
+        0xdc54014671a0135a,
+        0xdbaae0eda9c95998,
+        0xdc5ec698b6e2f9b9,
+        0x063cf305489af5dc,
+    ]),
+    c1: Fq::from_raw([
+        0x82d37f632623b0e3,
+        0x21807dc98fa25bd2,
+        0x0704b5a7ec796f2b,
+        0x07c03cbcac41049a,
+    ]),
+};
+
+/// grumpkin y^2 = x^3 + b over the bn256 scalar field p, with b = -17
+/// b = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593effffff0
+const GK_B: GkBase = GkBase::from_raw([
+    0x43e1f593effffff0,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+/// (p-1)/2 = 0x183227397098d014dc2822db40c0ac2e9419f4243cdcb848a1f0fac9f8000000
+const GK_Q2: GkBase = GkBase::from_raw([
+    0xa1f0fac9f8000000,
+    0x9419f4243cdcb848,
+    0xdc2822db40c0ac2e,
+    0x183227397098d014,
+]);
+
+/// R = 2^256 mod p = 0xe0a77c19a07df2f666ea36f7879462e36fc76959f60cd29ac96341c4ffffffb
+const GK_R: GkBase = GkBase::from_raw([
+    0xac96341c4ffffffb,
+    0x36fc76959f60cd29,
+    0x666ea36f7879462e,
+    0x0e0a77c19a07df2f,
+]);
+
+/// C1 = (-1 + sqrt(-3))/2 = 0x0000000000000000b3c4d79d41a917585bfc41088d8daaa78b17ea66b99c90dd
+const GK_C1: GkBase = GkBase::from_raw([
+    0x8b17ea66b99c90dd,
+    0x5bfc41088d8daaa7,
+    0xb3c4d79d41a91758,
+    0x0000000000000000,
+]);
+
+/// C2 = sqrt(-3) = 0x00000000000000016789af3a83522eb0b7f882111b1b554f162fd4cd733921bb
+const GK_C2: GkBase = GkBase::from_raw([
+    0x162fd4cd733921bb,
+    0xb7f882111b1b554f,
+    0x6789af3a83522eb0,
+    0x0000000000000001,
+]);
+
+/// C3 = 1/3 = 0x2042def740cbc01bd03583cf0100e59370229adafbd0f5b62d414e62a0000001
+const GK_C3: GkBase = GkBase::from_raw([
+    0x2d414e62a0000001,
+    0x70229adafbd0f5b6,
+    0xd03583cf0100e593,
+    0x2042def740cbc01b,
+]);
+
 pub fn from_be_bytes(bytes: &[u8; 32]) -> [u64; 4] {
     let limb0 = u64::from_be_bytes(bytes[24..32].try_into().unwrap());
     let limb1 = u64::from_be_bytes(bytes[16..24].try_into().unwrap());
@@ -63,7 +231,9 @@ pub fn from_be_bytes(bytes: &[u8; 32]) -> [u64; 4] {
     [limb0, limb1, limb2, limb3]
 }
 
-fn hash_to_field_evm(domain_prefix: &str, message: &[u8], buf: &mut [Fq; 2]) {
+// chained keccak hashes h_0 = keccak(0x00 || 0x01 || len(domain) || domain || len(message) || message)
+// and h_i = keccak(2i || 2i+1 || h_{i-1})
+fn expand_message_evm(domain_prefix: &str, message: &[u8], len: usize) -> Vec<[u8; 32]> {
     let domain = domain_prefix.as_bytes();
     let domain_len_bytes = domain.len().to_be_bytes().to_vec();
     // pad domain length to 32 bytes
@@ -88,29 +258,28 @@ fn hash_to_field_evm(domain_prefix: &str, message: &[u8], buf: &mut [Fq; 2]) {
         .finalize()
         .to_vec();
 
-    let hash1 = Keccak256::new()
-        .chain_update([2u8, 3u8])
-        .chain_update(&hash0)
-        .finalize()
-        .to_vec();
-    let hash2 = Keccak256::new()
-        .chain_update([4u8, 5u8])
-        .chain_update(&hash1)
-        .finalize()
-        .to_vec();
-    let hash3 = Keccak256::new()
-        .chain_update([6u8, 7u8])
-        .chain_update(&hash2)
-        .finalize()
-        .to_vec();
+    let mut hashes: Vec<[u8; 32]> = vec![hash0.try_into().unwrap()];
+    for i in 1..len {
+        let hash = Keccak256::new()
+            .chain_update([(2 * i) as u8, (2 * i + 1) as u8])
+            .chain_update(hashes[i - 1])
+            .finalize()
+            .to_vec();
+        hashes.push(hash.try_into().unwrap());
+    }
+
+    hashes
+}
 
-    let t0 = Fq::from_raw(from_be_bytes(&hash0.try_into().unwrap()));
-    let t1 = Fq::from_raw(from_be_bytes(&hash1.try_into().unwrap()));
-    let t2 = Fq::from_raw(from_be_bytes(&hash2.try_into().unwrap()));
-    let t3 = Fq::from_raw(from_be_bytes(&hash3.try_into().unwrap()));
+// each output element is built from two chained keccak hashes: t = h_{2i} * 2^256 + h_{2i+1}
+fn hash_to_field_evm(domain_prefix: &str, message: &[u8], buf: &mut [Fq]) {
+    let hashes = expand_message_evm(domain_prefix, message, 2 * buf.len());
 
-    buf[0] = t0 * R + t1;
-    buf[1] = t2 * R + t3;
+    for (i, b) in buf.iter_mut().enumerate() {
+        let t0 = Fq::from_raw(from_be_bytes(&hashes[2 * i]));
+        let t1 = Fq::from_raw(from_be_bytes(&hashes[2 * i + 1]));
+        *b = t0 * R + t1;
+    }
 }
 
 fn sign(t: Fq) -> Fq {
@@ -173,10 +342,250 @@ pub(crate) fn hash_to_curve_evm<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8])
     })
 }
 
+// same as hash_to_field_evm but reduced modulo the grumpkin base field
+fn hash_to_field_evm_grumpkin(domain_prefix: &str, message: &[u8], buf: &mut [GkBase]) {
+    let hashes = expand_message_evm(domain_prefix, message, 2 * buf.len());
+
+    for (i, b) in buf.iter_mut().enumerate() {
+        let t0 = GkBase::from_raw(from_be_bytes(&hashes[2 * i]));
+        let t1 = GkBase::from_raw(from_be_bytes(&hashes[2 * i + 1]));
+        *b = t0 * GK_R + t1;
+    }
+}
+
+fn curve_grumpkin(x: GkBase) -> GkBase {
+    x.cube() + GK_B
+}
+
+// fouque-tibouchi map to y^2 = x^3 - 17 as in map_to_curve_evm.
+// p = 1 mod 4 for the grumpkin base field, so the square root is not unique
+// and y is fixed to the root whose sign matches the sign of t
+fn map_to_curve_evm_grumpkin(t: GkBase) -> GkG1 {
+    // s = (t^2 + 1 + b)^3
+    let t_square = t.square();
+    let t4 = t_square.square();
+    let r = t_square + GkBase::ONE + GK_B;
+    let s = r.square() * r;
+
+    // alpha = 1/(t^2 * (t^2 + 1 + b))
+    let alpha = (t_square * r).invert().unwrap_or(GkBase::ZERO);
+
+    // x1 = C1 - C2 * t^4 * alpha
+    let x1 = GK_C1 - GK_C2 * t4 * alpha;
+    // x2 = -1 - x1
+    let x2 = -(x1 + GkBase::ONE);
+    // x3 = 1 - s * alpha/3
+    let x3 = GkBase::ONE - s * alpha * GK_C3;
+
+    let e1 = curve_grumpkin(x1).sqrt().is_some();
+    let e2 = curve_grumpkin(x2).sqrt().is_some();
+
+    let x = GkBase::conditional_select(&GkBase::conditional_select(&x3, &x2, e2), &x1, e1);
+    // at least one of x1, x2, x3 gives a square
+    let y = curve_grumpkin(x).sqrt().unwrap();
+    let y = if (y <= GK_Q2) == (t <= GK_Q2) { y } else { -y };
+
+    GkG1::new_jacobian(x, y, GkBase::ONE).unwrap()
+}
+
+pub(crate) fn hash_to_curve_evm_grumpkin<'a>(
+    domain_prefix: &'a str,
+) -> Box<dyn Fn(&[u8]) -> GkG1 + 'a> {
+    Box::new(move |message| {
+        let mut fs = [GkBase::ZERO; 2];
+        hash_to_field_evm_grumpkin(domain_prefix, message, &mut fs);
+
+        let q0 = map_to_curve_evm_grumpkin(fs[0]);
+        let q1 = map_to_curve_evm_grumpkin(fs[1]);
+
+        // grumpkin has prime order, no cofactor to clear
+        let r = q0 + &q1;
+        debug_assert!(bool::from(r.is_on_curve()));
+        r
+    })
+}
+
+// a <= b on the canonical representations, in constant time: no borrow in b - a
+fn ct_le(a: &Fq, b: &Fq) -> Choice {
+    let (a, b) = (a.to_repr(), b.to_repr());
+
+    let mut borrow = false;
+    for (a, b) in a.chunks(8).zip(b.chunks(8)) {
+        let a = u64::from_le_bytes(a.try_into().unwrap());
+        let b = u64::from_le_bytes(b.try_into().unwrap());
+        let (d, b1) = b.overflowing_sub(a);
+        let (_, b2) = d.overflowing_sub(borrow as u64);
+        borrow = b1 | b2;
+    }
+
+    Choice::from(!borrow as u8)
+}
+
+// constant time version of map_to_curve_evm for secret inputs: the sign of t and the
+// successful candidate are chosen with subtle instead of branches
+fn map_to_curve_evm_ct(t: Fq) -> G1 {
+    let t_sign = ct_le(&t, &Q2);
+    // s = (t^2 + 4)^3
+    let t_square = t.square();
+    let t4 = t_square.square();
+    let r = t_square + Fq::from(4u64);
+    let r_square = r.square();
+    let s = r * r_square;
+
+    // alpha = 1/(t^2 * (t^2 + 4))
+    let alpha = (t_square * r).invert().unwrap_or(Fq::ZERO);
+
+    // x1 = C1 - C2 * t^4 * alpha
+    let x1 = C1 - C2 * t4 * alpha;
+    // x2 = -1 - x1
+    let x2 = -(x1 + Fq::ONE);
+    // x3 = 1 - s * alpha/3
+    let x3 = Fq::ONE - s * alpha * C3;
+
+    let y1 = (x1.cube() + Fq::from(3u64)).sqrt();
+    let e1 = y1.is_some();
+    let y2 = (x2.cube() + Fq::from(3u64)).sqrt();
+    let e2 = y2.is_some();
+    let y3 = (x3.cube() + Fq::from(3u64)).sqrt();
+
+    let x = Fq::conditional_select(&Fq::conditional_select(&x3, &x2, e2), &x1, e1);
+    let y = Fq::conditional_select(
+        &Fq::conditional_select(&y3.unwrap_or(Fq::ZERO), &y2.unwrap_or(Fq::ZERO), e2),
+        &y1.unwrap_or(Fq::ZERO),
+        e1,
+    );
+    let y = Fq::conditional_select(&-y, &y, t_sign);
+
+    G1::new_jacobian(x, y, Fq::ONE).unwrap()
+}
+
+// same output as hash_to_curve_evm, but only the map of each field element is constant time:
+// the keccak expansion takes time linear in the message length, and the final jacobian
+// addition q0 + q1 branches on its inputs
+pub(crate) fn hash_to_curve_evm_ct<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> G1 + 'a> {
+    Box::new(move |message| {
+        let mut fs = [Fq::ZERO; 2];
+        hash_to_field_evm(domain_prefix, message, &mut fs);
+
+        let q0 = map_to_curve_evm_ct(fs[0]);
+        let q1 = map_to_curve_evm_ct(fs[1]);
+
+        q0 + &q1
+    })
+}
+
+// sgn0 for Fq2 as in rfc9380: parity of c0, or parity of c1 if c0 == 0
+fn sgn0_fq2(x: &Fq2) -> Choice {
+    let sign_0 = Choice::from(x.c0.to_bytes()[0] & 1);
+    let zero_0 = x.c0.is_zero();
+    let sign_1 = Choice::from(x.c1.to_bytes()[0] & 1);
+
+    sign_0 | (zero_0 & sign_1)
+}
+
+fn curve_g2(x: Fq2) -> Fq2 {
+    x.square() * x + B2
+}
+
+// shallue-van de woestijne map to the twist y^2 = x^3 + b' with Z = 1 (rfc9380 section 6.6.1)
+fn map_to_curve_evm_g2(u: Fq2) -> G2 {
+    let tv1 = u.square() * D1;
+    let tv2 = Fq2::ONE + tv1;
+    let tv1 = Fq2::ONE - tv1;
+    let tv3 = (tv1 * tv2).invert().unwrap_or(Fq2::ZERO);
+    let tv4 = u * tv1 * tv3 * D3;
+
+    let x1 = D2 - tv4;
+    let e1 = curve_g2(x1).sqrt().is_some();
+
+    let x2 = D2 + tv4;
+    let e2 = curve_g2(x2).sqrt().is_some() & !e1;
+
+    let x3 = tv2.square() * tv3;
+    let x3 = x3.square() * D4 + Fq2::ONE;
+
+    let x = Fq2::conditional_select(&x3, &x1, e1);
+    let x = Fq2::conditional_select(&x, &x2, e2);
+
+    // at least one of x1, x2, x3 gives a square
+    let y = curve_g2(x).sqrt().unwrap();
+    let e3 = !(sgn0_fq2(&u) ^ sgn0_fq2(&y));
+    let y = Fq2::conditional_select(&-y, &y, e3);
+
+    G2::new_jacobian(x, y, Fq2::ONE).unwrap()
+}
+
+// untwist-frobenius-twist endomorphism, acts as [q] on G2
+fn psi(p: G2) -> G2 {
+    if bool::from(p.is_identity()) {
+        return p;
+    }
+
+    let p = p.to_affine();
+    let x = Fq2 {
+        c0: p.x.c0,
+        c1: -p.x.c1,
+    } * PSI_X;
+    let y = Fq2 {
+        c0: p.y.c0,
+        c1: -p.y.c1,
+    } * PSI_Y;
+
+    G2::new_jacobian(x, y, Fq2::ONE).unwrap()
+}
+
+fn mul_by_x(p: G2) -> G2 {
+    let mut acc = G2::identity();
+    for i in (0..64).rev() {
+        acc = acc.double();
+        if (BN_X >> i) & 1 == 1 {
+            acc = acc + p;
+        }
+    }
+
+    acc
+}
+
+// budroni-pintore cofactor clearing (eprint 2017/419 section 4.1):
+// [x]P + psi([3x]P) + psi^2([x]P) + psi^3(P) lands in the prime order subgroup
+// with a 64-bit scalar multiplication instead of a multiplication by h
+fn clear_cofactor_g2(p: G2) -> G2 {
+    let xp = mul_by_x(p);
+    let p1 = psi(xp.double() + xp);
+    let p2 = psi(psi(xp));
+    let p3 = psi(psi(psi(p)));
+
+    xp + p1 + p2 + p3
+}
+
+// the field elements are expanded as in Hash.hashToField, but the G2 map and the cofactor
+// clearing have no solidity counterpart
+pub(crate) fn hash_to_curve_evm_g2<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> G2 + 'a> {
+    Box::new(move |message| {
+        let mut fs = [Fq::ZERO; 4];
+        hash_to_field_evm(domain_prefix, message, &mut fs);
+
+        let u0 = Fq2 {
+            c0: fs[0],
+            c1: fs[1],
+        };
+        let u1 = Fq2 {
+            c0: fs[2],
+            c1: fs[3],
+        };
+
+        let q0 = map_to_curve_evm_g2(u0);
+        let q1 = map_to_curve_evm_g2(u1);
+
+        let r = clear_cofactor_g2(q0 + &q1);
+        debug_assert!(bool::from(r.is_on_curve()));
+        r
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use halo2wrong::curves::group::Curve;
 
     #[test]
     fn test_hash() {
@@ -198,4 +607,175 @@ mod tests {
         println!("hash to point = {:?}", h.to_affine());
         assert!(bool::from(h.is_on_curve()))
     }
+
+    #[test]
+    fn test_hash_g2() {
+        use halo2wrong::curves::bn256::Fr;
+
+        let hasher = hash_to_curve_evm_g2("DVRF pseudorandom generation 2023 G2");
+        let h = hasher(b"hello world");
+        assert!(bool::from(h.is_on_curve()));
+        assert!(!bool::from(h.is_identity()));
+
+        // the output lies in the subgroup of order r: [r-1]h == -h
+        let minus_one = -Fr::ONE;
+        assert_eq!(h * minus_one, -h);
+
+        // test vectors, rust only as there is no solidity hash to G2;
+        // the evm encodes a G2 point as [x.c1, x.c0, y.c1, y.c0]
+        let h = h.to_affine();
+        let x = Fq2 {
+            c0: Fq::from_raw([
+                0x7f187eca2495953a,
+                0x8690f696795589f4,
+                0x14e74f257011776b,
+                0x17a69660a7290a67,
+            ]),
+            c1: Fq::from_raw([
+                0x0e6262279a4fe443,
+                0x2c1df54d5ccb0412,
+                0x1a57ebfaccd89944,
+                0x0845f5e6eec374d4,
+            ]),
+        };
+        let y = Fq2 {
+            c0: Fq::from_raw([
+                0x90c09fc2ea204b52,
+                0x5e4dea6f1218ba61,
+                0x84d22d076aaa97e9,
+                0x07da99eaff4ec365,
+            ]),
+            c1: Fq::from_raw([
+                0x8e4980b030214c50,
+                0xe171a72712c28930,
+                0x11cd7795ac7a5227,
+                0x0d22c846e4ff0579,
+            ]),
+        };
+        assert_eq!(h.x, x);
+        assert_eq!(h.y, y);
+
+        let h = hasher(b"").to_affine();
+        let x = Fq2 {
+            c0: Fq::from_raw([
+                0xb13fd2aaf33d1c07,
+                0x8c40c1f8b894262e,
+                0x9fbc6f4aff19317c,
+                0x1050b652b7571b1c,
+            ]),
+            c1: Fq::from_raw([
+                0x67e351b1a3f4bb8c,
+                0x2e7d05d6e7134f2e,
+                0x048582ae9de8e1f0,
+                0x0db2e8c3ae7e4822,
+            ]),
+        };
+        let y = Fq2 {
+            c0: Fq::from_raw([
+                0x0436dab3b1d8dde2,
+                0x1841b6cae04f2125,
+                0xefd44fa68243c7a2,
+                0x2f58e34dc6646061,
+            ]),
+            c1: Fq::from_raw([
+                0x2e762f2e0b065acd,
+                0x9eac8fdea11c12e8,
+                0xbfba31f321b7fa70,
+                0x12bd9c1f233de0c1,
+            ]),
+        };
+        assert_eq!(h.x, x);
+        assert_eq!(h.y, y);
+    }
+
+    #[test]
+    fn test_clear_cofactor_g2() {
+        use halo2wrong::curves::bn256::Fr;
+        use rand_core::OsRng;
+
+        // psi acts as [q] on G2, q mod r = 0x6f4d8248eeb859fbf83e9682e87cfd46
+        let g = G2::generator();
+        let q = Fr::from_raw([0xf83e9682e87cfd46, 0x6f4d8248eeb859fb, 0, 0]);
+        assert_eq!(psi(g), g * q);
+
+        // points on the twist outside G2 are mapped into it
+        let minus_one = -Fr::ONE;
+        for _ in 0..10 {
+            let u = Fq2::random(OsRng);
+            let p = map_to_curve_evm_g2(u);
+            assert_ne!(p * minus_one, -p);
+
+            let h = clear_cofactor_g2(p);
+            assert!(bool::from(h.is_on_curve()));
+            assert_eq!(h * minus_one, -h);
+        }
+    }
+
+    #[test]
+    fn test_hash_grumpkin() {
+        // the exceptional cases t = 0 and t^2 = -1 - b fall back to x1
+        for t in [GkBase::ZERO, GkBase::from(4u64), -GkBase::from(4u64)] {
+            let p = map_to_curve_evm_grumpkin(t);
+            assert!(bool::from(p.is_on_curve()));
+        }
+
+        let hasher = hash_to_curve_evm_grumpkin("DVRF pseudorandom generation 2023");
+        let h = hasher(b"hello world");
+        assert!(bool::from(h.is_on_curve()));
+        assert!(!bool::from(h.is_identity()));
+
+        // test vectors for the solidity implementation, checked in test/grumpkin.spec.ts
+        let h = h.to_affine();
+        let x = GkBase::from_raw([
+            0x7d9f36673f6c3642,
+            0x492ee16744fdb12c,
+            0x5e56135c76b5d5f9,
+            0x28ed2615657a4d52,
+        ]);
+        let y = GkBase::from_raw([
+            0x132be9ab395bf2a6,
+            0x9d66d2407155e703,
+            0xe020985b5818e32a,
+            0x2f3d2b9c50f15fb2,
+        ]);
+        assert_eq!(h.x, x);
+        assert_eq!(h.y, y);
+
+        let hasher = hash_to_curve_evm_grumpkin("another generator");
+        let h = hasher(b"").to_affine();
+        let x = GkBase::from_raw([
+            0x835e1c4886964b4a,
+            0x79baa3dc11ef9d08,
+            0x6b75b8f5cc21a731,
+            0x1dccb3657213352d,
+        ]);
+        let y = GkBase::from_raw([
+            0xcc2493039139f018,
+            0x606f0f48e2b85464,
+            0xf170e0941b0b18f9,
+            0x0368a122beb95b21,
+        ]);
+        assert_eq!(h.x, x);
+        assert_eq!(h.y, y);
+    }
+
+    #[test]
+    fn test_map_to_curve_ct() {
+        use rand_core::OsRng;
+
+        let edges = [Fq::ZERO, Fq::ONE, Q2, Q2 + Fq::ONE, MINUS_ONE];
+        let randoms = (0..1000).map(|_| Fq::random(OsRng));
+        for t in edges.into_iter().chain(randoms) {
+            assert_eq!(bool::from(ct_le(&t, &Q2)), t <= Q2);
+            let (p, q) = (map_to_curve_evm(t), map_to_curve_evm_ct(t));
+            assert_eq!((p.x, p.y, p.z), (q.x, q.y, q.z));
+        }
+
+        let hasher = hash_to_curve_evm("DVRF pseudorandom generation 2023");
+        let hasher_ct = hash_to_curve_evm_ct("DVRF pseudorandom generation 2023");
+        for i in 0..100u32 {
+            let (p, q) = (hasher(&i.to_be_bytes()), hasher_ct(&i.to_be_bytes()));
+            assert_eq!((p.x, p.y, p.z), (q.x, q.y, q.z));
+        }
+    }
 }
@@ -0,0 +1,334 @@
+use crate::hash_to_curve_evm::{from_be_bytes, hash_to_curve_evm, Q2, R};
+use halo2_ecc::halo2::arithmetic::CurveExt;
+use halo2wrong::curves::bn256::{Fq, G1};
+use halo2wrong::curves::ff::{Field, PrimeField};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use subtle::{Choice, ConditionallySelectable};
+
+pub const SHA256_SUITE_ID: &str = "BN254G1_XMD:SHA-256_SVDW_RO_";
+pub const KECCAK256_SUITE_ID: &str = "BN254G1_XMD:KECCAK-256_SVDW_RO_";
+
+// dst longer than 255 bytes is replaced by its hash (rfc9380 section 5.3.3)
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+// L = ceil((ceil(log2(q)) + k)/8) bytes per field element with k = 128
+const FIELD_LEN: usize = 48;
+
+/// svdw constants for G1 with Z = 1
+/// C3 = sqrt(-g(Z) * 3Z^2) = sqrt(-12) with sgn0(C3) = 0
+/// = 0x00000000000000016789af3a83522eb353c98fc6b36d713d5d8d1cc5dffffffa
+const C3: Fq = Fq::from_raw([
+    0x5d8d1cc5dffffffa,
+    0x53c98fc6b36d713d,
+    0x6789af3a83522eb3,
+    0x0000000000000001,
+]);
+
+/// C4 = -4g(Z)/(3Z^2) = -16/3
+/// = 0x10216f7ba065e00de81ac1e7808072c9dd2b2385cd7b438469602eb24829a9bd
+const C4: Fq = Fq::from_raw([
+    0x69602eb24829a9bd,
+    0xdd2b2385cd7b4384,
+    0xe81ac1e7808072c9,
+    0x10216f7ba065e00d,
+]);
+
+// how inputs are hashed to G1; Evm is the original construction of Hash.hashToG1,
+// the others are the rfc9380 suites with expand_message_xmd and the svdw map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HashSuite {
+    #[default]
+    Evm,
+    Sha256,
+    Keccak256,
+}
+
+impl HashSuite {
+    pub fn suite_id(&self) -> Option<&'static str> {
+        match self {
+            HashSuite::Evm => None,
+            HashSuite::Sha256 => Some(SHA256_SUITE_ID),
+            HashSuite::Keccak256 => Some(KECCAK256_SUITE_ID),
+        }
+    }
+
+    // the domain tagged with the suite id as recommended in rfc9380 section 3.1
+    pub fn dst(&self, domain: &str) -> String {
+        match self.suite_id() {
+            Some(suite_id) => format!("{domain}-with-{suite_id}"),
+            None => domain.to_string(),
+        }
+    }
+
+    pub fn hash_to_curve(&self, domain: &str, message: &[u8]) -> G1 {
+        let dst = self.dst(domain);
+        match self {
+            HashSuite::Evm => hash_to_curve_evm(&dst)(message),
+            HashSuite::Sha256 => hash_to_curve_xmd::<Sha256>(dst.as_bytes(), 64, message),
+            HashSuite::Keccak256 => hash_to_curve_xmd::<Keccak256>(dst.as_bytes(), 136, message),
+        }
+    }
+}
+
+// expand_message_xmd of rfc9380 section 5.3.1 for a hash with block size r_in_bytes
+fn expand_message_xmd<D: Digest>(
+    message: &[u8],
+    dst: &[u8],
+    r_in_bytes: usize,
+    len_in_bytes: usize,
+) -> Vec<u8> {
+    let b_in_bytes = <D as Digest>::output_size();
+    let ell = (len_in_bytes + b_in_bytes - 1) / b_in_bytes;
+    assert!(ell <= 255 && len_in_bytes <= 0xffff);
+
+    let dst = if dst.len() > 255 {
+        D::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize()
+            .to_vec()
+    } else {
+        dst.to_vec()
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b0 = D::new()
+        .chain_update(vec![0u8; r_in_bytes])
+        .chain_update(message)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst)
+        .chain_update(dst_len)
+        .finalize()
+        .to_vec();
+
+    let mut bi = D::new()
+        .chain_update(&b0)
+        .chain_update([1u8])
+        .chain_update(&dst)
+        .chain_update(dst_len)
+        .finalize()
+        .to_vec();
+    let mut uniform_bytes = bi.clone();
+    for i in 2..=ell {
+        let xor: Vec<_> = b0.iter().zip(bi.iter()).map(|(a, b)| a ^ b).collect();
+        bi = D::new()
+            .chain_update(xor)
+            .chain_update([i as u8])
+            .chain_update(&dst)
+            .chain_update(dst_len)
+            .finalize()
+            .to_vec();
+        uniform_bytes.extend_from_slice(&bi);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+// 48 big endian bytes reduced modulo q: hi * 2^256 + lo
+fn field_from_be_bytes(bytes: &[u8]) -> Fq {
+    let hi = u128::from_be_bytes(bytes[0..16].try_into().unwrap());
+    let lo = Fq::from_raw(from_be_bytes(&bytes[16..48].try_into().unwrap()));
+
+    Fq::from_u128(hi) * R + lo
+}
+
+fn hash_to_field_xmd<D: Digest>(dst: &[u8], r_in_bytes: usize, message: &[u8], buf: &mut [Fq]) {
+    let uniform_bytes = expand_message_xmd::<D>(message, dst, r_in_bytes, buf.len() * FIELD_LEN);
+
+    for (b, bytes) in buf.iter_mut().zip(uniform_bytes.chunks(FIELD_LEN)) {
+        *b = field_from_be_bytes(bytes);
+    }
+}
+
+// sgn0 for Fq as in rfc9380: parity of the canonical representation
+fn sgn0(x: &Fq) -> Choice {
+    Choice::from(x.to_repr()[0] & 1)
+}
+
+fn curve_g1(x: Fq) -> Fq {
+    x.square() * x + Fq::from(3u64)
+}
+
+// shallue-van de woestijne map to y^2 = x^3 + 3 with Z = 1 (rfc9380 section 6.6.1)
+fn map_to_curve_svdw(u: Fq) -> G1 {
+    // C1 = g(Z) = 4
+    let tv1 = u.square() * Fq::from(4u64);
+    let tv2 = Fq::ONE + tv1;
+    let tv1 = Fq::ONE - tv1;
+    let tv3 = (tv1 * tv2).invert().unwrap_or(Fq::ZERO);
+    let tv4 = u * tv1 * tv3 * C3;
+
+    // C2 = -Z/2 = (q-1)/2
+    let x1 = Q2 - tv4;
+    let e1 = curve_g1(x1).sqrt().is_some();
+
+    let x2 = Q2 + tv4;
+    let e2 = curve_g1(x2).sqrt().is_some() & !e1;
+
+    let x3 = tv2.square() * tv3;
+    let x3 = x3.square() * C4 + Fq::ONE;
+
+    let x = Fq::conditional_select(&x3, &x1, e1);
+    let x = Fq::conditional_select(&x, &x2, e2);
+
+    // at least one of x1, x2, x3 gives a square
+    let y = curve_g1(x).sqrt().unwrap();
+    let e3 = !(sgn0(&u) ^ sgn0(&y));
+    let y = Fq::conditional_select(&-y, &y, e3);
+
+    G1::new_jacobian(x, y, Fq::ONE).unwrap()
+}
+
+// hash_to_curve of rfc9380 section 3; G1 has cofactor 1
+fn hash_to_curve_xmd<D: Digest>(dst: &[u8], r_in_bytes: usize, message: &[u8]) -> G1 {
+    let mut fs = [Fq::ZERO; 2];
+    hash_to_field_xmd::<D>(dst, r_in_bytes, message, &mut fs);
+
+    let q0 = map_to_curve_svdw(fs[0]);
+    let q1 = map_to_curve_svdw(fs[1]);
+
+    let r = q0 + &q1;
+    debug_assert!(bool::from(r.is_on_curve()));
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2wrong::curves::group::Curve;
+
+    fn fq(hex: &str) -> Fq {
+        let bytes: [u8; 32] = hex::decode(format!("{hex:0>64}"))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Fq::from_raw(from_be_bytes(&bytes))
+    }
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // rfc9380 appendix K.1
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: [(&[u8], usize, &str); 3] = [
+            (
+                b"",
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+                 e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+                 eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+                 c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+        ];
+
+        for (message, len, expected) in vectors {
+            let uniform_bytes = expand_message_xmd::<Sha256>(message, dst, 64, len);
+            assert_eq!(hex::encode(uniform_bytes), expected);
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve_sha256() {
+        // BN254G1_XMD:SHA-256_SVDW_RO_ with the rfc9380 test dst
+        let dst = format!("QUUX-V01-CS02-with-{SHA256_SUITE_ID}");
+        let vectors: [(&[u8], &str, &str, &str, &str); 2] = [
+            (
+                b"",
+                "2f87b81d9d6ef05ad4d249737498cc27e1bd485dca804487844feb3c67c1a9b5",
+                "06de2d0d7c0d9c7a5a6c0b74675e7543f5b98186b5dbf831067449000b2b1f8e",
+                "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86",
+                "02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5",
+            ),
+            (
+                b"abc",
+                "11945105b5e3d3b9392b5a2318409cbc28b7246aa47fa30da5739907737799a9",
+                "1255fc9ad5a6e0fb440916f091229bda611c41be2f2283c3d8f98c596be4c8c9",
+                "23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1",
+                "04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d",
+            ),
+        ];
+
+        for (message, u0, u1, x, y) in vectors {
+            let mut fs = [Fq::ZERO; 2];
+            hash_to_field_xmd::<Sha256>(dst.as_bytes(), 64, message, &mut fs);
+            assert_eq!(fs, [fq(u0), fq(u1)]);
+
+            let h = hash_to_curve_xmd::<Sha256>(dst.as_bytes(), 64, message).to_affine();
+            assert_eq!((h.x, h.y), (fq(x), fq(y)));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve_keccak256() {
+        let dst = format!("QUUX-V01-CS02-with-{KECCAK256_SUITE_ID}");
+        let vectors: [(&[u8], &str, &str); 2] = [
+            (
+                b"",
+                "0c112533eaaa53fc814ee3ee0c23c45264bd0d24524d4af3a4abaea2fa9bc358",
+                "1418c14cf13dcb591a3ef964d0214db0936bc5efb568e1d168f6d66fadd34006",
+            ),
+            (
+                b"abc",
+                "1810adf4e1884db8b8ceb08ff576172d17456ce8eb4f0bffb878daa8470acb90",
+                "20926f6ce4d67301e6d5329a650da7074bb48088b363092eebd7472250635bc7",
+            ),
+        ];
+
+        for (message, x, y) in vectors {
+            let h = hash_to_curve_xmd::<Keccak256>(dst.as_bytes(), 136, message).to_affine();
+            assert_eq!((h.x, h.y), (fq(x), fq(y)));
+        }
+    }
+
+    #[test]
+    fn test_hash_suite() {
+        let domain = "DVRF pseudorandom generation 2023";
+        assert_eq!(
+            HashSuite::Sha256.dst(domain),
+            "DVRF pseudorandom generation 2023-with-BN254G1_XMD:SHA-256_SVDW_RO_"
+        );
+        assert_eq!(
+            HashSuite::Evm.hash_to_curve(domain, b"hello world"),
+            hash_to_curve_evm(domain)(b"hello world")
+        );
+
+        // test vectors for the solidity implementation, checked in test/hash_xmd.spec.ts
+        let h = HashSuite::Sha256
+            .hash_to_curve(domain, b"hello world")
+            .to_affine();
+        assert_eq!(
+            (h.x, h.y),
+            (
+                fq("1c2ffe13f873ad57c15c60ebb115bad2a233a5da7708c47b5a1d3f3c1aba8766"),
+                fq("0b730ac2570bc6a8bcbf366d4e56a0dc8897884d5cd9154644756753f7e197f5")
+            )
+        );
+        let h = HashSuite::Keccak256
+            .hash_to_curve(domain, b"hello world")
+            .to_affine();
+        assert_eq!(
+            (h.x, h.y),
+            (
+                fq("0cc3dbd4c392eee575e0fe3a990be7d022a080838a97b966fa02a7c31bbfe256"),
+                fq("1d84e9c05b3e12192622f61fd56e645cad6d71bdce71a336d4e60f2eaa8d10cf")
+            )
+        );
+
+        // the exceptional case u = 0 maps to x = C2
+        let p = map_to_curve_svdw(Fq::ZERO).to_affine();
+        assert_eq!(p.x, Q2);
+    }
+}
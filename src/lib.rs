@@ -1,21 +1,28 @@
+pub mod aggregate;
 pub mod dkg;
 pub mod dkg_circuit;
 #[allow(dead_code)]
 mod ecc_chip;
+pub mod encryption;
 mod error;
 #[allow(dead_code)]
 mod grumpkin_chip;
 mod hash_to_curve_evm;
+mod hash_to_curve_rfc;
 mod poseidon;
+pub mod signature;
+pub mod timelock;
 mod utils;
 
-pub use utils::{load_or_create_params, load_or_create_pk, load_or_create_vk};
+pub use utils::{load_or_create_params, load_or_create_pk, load_or_create_vk, MAX_DEGREE};
 
 use rand_core::RngCore;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashSet;
 use std::rc::Rc;
 
 pub use halo2_ecc::integer::NUMBER_OF_LOOKUP_LIMBS;
-use halo2_gadgets::poseidon::primitives::{ConstantLength, Hash};
 use halo2wrong::curves::bn256::{Fr as BnScalar, G1Affine as BnG1, G2Affine as BnG2};
 use halo2wrong::curves::ff::PrimeField;
 use halo2wrong::curves::group::prime::PrimeCurveAffine;
@@ -25,14 +32,23 @@ use halo2wrong::curves::CurveAffine;
 use halo2wrong::halo2::arithmetic::Field;
 use halo2wrong::halo2::circuit::Value;
 
+pub use crate::aggregate::{GlobalParamsBuilder, ShareAccumulator};
 pub use crate::dkg::{
-    combine_partial_evaluations, is_dl_equal, keygen, shares, DkgConfig, DkgShareKey, PseudoRandom,
-    EVAL_PREFIX,
+    combine_many, combine_partial_evaluations, combine_partial_evaluations_g2, is_dl_equal, keygen,
+    shares, BatchPartialEval, Combiner, DkgConfig, DkgShareKey, PartialEvalG2, PreparedGroupKey,
+    PseudoRandom, PseudoRandomBundle, PseudoRandomG2, SessionId, VerifyPath, EVAL_PREFIX,
+    EVAL_PREFIX_G2, MAX_CACHED_INDEX_SETS,
 };
-pub use crate::dkg_circuit::DkgCircuit;
+pub use crate::dkg_circuit::{recommend_circuit_params, DkgCircuit, DkgCircuitParams};
+pub use crate::encryption::{DealerContext, DecryptionProof};
 pub use crate::error::Error;
+pub use crate::hash_to_curve_rfc::{HashSuite, KECCAK256_SUITE_ID, SHA256_SUITE_ID};
 pub use crate::poseidon::P128Pow5T3Bn;
-pub use crate::utils::{hash_to_curve_bn, hash_to_curve_grumpkin, mod_n, rns_setup};
+pub use crate::signature::{PartialSignature, Signature, ThresholdSigner};
+pub use crate::utils::{
+    hash_to_curve_bn, hash_to_curve_bn_ct, hash_to_curve_bn_g2, hash_to_curve_grumpkin,
+    hash_to_curve_grumpkin_evm, mod_n, rns_setup,
+};
 #[cfg(feature = "g2chip")]
 use crate::utils::{point2_to_public, public_to_point2};
 use crate::utils::{point_to_public, public_to_point};
@@ -47,7 +63,11 @@ const POINT2_LEN: usize = POINT_LEN * 2;
 const POSEIDON_WIDTH: usize = 3;
 const POSEIDON_RATE: usize = 2;
 const POSEIDON_LEN: usize = 2;
+const POSEIDON_KEY_LEN: usize = 4;
 pub const WINDOW_SIZE: usize = 3;
+// default window of the grumpkin fixed base multiplication g^r; coefficients are constants,
+// so small windows are cheapest
+pub const GRUMPKIN_WINDOW_SIZE: usize = 2;
 
 #[derive(Debug, Clone)]
 pub struct MemberKey {
@@ -76,13 +96,27 @@ impl MemberKey {
         self.sk
     }
 
-    pub fn decrypt_share(&self, gr: &GkG1, cipher: &BnScalar) -> BnScalar {
+    // decrypt the cipher for member index after checking its tag under the dealer context
+    pub fn decrypt_share(
+        &self,
+        context: &DealerContext,
+        index: usize,
+        gr: &GkG1,
+        cipher: &BnScalar,
+        tag: &BnScalar,
+    ) -> Result<BnScalar, Error> {
         let pkr = (gr * self.sk).to_affine();
-        let poseidon = Hash::<_, P128Pow5T3Bn, ConstantLength<2>, 3, 2>::init();
-        let key = poseidon.clone().hash([pkr.x, pkr.y]);
-        let plaintext = cipher - key;
+        context.decrypt(&pkr, index, cipher, tag)
+    }
 
-        plaintext
+    // reveal pk^r of a dealing with a proof, e.g. to complain about the dealer's cipher
+    pub fn prove_decryption(
+        &self,
+        gr: &GkG1,
+        cipher: &BnScalar,
+        rng: impl RngCore,
+    ) -> DecryptionProof {
+        DecryptionProof::prove(&self.sk, gr, cipher, rng)
     }
 
     // find the index of this member in a list of public keys; member_index is array_index + 1
@@ -104,17 +138,43 @@ impl MemberKey {
             return Err(Error::InvalidIndex { index });
         }
 
-        let k = index - 1;
-        let mut sk = BnScalar::zero();
-        for &pp in pps.iter() {
-            let s = self.decrypt_share(&pp.gr, &pp.ciphers[k]);
-            sk += s;
+        // each dealer contributes at most once
+        let mut dealers = HashSet::new();
+        for pp in pps.iter() {
+            let dealer_index = pp.context.dealer_index();
+            check_dealer_context(dkg_config, dealer_index, &pp.context)?;
+            if !dealers.insert(dealer_index) {
+                return Err(Error::DuplicateDealer {
+                    index: dealer_index,
+                });
+            }
         }
 
+        let k = index - 1;
+        let decrypt = |pp: &&DkgMemberPublicParams| {
+            let dealer_index = pp.context.dealer_index();
+            match (pp.ciphers.get(k), pp.tags.get(k)) {
+                (Some(cipher), Some(tag)) => {
+                    self.decrypt_share(&pp.context, index, &pp.gr, cipher, tag)
+                }
+                _ => Err(Error::InvalidDealer {
+                    index: dealer_index,
+                }),
+            }
+        };
+        #[cfg(feature = "parallel")]
+        let shares = pps
+            .par_iter()
+            .map(decrypt)
+            .collect::<Result<Vec<_>, Error>>()?;
+        #[cfg(not(feature = "parallel"))]
+        let shares = pps.iter().map(decrypt).collect::<Result<Vec<_>, Error>>()?;
+        let sk = shares.iter().fold(BnScalar::zero(), |acc, s| acc + s);
+
         let g = BnG1::generator();
         let vk = (g * sk).to_affine();
 
-        Ok(DkgShareKey::new(index, sk, vk))
+        Ok(DkgShareKey::new(index, sk, vk).with_session(dkg_config.session()))
     }
 }
 
@@ -123,13 +183,23 @@ pub struct DkgMemberPublicParams {
     // each member is indexed between 1...NUMBER_OF_MEMBERS
     pub public_shares: Vec<BnG1>,
     pub ciphers: Vec<BnScalar>,
+    // tags of the ciphers, checked by the recipients
+    pub tags: Vec<BnScalar>,
+    pub context: DealerContext,
     pub gr: GkG1,
     pub ga: BnG1,
     pub g2a: BnG2,
+    // g2^s_i for verifying partial evaluations in G2
+    #[cfg(feature = "g2shares")]
+    pub public_shares2: Vec<BnG2>,
 }
 
 impl DkgMemberPublicParams {
     pub fn instance(&self, pks: &[GkG1]) -> Vec<Vec<BnScalar>> {
+        self.instance_with_selectors(pks, &[])
+    }
+
+    fn instance_with_selectors(&self, pks: &[GkG1], selectors: &[BnScalar]) -> Vec<Vec<BnScalar>> {
         let (rns_base, _) = rns_setup::<BnG1>(0);
         let rns_base = Rc::new(rns_base);
 
@@ -145,6 +215,12 @@ impl DkgMemberPublicParams {
         #[cfg(feature = "g2chip")]
         public_data.extend(g2a_public);
 
+        #[cfg(feature = "g2shares")]
+        for i in 0..pks.len() {
+            let gs2_public = point2_to_public(Rc::clone(&rns_base), self.public_shares2[i]);
+            public_data.extend(gs2_public);
+        }
+
         public_data.push(self.gr.x);
         public_data.push(self.gr.y);
 
@@ -152,73 +228,234 @@ impl DkgMemberPublicParams {
             public_data.push(*c);
         }
 
+        for t in self.tags.iter() {
+            public_data.push(*t);
+        }
+
+        public_data.push(BnScalar::from(self.context.dealer_index() as u64));
+        public_data.push(self.context.session().value());
+
         for i in 0..pks.len() {
             public_data.push(pks[i].x);
             public_data.push(pks[i].y);
         }
 
+        public_data.extend_from_slice(selectors);
+
         let instance = vec![public_data];
         instance
     }
 
+    // instance of a padded circuit: inactive member slots hold generators and zero ciphers,
+    // and the selector bits are appended at the end
+    pub fn padded_instance(&self, dkg_config: &DkgConfig, pks: &[GkG1]) -> Vec<Vec<BnScalar>> {
+        let number_of_members = dkg_config.max_number_of_members();
+
+        let mut padded = self.clone();
+        padded
+            .public_shares
+            .resize(number_of_members, BnG1::generator());
+        padded.ciphers.resize(number_of_members, BnScalar::zero());
+        padded.tags.resize(number_of_members, BnScalar::zero());
+        #[cfg(feature = "g2shares")]
+        padded
+            .public_shares2
+            .resize(number_of_members, BnG2::generator());
+
+        let mut pks = pks.to_vec();
+        pks.resize(number_of_members, GkG1::generator());
+
+        padded.instance_with_selectors(&pks, &dkg_config.selectors())
+    }
+
+    // check the cipher for member index with the pk^r revealed by the member:
+    // VerifyFailed rejects the proof, InvalidTag or InvalidShare blames the dealer
+    pub fn verify_share(
+        &self,
+        index: usize,
+        pk: &GkG1,
+        proof: &DecryptionProof,
+    ) -> Result<BnScalar, Error> {
+        let k = index.checked_sub(1).ok_or(Error::InvalidIndex { index })?;
+        let (cipher, tag, public_share) = match (
+            self.ciphers.get(k),
+            self.tags.get(k),
+            self.public_shares.get(k),
+        ) {
+            (Some(cipher), Some(tag), Some(public_share)) => (cipher, tag, public_share),
+            _ => return Err(Error::InvalidIndex { index }),
+        };
+        proof.verify(pk, &self.gr, cipher)?;
+
+        let s = self.context.decrypt(&proof.pkr, index, cipher, tag)?;
+        if (BnG1::generator() * s).to_affine() != *public_share {
+            return Err(Error::InvalidShare {
+                index: self.context.dealer_index(),
+            });
+        }
+
+        Ok(s)
+    }
+
     // check if ga and g2a have the same exponent
     pub fn check_public(&self) -> Result<(), Error> {
         is_dl_equal(&self.ga, &self.g2a)
     }
 
     #[cfg(feature = "g2chip")]
-    pub fn from_instance(dkg_config: &DkgConfig, instance: &[BnScalar]) -> (Self, Vec<GkG1>) {
+    pub fn from_instance(
+        dkg_config: &DkgConfig,
+        instance: &[BnScalar],
+    ) -> Result<(Self, Vec<GkG1>), Error> {
         let len = dkg_config.instance_size();
-        assert_eq!(len, instance.len());
+        if instance.len() != len {
+            return Err(Error::LengthMismatch {
+                left: instance.len(),
+                right: len,
+            });
+        }
+        let number_of_members = dkg_config.max_number_of_members();
 
         // read ga
-        let ga: BnG1 = public_to_point(&instance[0..POINT_LEN]);
+        let ga: BnG1 = public_to_point(&instance[0..POINT_LEN])
+            .ok_or(Error::InvalidInstance { position: 0 })?;
 
         // read gs1,..., gs_n
         let mut public_shares: Vec<BnG1> = vec![];
         let mut begin = POINT_LEN;
-        for _ in 0..dkg_config.number_of_members() {
-            let gs: BnG1 = public_to_point(&instance[begin..begin + POINT_LEN]);
+        for _ in 0..number_of_members {
+            let gs: BnG1 = public_to_point(&instance[begin..begin + POINT_LEN])
+                .ok_or(Error::InvalidInstance { position: begin })?;
             public_shares.push(gs);
             begin += POINT_LEN;
         }
 
         // read g2a
-        let g2a: BnG2 = public_to_point2(&instance[begin..begin + POINT2_LEN]);
+        let g2a: BnG2 = public_to_point2(&instance[begin..begin + POINT2_LEN])
+            .ok_or(Error::InvalidInstance { position: begin })?;
         begin += POINT2_LEN;
 
+        // read g2s1,..., g2s_n
+        #[cfg(feature = "g2shares")]
+        let mut public_shares2: Vec<BnG2> = vec![];
+        #[cfg(feature = "g2shares")]
+        for _ in 0..number_of_members {
+            let gs2: BnG2 = public_to_point2(&instance[begin..begin + POINT2_LEN])
+                .ok_or(Error::InvalidInstance { position: begin })?;
+            public_shares2.push(gs2);
+            begin += POINT2_LEN;
+        }
+
         // read gr
-        let gr = GkG1::from_xy(instance[begin], instance[begin + 1]).unwrap();
+        let gr = Option::<GkG1>::from(GkG1::from_xy(instance[begin], instance[begin + 1]))
+            .ok_or(Error::InvalidInstance { position: begin })?;
         begin += 2;
 
         // read cipher_1, ..., cipher_n
         let mut ciphers = vec![];
-        for _ in 0..dkg_config.number_of_members() {
+        for _ in 0..number_of_members {
             let cipher = instance[begin];
             ciphers.push(cipher);
 
             begin += 1;
         }
 
+        // read tag_1, ..., tag_n
+        let mut tags = vec![];
+        for _ in 0..number_of_members {
+            tags.push(instance[begin]);
+            begin += 1;
+        }
+
+        // read dealer index and session
+        let context = DealerContext::from_public(instance[begin], instance[begin + 1])?;
+        begin += 2;
+
         // read pk_1, ..., pk_n
         let mut pks = vec![];
-        for _ in 0..dkg_config.number_of_members() {
-            let pk = GkG1::from_xy(instance[begin], instance[begin + 1]).unwrap();
+        for i in 0..number_of_members {
+            let pk = Option::<GkG1>::from(GkG1::from_xy(instance[begin], instance[begin + 1]))
+                .ok_or(Error::InvalidPublicKey { index: i + 1 })?;
             pks.push(pk);
 
             begin += 2;
         }
 
+        // keep the active members only
+        if dkg_config.is_padded() {
+            let selectors = dkg_config.selectors();
+            if let Some(i) = (0..selectors.len()).find(|&i| instance[begin + i] != selectors[i]) {
+                return Err(Error::InvalidInstance {
+                    position: begin + i,
+                });
+            }
+
+            let n = dkg_config.number_of_members();
+            public_shares.truncate(n);
+            #[cfg(feature = "g2shares")]
+            public_shares2.truncate(n);
+            ciphers.truncate(n);
+            tags.truncate(n);
+            pks.truncate(n);
+        }
+
         let pp = Self {
             public_shares,
             ciphers,
+            tags,
+            context,
             gr,
             ga,
             g2a,
+            #[cfg(feature = "g2shares")]
+            public_shares2,
         };
 
-        (pp, pks)
+        Ok((pp, pks))
+    }
+}
+
+// member public keys must be points on curve other than the identity with distinct x coordinates,
+// so a key and its negation are not accepted together; the circuit checks the same
+pub fn check_public_keys(public_keys: &[GkG1]) -> Result<(), Error> {
+    let mut xs = HashSet::new();
+    for (i, pk) in public_keys.iter().enumerate() {
+        if bool::from(pk.is_identity()) || !bool::from(pk.is_on_curve()) {
+            return Err(Error::InvalidPublicKey { index: i + 1 });
+        }
+        if !xs.insert(pk.x.to_repr()) {
+            return Err(Error::DuplicatePublicKey { index: i + 1 });
+        }
     }
+
+    Ok(())
+}
+
+// public params received in the slot of dealer_index must have been dealt by that dealer in the session
+// of dkg_config, otherwise a dealing could be replayed under another index or in another session
+pub fn check_dealer_context(
+    dkg_config: &DkgConfig,
+    dealer_index: usize,
+    context: &DealerContext,
+) -> Result<(), Error> {
+    if dealer_index < 1 || dealer_index > dkg_config.number_of_members() {
+        return Err(Error::InvalidIndex {
+            index: dealer_index,
+        });
+    }
+    if context.dealer_index() != dealer_index {
+        return Err(Error::InvalidDealer {
+            index: dealer_index,
+        });
+    }
+    // ciphers are bound to the session id, not to the hash suite
+    if context.session().value() != dkg_config.session().value() {
+        return Err(Error::InvalidSession {
+            index: dealer_index,
+        });
+    }
+
+    Ok(())
 }
 
 #[derive(Clone, Debug)]
@@ -232,13 +469,37 @@ pub struct DkgMemberParams {
 }
 
 impl DkgMemberParams {
+    // dealing of dealer_index in 1..=n, bound to the session of dkg_config
     pub fn new(
         dkg_config: DkgConfig,
+        dealer_index: usize,
         public_keys: Vec<GkG1>,
-        mut rng: impl RngCore,
+        rng: impl RngCore,
+    ) -> Result<Self, Error> {
+        let context = DealerContext::new(dkg_config.session(), dealer_index);
+        Self::new_with_context(dkg_config, context, public_keys, rng)
+    }
+
+    pub fn new_with_context(
+        dkg_config: DkgConfig,
+        context: DealerContext,
+        public_keys: Vec<GkG1>,
+        rng: impl RngCore,
     ) -> Result<Self, Error> {
         assert_eq!(public_keys.len(), dkg_config.number_of_members());
+        check_public_keys(&public_keys)?;
+        check_dealer_context(&dkg_config, context.dealer_index(), &context)?;
+
+        Ok(Self::new_unchecked(dkg_config, context, public_keys, rng))
+    }
 
+    // dealing without checking the public keys, which the circuit would reject
+    fn new_unchecked(
+        dkg_config: DkgConfig,
+        context: DealerContext,
+        public_keys: Vec<GkG1>,
+        mut rng: impl RngCore,
+    ) -> Self {
         // generate random coefficients for polynomial
         let coeffs: Vec<_> = (0..dkg_config.threshold())
             .map(|_| BnScalar::random(&mut rng))
@@ -253,7 +514,17 @@ impl DkgMemberParams {
 
         // compute secret shares for members
         let shares = shares(dkg_config.number_of_members(), &coeffs);
-        let public_shares: Vec<_> = shares.iter().map(|s| (g * s).to_affine()).collect();
+        let public_share = |s: &BnScalar| (g * s).to_affine();
+        #[cfg(feature = "parallel")]
+        let public_shares: Vec<_> = shares.par_iter().map(public_share).collect();
+        #[cfg(not(feature = "parallel"))]
+        let public_shares: Vec<_> = shares.iter().map(public_share).collect();
+        #[cfg(feature = "g2shares")]
+        let public_share2 = |s: &BnScalar| (g2 * s).to_affine();
+        #[cfg(all(feature = "g2shares", feature = "parallel"))]
+        let public_shares2: Vec<_> = shares.par_iter().map(public_share2).collect();
+        #[cfg(all(feature = "g2shares", not(feature = "parallel")))]
+        let public_shares2: Vec<_> = shares.iter().map(public_share2).collect();
 
         // draw arandomness for encryption
         let r = BnScalar::random(&mut rng);
@@ -262,56 +533,101 @@ impl DkgMemberParams {
             .expect("unable to convert Bn256 scalar to Grumpkin scalar");
         let gr = (gg * rs).to_affine();
 
-        // encrypt shares
-        let poseidon = Hash::<_, P128Pow5T3Bn, ConstantLength<2>, 3, 2>::init();
-        let mut ciphers = vec![];
-        for i in 0..dkg_config.number_of_members() {
-            let pkr = (public_keys[i] * rs).to_affine();
-            let key = poseidon.clone().hash([pkr.x, pkr.y]);
-            let cipher = key + shares[i];
-            ciphers.push(cipher);
-        }
+        // encrypt shares for member i + 1
+        let encrypt = |(i, (pk, s)): (usize, (&GkG1, &BnScalar))| {
+            let pkr = (pk * rs).to_affine();
+            context.encrypt(&pkr, i + 1, s)
+        };
+        #[cfg(feature = "parallel")]
+        let (ciphers, tags): (Vec<_>, Vec<_>) = public_keys
+            .par_iter()
+            .zip(shares.par_iter())
+            .enumerate()
+            .map(encrypt)
+            .unzip();
+        #[cfg(not(feature = "parallel"))]
+        let (ciphers, tags): (Vec<_>, Vec<_>) = public_keys
+            .iter()
+            .zip(shares.iter())
+            .enumerate()
+            .map(encrypt)
+            .unzip();
 
         let public_params = DkgMemberPublicParams {
             public_shares,
             ciphers,
+            tags,
+            context,
             gr,
             ga,
             g2a,
+            #[cfg(feature = "g2shares")]
+            public_shares2,
         };
 
-        Ok(DkgMemberParams {
+        DkgMemberParams {
             dkg_config,
             coeffs,
             shares,
             r,
             public_keys,
             public_params,
-        })
+        }
+    }
+
+    pub fn circuit(&self, rng: impl RngCore) -> DkgCircuit {
+        self.circuit_with_params(DkgCircuitParams::default(), rng)
     }
 
-    pub fn circuit(&self, mut rng: impl RngCore) -> DkgCircuit {
-        let coeffs: Vec<_> = self.coeffs.iter().map(|a| Value::known(*a)).collect();
-        let public_keys: Vec<_> = self
+    pub fn circuit_with_params(
+        &self,
+        circuit_params: DkgCircuitParams,
+        mut rng: impl RngCore,
+    ) -> DkgCircuit {
+        // unused slots of a padded circuit take zero coefficients and generator public keys
+        let mut coeffs: Vec<_> = self.coeffs.iter().map(|a| Value::known(*a)).collect();
+        coeffs.resize(
+            self.dkg_config.max_threshold(),
+            Value::known(BnScalar::zero()),
+        );
+        let mut public_keys: Vec<_> = self
             .public_keys
             .iter()
             .map(|pk| Value::known(*pk))
             .collect();
+        public_keys.resize(
+            self.dkg_config.max_number_of_members(),
+            Value::known(GkG1::generator()),
+        );
 
-        let grumpkin_aux_generator = Value::known(GkG1::random(&mut rng));
+        // aux generator must not share the x coordinate with any public key
+        let aux_generator = loop {
+            let aux = GkG1::random(&mut rng);
+            if self.public_keys.iter().all(|pk| pk.x != aux.x) {
+                break aux;
+            }
+        };
+        let grumpkin_aux_generator = Value::known(aux_generator);
         let circuit = DkgCircuit::new(
             self.dkg_config,
             coeffs,
             Value::known(self.r),
             public_keys,
             grumpkin_aux_generator,
-        );
+        )
+        .with_circuit_params(circuit_params)
+        .with_context(self.public_params.context);
 
         circuit
     }
 
     pub fn instance(&self) -> Vec<Vec<BnScalar>> {
-        self.public_params.instance(&self.public_keys)
+        if self.dkg_config.is_padded() {
+            self.public_params
+                .padded_instance(&self.dkg_config, &self.public_keys)
+        } else {
+            self.public_params.instance(&self.public_keys)
+        }
     }
 
     pub fn member_public_params(&self) -> &DkgMemberPublicParams {
@@ -324,6 +640,9 @@ pub struct DkgGlobalPubParams {
     pub ga: BnG1,
     pub g2a: BnG2,
     pub verify_keys: Vec<BnG1>,
+    // g2^sk_i for verifying partial evaluations in G2
+    #[cfg(feature = "g2shares")]
+    pub verify_keys2: Vec<BnG2>,
 }
 
 impl DkgGlobalPubParams {
@@ -345,20 +664,45 @@ pub fn dkg_global_public_params(pps: &[&DkgMemberPublicParams]) -> DkgGlobalPubP
         .fold(pps[0].g2a, |acc, pp| (acc + pp.g2a).to_affine());
 
     // compute vk_1, ... vk_n
-    let mut vks = vec![];
     let number_of_members = pps[0].public_shares.len();
-    for i in 0..number_of_members {
+    let verify_key = |i: usize| {
         let mut vk = pps[0].public_shares[i].to_curve();
         for pp in pps.iter().skip(1) {
             vk = vk + pp.public_shares[i];
         }
-        vks.push(vk.to_affine());
-    }
+        vk.to_affine()
+    };
+    #[cfg(feature = "parallel")]
+    let vks: Vec<_> = (0..number_of_members)
+        .into_par_iter()
+        .map(verify_key)
+        .collect();
+    #[cfg(not(feature = "parallel"))]
+    let vks: Vec<_> = (0..number_of_members).map(verify_key).collect();
+
+    // compute g2^sk_1, ..., g2^sk_n
+    #[cfg(feature = "g2shares")]
+    let verify_key2 = |i: usize| {
+        let mut vk2 = pps[0].public_shares2[i].to_curve();
+        for pp in pps.iter().skip(1) {
+            vk2 = vk2 + pp.public_shares2[i];
+        }
+        vk2.to_affine()
+    };
+    #[cfg(all(feature = "g2shares", feature = "parallel"))]
+    let vks2: Vec<_> = (0..number_of_members)
+        .into_par_iter()
+        .map(verify_key2)
+        .collect();
+    #[cfg(all(feature = "g2shares", not(feature = "parallel")))]
+    let vks2: Vec<_> = (0..number_of_members).map(verify_key2).collect();
 
     DkgGlobalPubParams {
         ga,
         g2a,
         verify_keys: vks,
+        #[cfg(feature = "g2shares")]
+        verify_keys2: vks2,
     }
 }
 
@@ -370,7 +714,8 @@ mod tests {
     use ark_std::{end_timer, start_timer};
     use halo2_ecc::halo2::SerdeFormat;
     use halo2wrong::curves::bn256::Bn256;
-    use halo2wrong::halo2::plonk::{create_proof, keygen_pk, keygen_vk, verify_proof};
+    use halo2wrong::halo2::dev::MockProver;
+    use halo2wrong::halo2::plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, Circuit};
     use halo2wrong::halo2::poly::commitment::ParamsProver;
     use halo2wrong::halo2::poly::kzg::commitment::{
         KZGCommitmentScheme, ParamsKZG, ParamsVerifierKZG,
@@ -401,7 +746,7 @@ mod tests {
 
         let dkg_config = DkgConfig::new(threshold, number_of_members).unwrap();
         let (pks, _) = mock_members(&dkg_config, &mut rng);
-        let dkg_params = DkgMemberParams::new(dkg_config, pks, &mut rng).unwrap();
+        let dkg_params = DkgMemberParams::new(dkg_config, 1, pks, &mut rng).unwrap();
         let circuit = dkg_params.circuit(&mut rng);
         let instance = dkg_params.instance();
         println!("total instance {:?}", instance[0].len());
@@ -432,6 +777,319 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dkg_circuit_window_size() {
+        let mut rng = OsRng;
+
+        let dkg_config = DkgConfig::new(3, 5).unwrap();
+        let (pks, _) = mock_members(&dkg_config, &mut rng);
+        let dkg_params = DkgMemberParams::new(dkg_config, 1, pks, &mut rng).unwrap();
+        let instance = dkg_params.instance();
+
+        for window_size in [2, 4] {
+            let circuit_params = DkgCircuitParams::new(window_size).unwrap();
+            let circuit = dkg_params.circuit_with_params(circuit_params, &mut rng);
+            mock_prover_verify(&circuit, instance.clone());
+        }
+
+        assert!(DkgCircuitParams::new(0).is_err());
+        assert!(DkgCircuitParams::new(1).is_err());
+
+        // window of the grumpkin multiplication g^r
+        for grumpkin_window_size in [3, 4] {
+            let circuit_params = DkgCircuitParams::default()
+                .with_grumpkin_window_size(grumpkin_window_size)
+                .unwrap();
+            let circuit = dkg_params.circuit_with_params(circuit_params, &mut rng);
+            mock_prover_verify(&circuit, instance.clone());
+        }
+        assert!(DkgCircuitParams::default()
+            .with_grumpkin_window_size(1)
+            .is_err());
+
+        // canonical decomposition of r only adds constraints
+        let degree = dkg_params
+            .circuit_with_params(DkgCircuitParams::default(), &mut rng)
+            .degree()
+            .unwrap();
+        let circuit_params = DkgCircuitParams {
+            canonical_random: true,
+            ..Default::default()
+        };
+        let circuit = dkg_params.circuit_with_params(circuit_params, &mut rng);
+        mock_prover_verify(&circuit, instance);
+        assert!(circuit.degree().unwrap() >= degree);
+    }
+
+    #[test]
+    fn test_dkg_circuit_padded() {
+        let mut rng = OsRng;
+
+        // two committees proven with the same (t_max, n_max) circuit
+        for (threshold, number_of_members) in [(2, 3), (3, 4)] {
+            let dkg_config = DkgConfig::new_padded(threshold, number_of_members, 4, 5).unwrap();
+            let (pks, _) = mock_members(&dkg_config, &mut rng);
+            let dkg_params = DkgMemberParams::new(dkg_config, 1, pks.clone(), &mut rng).unwrap();
+            let circuit = dkg_params.circuit(&mut rng);
+            let instance = dkg_params.instance();
+            assert_eq!(instance[0].len(), dkg_config.instance_size());
+
+            mock_prover_verify(&circuit, instance.clone());
+
+            #[cfg(feature = "g2chip")]
+            {
+                let (pp, pks_read) =
+                    DkgMemberPublicParams::from_instance(&dkg_config, &instance[0]).unwrap();
+                assert_eq!(pks_read, pks);
+                assert_eq!(pp.ciphers, dkg_params.public_params.ciphers);
+                assert_eq!(pp.tags, dkg_params.public_params.tags);
+                assert_eq!(pp.context, dkg_params.public_params.context);
+                assert_eq!(pp.public_shares, dkg_params.public_params.public_shares);
+
+                // malformed instances are rejected without panicking
+                let len = instance[0].len();
+                let res = DkgMemberPublicParams::from_instance(&dkg_config, &instance[0][1..]);
+                assert!(matches!(res, Err(Error::LengthMismatch { .. })));
+
+                let mut bad = instance[0].clone();
+                bad[len - 1] += BnScalar::one();
+                let res = DkgMemberPublicParams::from_instance(&dkg_config, &bad);
+                assert!(
+                    matches!(res, Err(Error::InvalidInstance { position }) if position == len - 1)
+                );
+
+                let pk_begin =
+                    len - dkg_config.selectors().len() - 2 * dkg_config.max_number_of_members();
+                let mut bad = instance[0].clone();
+                bad[pk_begin + 1] += BnScalar::one();
+                let res = DkgMemberPublicParams::from_instance(&dkg_config, &bad);
+                assert!(matches!(res, Err(Error::InvalidPublicKey { index: 1 })));
+            }
+
+            // a nonzero coefficient in an unused slot is rejected
+            let mut coeffs: Vec<_> = dkg_params.coeffs.iter().map(|a| Value::known(*a)).collect();
+            coeffs.resize(
+                dkg_config.max_threshold(),
+                Value::known(BnScalar::random(&mut rng)),
+            );
+            let mut public_keys: Vec<_> = pks.iter().map(|pk| Value::known(*pk)).collect();
+            public_keys.resize(
+                dkg_config.max_number_of_members(),
+                Value::known(GkG1::generator()),
+            );
+            let forged = DkgCircuit::new(
+                dkg_config,
+                coeffs,
+                Value::known(dkg_params.r),
+                public_keys,
+                Value::known(GkG1::random(&mut rng)),
+            );
+            let k = DimensionMeasurement::measure(&forged).unwrap().k();
+            let prover = MockProver::run(k, &forged, instance).unwrap();
+            assert!(prover.verify().is_err());
+        }
+
+        assert!(DkgConfig::new_padded(3, 4, 2, 5).is_err());
+        assert!(DkgConfig::new_padded(3, 6, 3, 5).is_err());
+    }
+
+    #[test]
+    fn test_member_public_keys() {
+        let mut rng = OsRng;
+
+        let dkg_config = DkgConfig::new(3, 5).unwrap();
+        let (pks, _) = mock_members(&dkg_config, &mut rng);
+        check_public_keys(&pks).unwrap();
+
+        let mut duplicate = pks.clone();
+        duplicate[3] = pks[1];
+        let mut negation = pks.clone();
+        negation[3] = -pks[1];
+        let mut identity = pks.clone();
+        identity[2] = GkG1::identity();
+
+        assert!(check_public_keys(&duplicate).is_err());
+        assert!(check_public_keys(&negation).is_err());
+        assert!(check_public_keys(&identity).is_err());
+        assert!(DkgMemberParams::new(dkg_config, 1, duplicate.clone(), &mut rng).is_err());
+
+        // the circuit rejects the same keys when dealing skips the checks
+        let prover = |circuit: &DkgCircuit, instance| {
+            let k = DimensionMeasurement::measure(circuit).unwrap().k();
+            MockProver::run(k, circuit, instance).unwrap().verify()
+        };
+        for forged_pks in [duplicate, negation, identity] {
+            let dkg_params = DkgMemberParams::new_unchecked(
+                dkg_config,
+                DealerContext::default(),
+                forged_pks,
+                &mut rng,
+            );
+            let circuit = dkg_params.circuit(&mut rng);
+            assert!(prover(&circuit, dkg_params.instance()).is_err());
+        }
+
+        // a key equal to aux generator
+        let dkg_params = DkgMemberParams::new(dkg_config, 1, pks.clone(), &mut rng).unwrap();
+        let forged = DkgCircuit::new(
+            dkg_config,
+            dkg_params.coeffs.iter().map(|a| Value::known(*a)).collect(),
+            Value::known(dkg_params.r),
+            pks.iter().map(|pk| Value::known(*pk)).collect(),
+            Value::known(pks[4]),
+        );
+        assert!(prover(&forged, dkg_params.instance()).is_err());
+    }
+
+    #[test]
+    fn test_dealer_context() {
+        let mut rng = OsRng;
+
+        let session = SessionId::new(BnScalar::random(&mut rng));
+        let dkg_config = DkgConfig::new(3, 5).unwrap().with_session(session);
+        let (pks, members) = mock_members(&dkg_config, &mut rng);
+        let context = DealerContext::new(session, 2);
+        for forged in [
+            DealerContext::new(session, 6),
+            DealerContext::new(SessionId::default(), 2),
+        ] {
+            assert!(
+                DkgMemberParams::new_with_context(dkg_config, forged, pks.clone(), &mut rng)
+                    .is_err()
+            );
+        }
+
+        let dkg_params =
+            DkgMemberParams::new_with_context(dkg_config, context, pks.clone(), &mut rng).unwrap();
+        let instance = dkg_params.instance();
+        assert_eq!(instance[0].len(), dkg_config.instance_size());
+        let circuit = dkg_params.circuit(&mut rng);
+        mock_prover_verify(&circuit, instance.clone());
+
+        // the same dealing claimed under another dealer index is rejected
+        let k = DimensionMeasurement::measure(&circuit).unwrap().k();
+        let mut pp = dkg_params.public_params.clone();
+        pp.context = DealerContext::new(session, 3);
+        let prover = MockProver::run(k, &circuit, pp.instance(&pks)).unwrap();
+        assert!(prover.verify().is_err());
+        // so is the dealing replayed in another session
+        pp.context = DealerContext::new(SessionId::new(BnScalar::one()), 2);
+        let prover = MockProver::run(k, &circuit, pp.instance(&pks)).unwrap();
+        assert!(prover.verify().is_err());
+
+        // members decrypt under the context and reject a tampered tag
+        let pps = [&dkg_params.public_params];
+        let key = members[1].dkg_share_key(&dkg_config, 2, &pps).unwrap();
+        assert_eq!(key.secret_key(), dkg_params.shares[1]);
+        assert_eq!(key.session(), session);
+        let unbound = DkgConfig::new(3, 5).unwrap();
+        assert!(members[1].dkg_share_key(&unbound, 2, &pps).is_err());
+        pp.context = context;
+        pp.tags[1] += BnScalar::one();
+        assert!(members[1].dkg_share_key(&dkg_config, 2, &[&pp]).is_err());
+        assert!(members[0].dkg_share_key(&dkg_config, 1, &[&pp]).is_ok());
+
+        // a dealing counted twice, or without a dealer index, is rejected
+        let twice = [&dkg_params.public_params, &dkg_params.public_params];
+        assert!(members[1].dkg_share_key(&dkg_config, 2, &twice).is_err());
+        pp.tags[1] -= BnScalar::one();
+        pp.context = DealerContext::new(session, 0);
+        assert!(members[1].dkg_share_key(&dkg_config, 2, &[&pp]).is_err());
+        assert!(DkgMemberParams::new(dkg_config, 0, pks.clone(), &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_decryption_proof() {
+        let mut rng = OsRng;
+
+        let dkg_config = DkgConfig::new(3, 5).unwrap();
+        let (pks, members) = mock_members(&dkg_config, &mut rng);
+        let context = DealerContext::new(dkg_config.session(), 4);
+        let dkg_params =
+            DkgMemberParams::new_with_context(dkg_config, context, pks.clone(), &mut rng).unwrap();
+        let pp = dkg_params.member_public_params();
+
+        // an honest cipher decrypts to the public share
+        let (index, k) = (2, 1);
+        let proof = members[k].prove_decryption(&pp.gr, &pp.ciphers[k], &mut rng);
+        assert_eq!(
+            pp.verify_share(index, &pks[k], &proof).unwrap(),
+            dkg_params.shares[k]
+        );
+        // a proof by another member is rejected
+        assert!(matches!(
+            pp.verify_share(index, &pks[0], &proof),
+            Err(Error::VerifyFailed)
+        ));
+
+        // a cipher of another share with a valid tag blames the dealer
+        let mut forged = pp.clone();
+        let pkr = (pp.gr * members[k].secret_key()).to_affine();
+        let (cipher, tag) = context.encrypt(&pkr, index, &BnScalar::random(&mut rng));
+        forged.ciphers[k] = cipher;
+        forged.tags[k] = tag;
+        let proof = members[k].prove_decryption(&forged.gr, &cipher, &mut rng);
+        assert!(matches!(
+            forged.verify_share(index, &pks[k], &proof),
+            Err(Error::InvalidShare { index: 4 })
+        ));
+
+        // so does a cipher that does not match its tag
+        forged.tags[k] += BnScalar::one();
+        assert!(matches!(
+            forged.verify_share(index, &pks[k], &proof),
+            Err(Error::InvalidTag { index: 4 })
+        ));
+    }
+
+    #[test]
+    fn test_dealing_deterministic() {
+        let dkg_config = DkgConfig::new(4, 7).unwrap();
+        let (pks, _) = mock_members(&dkg_config, ChaCha20Rng::seed_from_u64(7));
+
+        let deal = || {
+            let rng = ChaCha20Rng::seed_from_u64(42);
+            DkgMemberParams::new(dkg_config, 1, pks.clone(), rng).unwrap()
+        };
+        let (a, b) = (deal(), deal());
+        assert_eq!(a.public_params.public_shares, b.public_params.public_shares);
+        assert_eq!(a.public_params.ciphers, b.public_params.ciphers);
+        assert_eq!(a.instance(), b.instance());
+
+        // the same dealing computed sequentially, drawing randomness in the same order
+        let mut rng = ChaCha20Rng::seed_from_u64(42);
+        let coeffs: Vec<_> = (0..dkg_config.threshold())
+            .map(|_| BnScalar::random(&mut rng))
+            .collect();
+        let r = BnScalar::random(&mut rng);
+        let rs = GkScalar::from_repr(r.to_repr()).unwrap();
+
+        let g = BnG1::generator();
+        let shares = shares(dkg_config.number_of_members(), &coeffs);
+        let context = DealerContext::new(dkg_config.session(), 0);
+        let mut public_shares = vec![];
+        let mut ciphers = vec![];
+        let mut tags = vec![];
+        for (i, (pk, s)) in pks.iter().zip(shares.iter()).enumerate() {
+            public_shares.push((g * s).to_affine());
+            let (cipher, tag) = context.encrypt(&(pk * rs).to_affine(), i + 1, s);
+            ciphers.push(cipher);
+            tags.push(tag);
+        }
+
+        assert_eq!(a.public_params.ga, (g * coeffs[0]).to_affine());
+        assert_eq!(a.public_params.gr, (GkG1::generator() * rs).to_affine());
+        assert_eq!(a.public_params.public_shares, public_shares);
+        assert_eq!(a.public_params.ciphers, ciphers);
+        assert_eq!(a.public_params.tags, tags);
+        #[cfg(feature = "g2shares")]
+        {
+            let g2 = BnG2::generator();
+            let public_shares2: Vec<_> = shares.iter().map(|s| (g2 * s).to_affine()).collect();
+            assert_eq!(a.public_params.public_shares2, public_shares2);
+        }
+    }
+
     #[test]
     #[ignore]
     fn test_pk_vk() {
@@ -442,7 +1100,7 @@ mod tests {
 
         let dkg_config = DkgConfig::new(threshold, number_of_members).unwrap();
         let (pks, _) = mock_members(&dkg_config, &mut rng);
-        let dkg_params = DkgMemberParams::new(dkg_config, pks, &mut rng).unwrap();
+        let dkg_params = DkgMemberParams::new(dkg_config, 1, pks, &mut rng).unwrap();
         let circuit1 = dkg_params.circuit(&mut rng);
         let instance1 = dkg_params.instance();
         mock_prover_verify(&circuit1, instance1);
@@ -472,13 +1130,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_without_witnesses() {
+        let mut rng = OsRng;
+
+        for dkg_config in [
+            DkgConfig::new(3, 5).unwrap(),
+            DkgConfig::new_padded(2, 3, 3, 5).unwrap(),
+        ] {
+            // the smallest degree keeps the key generation cheap enough to run by default
+            let degree = dkg_config.min_degree().unwrap();
+            let general_params = ParamsKZG::<Bn256>::setup(degree, &mut rng);
+
+            let (pks, _) = mock_members(&dkg_config, &mut rng);
+            let dkg_params = DkgMemberParams::new(dkg_config, 1, pks, &mut rng).unwrap();
+            let circuit = dkg_params.circuit(&mut rng);
+            let blank = circuit.without_witnesses();
+            #[cfg(feature = "circuit-params")]
+            assert_eq!(blank.params(), (dkg_config, DkgCircuitParams::default()));
+
+            let vk1 = keygen_vk(&general_params, &circuit).expect("keygen_vk should not fail");
+            let vk2 = keygen_vk(&general_params, &blank).expect("keygen_vk should not fail");
+            assert_eq!(
+                vk1.to_bytes(SerdeFormat::RawBytes),
+                vk2.to_bytes(SerdeFormat::RawBytes)
+            );
+        }
+    }
+
     fn dkg_proof(threshold: usize, number_of_members: usize, degree: usize) {
         // let mut rng = ChaCha20Rng::seed_from_u64(42);
         let mut rng = OsRng;
 
         let dkg_config = DkgConfig::new(threshold, number_of_members).unwrap();
         let (mpks, _) = mock_members(&dkg_config, &mut rng);
-        let dkg_params = DkgMemberParams::new(dkg_config, mpks, &mut rng).unwrap();
+        let dkg_params = DkgMemberParams::new(dkg_config, 1, mpks, &mut rng).unwrap();
         let circuit = dkg_params.circuit(&mut rng);
         let instance = dkg_params.instance();
         let instance_ref = instance.iter().map(|i| i.as_slice()).collect::<Vec<_>>();
@@ -586,7 +1272,7 @@ mod tests {
 
         let dkg_config = DkgConfig::new(threshold, number_of_members).unwrap();
         let (mpks, _) = mock_members(&dkg_config, &mut rng);
-        let dkg_params = DkgMemberParams::new(dkg_config, mpks, &mut rng).unwrap();
+        let dkg_params = DkgMemberParams::new(dkg_config, 1, mpks, &mut rng).unwrap();
         let circuit = dkg_params.circuit(&mut rng);
         let instance = dkg_params.instance();
         let instance_ref = instance.iter().map(|i| i.as_slice()).collect::<Vec<_>>();
@@ -600,7 +1286,14 @@ mod tests {
         let verifier_params: ParamsVerifierKZG<Bn256> = general_params.verifier_params().clone();
         end_timer!(start1);
 
-        let pk = load_or_create_pk(dkg_config, params_dir, &general_params, degree).unwrap();
+        let pk = load_or_create_pk(
+            dkg_config,
+            DkgCircuitParams::default(),
+            params_dir,
+            &general_params,
+            degree,
+        )
+        .unwrap();
 
         // Create a proof
         let mut transcript = Blake2bWrite::<_, BnG1, Challenge255<_>>::init(vec![]);
@@ -632,7 +1325,14 @@ mod tests {
         let start3 = start_timer!(|| format!("verify snark proof for dkg"));
         let mut verifier_transcript = Blake2bRead::<_, BnG1, Challenge255<_>>::init(&proof[..]);
         let strategy = SingleStrategy::new(&general_params);
-        let vk = load_or_create_vk(dkg_config, params_dir, &general_params, degree).unwrap();
+        let vk = load_or_create_vk(
+            dkg_config,
+            DkgCircuitParams::default(),
+            params_dir,
+            &general_params,
+            degree,
+        )
+        .unwrap();
 
         verify_proof::<
             KZGCommitmentScheme<Bn256>,
@@ -658,7 +1358,7 @@ mod tests {
         let dkg_config = DkgConfig::new(threshold, number_of_members).unwrap();
         let (pks, members) = mock_members(&dkg_config, &mut rng);
         let dkgs: Vec<_> = (0..number_of_members)
-            .map(|_| DkgMemberParams::new(dkg_config, pks.clone(), &mut rng).unwrap())
+            .map(|i| DkgMemberParams::new(dkg_config, i + 1, pks.clone(), &mut rng).unwrap())
             .collect();
 
         let dkgs_pub: Vec<_> = dkgs.iter().map(|dkg| dkg.member_public_params()).collect();
@@ -690,6 +1390,11 @@ mod tests {
 
         // each member performs partial evaluation
         let input = b"first random";
+
+        // encrypt to the round before the pseudorandom for input is generated
+        let message = b"opened only after the first random is revealed";
+        let sealed = timelock::encrypt(input, &pp.g2a, message, &mut rng);
+
         let mut sigmas = vec![];
         for i in 0..number_of_members {
             let sigma = shares[i].evaluate(input, &mut rng);
@@ -702,6 +1407,26 @@ mod tests {
         // combine partial evaluations to obtain final random
         let v = combine_partial_evaluations(&dkg_config, &sigmas[0..threshold]).unwrap();
         v.verify(input, &pp.g2a).unwrap();
+
+        // the final pseudorandom proof opens the time-locked message
+        let opened = timelock::decrypt(v.proof(), &sealed).unwrap();
+        assert_eq!(opened, message.to_vec());
+
+        // partial evaluations in G2 verified against the G2 public shares and ga
+        #[cfg(feature = "g2shares")]
+        {
+            let mut sigmas2 = vec![];
+            for i in 0..number_of_members {
+                let sigma = shares[i].evaluate_g2(input, &mut rng);
+                sigma
+                    .verify(&dkg_config, input, &pp.verify_keys2[i])
+                    .unwrap();
+                sigmas2.push(sigma);
+            }
+
+            let v2 = combine_partial_evaluations_g2(&dkg_config, &sigmas2[0..threshold]).unwrap();
+            v2.verify(input, &pp.ga).unwrap();
+        }
     }
 
     #[test]
@@ -0,0 +1,182 @@
+use crate::dkg::{
+    combine_values, dleq_prove, dleq_verify, verify_pairing, DkgConfig, DkgShareKey,
+    PartialEvalProof, EVAL_PREFIX,
+};
+use crate::error::Error;
+use crate::utils::hash_to_curve_bn;
+use halo2wrong::curves::bn256::{G1Affine as BnG1, G2Affine as BnG2};
+use halo2wrong::curves::group::Curve;
+use rand_core::RngCore;
+
+// threshold bls signatures on arbitrary messages with the dkg secret shares
+#[derive(Debug, Clone)]
+pub struct ThresholdSigner {
+    dkg_config: DkgConfig,
+    dst: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartialSignature {
+    pub index: usize,
+    pub value: BnG1,
+    pub proof: PartialEvalProof,
+}
+
+#[derive(Debug, Clone)]
+pub struct Signature {
+    pub value: BnG1,
+}
+
+impl ThresholdSigner {
+    // the domain separation tag must differ from the one used for pseudorandom generation
+    pub fn new(dkg_config: DkgConfig, dst: &str) -> Result<Self, Error> {
+        if dst.is_empty() || dst == EVAL_PREFIX {
+            return Err(Error::InvalidDomain {
+                dst: dst.to_string(),
+            });
+        }
+
+        Ok(ThresholdSigner {
+            dkg_config,
+            dst: dst.to_string(),
+        })
+    }
+
+    pub fn dst(&self) -> &str {
+        &self.dst
+    }
+
+    // hash in the domain of the tag and the session, so a signature does not carry over to another session
+    fn hash(&self, message: &[u8]) -> BnG1 {
+        let domain = self.dkg_config.session().domain(&self.dst);
+        let hasher = hash_to_curve_bn(&domain);
+        hasher(message).to_affine()
+    }
+
+    // compute H(m)^sk with a proof that it is consistent with the member's verification key
+    pub fn sign(&self, key: &DkgShareKey, message: &[u8], rng: impl RngCore) -> PartialSignature {
+        let h = self.hash(message);
+        let session = self.dkg_config.session();
+        let (value, proof) = dleq_prove(&h, &key.secret_key(), &key.verify_key(), &session, rng);
+
+        PartialSignature {
+            index: key.index(),
+            value,
+            proof,
+        }
+    }
+
+    pub fn verify_partial(
+        &self,
+        partial: &PartialSignature,
+        message: &[u8],
+        vk: &BnG1,
+    ) -> Result<(), Error> {
+        if partial.index > self.dkg_config.number_of_members() || partial.index < 1 {
+            return Err(Error::InvalidIndex {
+                index: partial.index,
+            });
+        };
+
+        let h = self.hash(message);
+        dleq_verify(
+            &h,
+            vk,
+            &partial.value,
+            &partial.proof,
+            &self.dkg_config.session(),
+        )
+    }
+
+    // combine t partial signatures sorted by index
+    pub fn combine(&self, partials: &[PartialSignature]) -> Result<Signature, Error> {
+        assert_eq!(partials.len(), self.dkg_config.threshold());
+
+        let indices: Vec<_> = partials.iter().map(|p| p.index).collect();
+        let values: Vec<_> = partials.iter().map(|p| p.value).collect();
+        let value = combine_values(&self.dkg_config, &indices, &values)?;
+
+        Ok(Signature { value })
+    }
+
+    // verify against the global public key g2a
+    pub fn verify(&self, signature: &Signature, message: &[u8], gpk: &BnG2) -> Result<(), Error> {
+        let h = self.hash(message);
+        verify_pairing(&h, &signature.value, gpk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dkg::{shares, SessionId};
+    use halo2wrong::curves::bn256::Fr as BnScalar;
+    use halo2wrong::halo2::arithmetic::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_threshold_signature() {
+        let mut rng = OsRng;
+
+        let g = BnG1::generator();
+        let g2 = BnG2::generator();
+
+        let dkg_config = DkgConfig::new(4, 6).unwrap();
+        let coeffs: Vec<_> = (0..dkg_config.threshold())
+            .map(|_| BnScalar::random(&mut rng))
+            .collect();
+        let shares = shares(dkg_config.number_of_members(), &coeffs);
+        let keys: Vec<_> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, s)| DkgShareKey::new(i + 1, *s, (g * s).to_affine()))
+            .collect();
+        let gpk = (g2 * coeffs[0]).to_affine();
+
+        assert!(ThresholdSigner::new(dkg_config, EVAL_PREFIX).is_err());
+
+        let signer = ThresholdSigner::new(dkg_config, "bridge checkpoint 2024").unwrap();
+        let message = b"checkpoint 1024";
+
+        let partials: Vec<_> = keys
+            .iter()
+            .map(|key| signer.sign(key, message, &mut rng))
+            .collect();
+        for (p, key) in partials.iter().zip(keys.iter()) {
+            signer
+                .verify_partial(p, message, &key.verify_key())
+                .unwrap();
+        }
+
+        let signature = signer
+            .combine(&partials[2..2 + dkg_config.threshold()])
+            .unwrap();
+        signer.verify(&signature, message, &gpk).unwrap();
+        assert!(signer.verify(&signature, b"checkpoint 1025", &gpk).is_err());
+
+        // a signature under another tag does not verify
+        let other = ThresholdSigner::new(dkg_config, "bridge checkpoint 2025").unwrap();
+        assert!(other.verify(&signature, message, &gpk).is_err());
+
+        // nor does a signature from another session
+        let session = SessionId::new(BnScalar::random(&mut rng));
+        let bound_config = dkg_config.with_session(session);
+        let bound = ThresholdSigner::new(bound_config, "bridge checkpoint 2024").unwrap();
+        assert!(bound.verify(&signature, message, &gpk).is_err());
+        let bound_keys: Vec<_> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, s)| DkgShareKey::new(i + 1, *s, (g * s).to_affine()).with_session(session))
+            .collect();
+        let partials: Vec<_> = bound_keys
+            .iter()
+            .map(|key| bound.sign(key, message, &mut rng))
+            .collect();
+        assert!(signer
+            .verify_partial(&partials[0], message, &bound_keys[0].verify_key())
+            .is_err());
+        let signature = bound.combine(&partials[0..dkg_config.threshold()]).unwrap();
+        bound.verify(&signature, message, &gpk).unwrap();
+        assert!(signer.verify(&signature, message, &gpk).is_err());
+    }
+}
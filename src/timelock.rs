@@ -0,0 +1,218 @@
+use crate::dkg::SessionId;
+use crate::error::Error;
+use halo2wrong::curves::bn256::{
+    pairing, Fq12, Fr as BnScalar, G1Affine as BnG1, G2Affine as BnG2, Gt,
+};
+use halo2wrong::curves::group::Curve;
+use halo2wrong::halo2::arithmetic::Field;
+use rand_core::RngCore;
+use sha3::{Digest, Keccak256};
+use subtle::ConstantTimeEq;
+
+const KEY_PREFIX: &[u8] = b"DVRF timelock key";
+const STREAM_PREFIX: &[u8] = b"DVRF timelock stream";
+const TAG_PREFIX: &[u8] = b"DVRF timelock tag";
+
+// ciphertext that can only be opened with the pseudorandom proof H(x)^a for the round input x
+#[derive(Debug, Clone)]
+pub struct TimelockCiphertext {
+    pub u: BnG2,
+    pub ciphertext: Vec<u8>,
+    pub tag: Vec<u8>,
+}
+
+// halo2curves 0.6 declares `pub struct Gt(pub(crate) Fq12)` and offers no accessor or
+// serialization for the target group, so read the Fq12 through its layout. transmute
+// refuses to compile if the sizes ever differ, and test_gt_to_fq12 checks that the
+// result is the group homomorphism into Fq12 that halo2curves computes with
+fn gt_to_fq12(t: &Gt) -> Fq12 {
+    // SAFETY: Gt is a single field wrapper around Fq12 in the version pinned by Cargo.lock
+    unsafe { std::mem::transmute::<Gt, Fq12>(*t) }
+}
+
+// canonical little endian bytes of the twelve Fq coefficients of an Fq12 element
+// in the order c0.c0.c0, c0.c0.c1, c0.c1.c0, ..., c1.c2.c1
+fn fq12_to_bytes(f: &Fq12) -> Vec<u8> {
+    [f.c0, f.c1]
+        .iter()
+        .flat_map(|c| [c.c0, c.c1, c.c2])
+        .flat_map(|c| [c.c0, c.c1])
+        .flat_map(|c| c.to_bytes())
+        .collect()
+}
+
+fn fq12_key(f: &Fq12) -> Vec<u8> {
+    Keccak256::new()
+        .chain_update(KEY_PREFIX)
+        .chain_update(fq12_to_bytes(f))
+        .finalize()
+        .to_vec()
+}
+
+// derive a symmetric key from e(H(x), g2)^(ar) by hashing the canonical bytes of its coefficients
+fn derive_key(t: &Gt) -> Vec<u8> {
+    fq12_key(&gt_to_fq12(t))
+}
+
+// keccak in counter mode
+fn apply_keystream(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    for (counter, chunk) in data.chunks(32).enumerate() {
+        let stream = Keccak256::new()
+            .chain_update(STREAM_PREFIX)
+            .chain_update(key)
+            .chain_update((counter as u64).to_be_bytes())
+            .finalize();
+        out.extend(chunk.iter().zip(stream.iter()).map(|(a, b)| a ^ b));
+    }
+
+    out
+}
+
+fn compute_tag(key: &[u8], u: &BnG2, ciphertext: &[u8]) -> Vec<u8> {
+    Keccak256::new()
+        .chain_update(TAG_PREFIX)
+        .chain_update(key)
+        .chain_update(u.x.c0.to_bytes())
+        .chain_update(u.x.c1.to_bytes())
+        .chain_update(u.y.c0.to_bytes())
+        .chain_update(u.y.c1.to_bytes())
+        .chain_update(ciphertext)
+        .finalize()
+        .to_vec()
+}
+
+// encrypt a message to the round with input x under the global public key gpk = g2^a
+pub fn encrypt(input: &[u8], gpk: &BnG2, message: &[u8], rng: impl RngCore) -> TimelockCiphertext {
+    encrypt_in_session(&SessionId::default(), input, gpk, message, rng)
+}
+
+// encrypt to the round with input x of a dkg session
+pub fn encrypt_in_session(
+    session: &SessionId,
+    input: &[u8],
+    gpk: &BnG2,
+    message: &[u8],
+    mut rng: impl RngCore,
+) -> TimelockCiphertext {
+    let hasher = session.hasher();
+    let h: BnG1 = hasher(input).to_affine();
+
+    let g2 = BnG2::generator();
+    let r = BnScalar::random(&mut rng);
+    let u = (g2 * r).to_affine();
+
+    // e(H(x), gpk^r) = e(H(x), g2)^(ar)
+    let gpkr = (gpk * r).to_affine();
+    let key = derive_key(&pairing(&h, &gpkr));
+
+    let ciphertext = apply_keystream(&key, message);
+    let tag = compute_tag(&key, &u, &ciphertext);
+
+    TimelockCiphertext { u, ciphertext, tag }
+}
+
+// decrypt using the combined pseudorandom proof H(x)^a of the round
+pub fn decrypt(proof: &BnG1, ciphertext: &TimelockCiphertext) -> Result<Vec<u8>, Error> {
+    // e(H(x)^a, g2^r) = e(H(x), g2)^(ar)
+    let key = derive_key(&pairing(proof, &ciphertext.u));
+
+    let tag = compute_tag(&key, &ciphertext.u, &ciphertext.ciphertext);
+    if !bool::from(tag.ct_eq(&ciphertext.tag)) {
+        return Err(Error::VerifyFailed);
+    }
+
+    Ok(apply_keystream(&key, &ciphertext.ciphertext))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dkg::{keygen, EVAL_PREFIX};
+    use crate::utils::hash_to_curve_bn;
+    use halo2wrong::curves::bn256::{Fq as BnBase, Fq2, Fq6};
+    use halo2wrong::curves::group::Group;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_timelock() {
+        let mut rng = OsRng;
+
+        let (a, _) = keygen(&mut rng);
+        let g2 = BnG2::generator();
+        let gpk = (g2 * a).to_affine();
+
+        let input = b"round 20231120";
+        let message = b"sealed until the committee reveals round 20231120";
+        let ct = encrypt(input, &gpk, message, &mut rng);
+
+        let hasher = hash_to_curve_bn(EVAL_PREFIX);
+        let h: BnG1 = hasher(input).to_affine();
+        let proof = (h * a).to_affine();
+        let plaintext = decrypt(&proof, &ct).unwrap();
+        assert_eq!(plaintext, message.to_vec());
+
+        // proof for another round cannot open the ciphertext
+        let h: BnG1 = hasher(b"round 20231121").to_affine();
+        let proof = (h * a).to_affine();
+        assert!(decrypt(&proof, &ct).is_err());
+    }
+
+    #[test]
+    fn test_gt_to_fq12() {
+        let mut rng = OsRng;
+
+        assert_eq!(gt_to_fq12(&Gt::identity()), Fq12::ONE);
+
+        let a = BnScalar::random(&mut rng);
+        let b = BnScalar::random(&mut rng);
+        let h = (BnG1::generator() * a).to_affine();
+        let t1 = pairing(&h, &BnG2::generator());
+        let t2 = pairing(&h, &(BnG2::generator() * b).to_affine());
+
+        // the accessor turns the group law of Gt into multiplication in Fq12
+        assert_eq!(gt_to_fq12(&(t1 + t2)), gt_to_fq12(&t1) * gt_to_fq12(&t2));
+        assert_eq!(gt_to_fq12(&t1.double()), gt_to_fq12(&t1).square());
+        assert_eq!(
+            gt_to_fq12(&(t1 * BnScalar::from(5))),
+            gt_to_fq12(&t1).pow_vartime([5u64])
+        );
+        assert_eq!(
+            gt_to_fq12(&(t1 * -BnScalar::one())) * gt_to_fq12(&t1),
+            Fq12::ONE
+        );
+        assert_ne!(gt_to_fq12(&t1), Fq12::ONE);
+
+        // the key changes with the element
+        assert_ne!(derive_key(&t1), derive_key(&t2));
+    }
+
+    #[test]
+    fn test_derive_key_vectors() {
+        // keccak256("DVRF timelock key" || 1 || 0 || ... || 0) with 32 byte little endian coefficients
+        assert_eq!(
+            hex::encode(derive_key(&Gt::identity())),
+            "a6c30fb523a2b02ecf89bd15d9543c8cd63eeaa1e88946be9873fa2e4c78de8b"
+        );
+
+        // coefficients 1, 2, ..., 12 pin the order c0.c0.c0, c0.c0.c1, c0.c1.c0, ..., c1.c2.c1
+        let mut coeffs = (1..=12u64).map(BnBase::from);
+        let mut fq2 = || Fq2 {
+            c0: coeffs.next().unwrap(),
+            c1: coeffs.next().unwrap(),
+        };
+        let mut fq6 = || Fq6 {
+            c0: fq2(),
+            c1: fq2(),
+            c2: fq2(),
+        };
+        let f = Fq12 {
+            c0: fq6(),
+            c1: fq6(),
+        };
+        assert_eq!(
+            hex::encode(fq12_key(&f)),
+            "3bdab076e5b3f15d448de791a70e8e15515a7e799979f0d3c3bc1534b078de08"
+        );
+    }
+}
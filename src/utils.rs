@@ -1,7 +1,7 @@
 use crate::dkg::DkgConfig;
 use crate::{
-    DkgCircuit, BIT_LEN_LIMB, COORD_LEN, NUMBER_OF_LIMBS, NUMBER_OF_LOOKUP_LIMBS, POINT_LEN,
-    WRAP_LEN,
+    DkgCircuit, DkgCircuitParams, BIT_LEN_LIMB, COORD_LEN, NUMBER_OF_LIMBS, NUMBER_OF_LOOKUP_LIMBS,
+    POINT_LEN, WRAP_LEN,
 };
 use anyhow::Result;
 use ark_std::{end_timer, start_timer};
@@ -23,10 +23,13 @@ use std::rc::Rc;
 
 #[cfg(feature = "g2chip")]
 use crate::ecc_chip::{Point2, SplitBase};
-use crate::hash_to_curve_evm::hash_to_curve_evm;
+use crate::hash_to_curve_evm::{
+    hash_to_curve_evm, hash_to_curve_evm_ct, hash_to_curve_evm_g2, hash_to_curve_evm_grumpkin,
+};
 
 pub(crate) const DEFAULT_SERDE_FORMAT: SerdeFormat = SerdeFormat::RawBytesUnchecked;
-pub(crate) const MAX_DEGREE: usize = 22;
+// degree of the downloaded kzg params; smaller params are derived from them
+pub const MAX_DEGREE: usize = 22;
 
 pub fn mod_n<C: CurveAffine>(x: C::Base) -> C::Scalar {
     let x_big = fe_to_big(x);
@@ -81,26 +84,25 @@ pub fn point_to_public<W: PrimeField, N: PrimeField>(
 
 pub fn public_to_point<W: PrimeField, N: PrimeField<Repr = W::Repr>, C: CurveAffine<Base = W>>(
     public: &[N],
-) -> C {
+) -> Option<C> {
     assert_eq!(public.len(), POINT_LEN);
 
     let two = W::from(2);
     let base = two.pow([(BIT_LEN_LIMB * WRAP_LEN) as u64]);
 
-    let mut x = W::from_repr(public[COORD_LEN - 1].to_repr()).unwrap();
+    let mut x = Option::<W>::from(W::from_repr(public[COORD_LEN - 1].to_repr()))?;
     for i in (0..COORD_LEN - 1).rev() {
-        let v = W::from_repr(public[i].to_repr()).unwrap();
+        let v = Option::<W>::from(W::from_repr(public[i].to_repr()))?;
         x = x * base + v;
     }
 
-    let mut y = W::from_repr(public[POINT_LEN - 1].to_repr()).unwrap();
+    let mut y = Option::<W>::from(W::from_repr(public[POINT_LEN - 1].to_repr()))?;
     for i in (COORD_LEN..POINT_LEN - 1).rev() {
-        let v = W::from_repr(public[i].to_repr()).unwrap();
+        let v = Option::<W>::from(W::from_repr(public[i].to_repr()))?;
         y = y * base + v;
     }
 
-    let point = C::from_xy(x, y).unwrap();
-    point
+    Option::from(C::from_xy(x, y))
 }
 
 #[cfg(feature = "g2chip")]
@@ -141,7 +143,7 @@ pub fn public_to_point2<
     C: CurveAffine + SplitBase<C::Base, W>,
 >(
     public: &[N],
-) -> C {
+) -> Option<C> {
     assert_eq!(public.len(), POINT_LEN * 2);
 
     let two = W::from(2);
@@ -152,9 +154,9 @@ pub fn public_to_point2<
         let begin = i * COORD_LEN;
         let end = begin + COORD_LEN - 1;
 
-        let mut c = W::from_repr(public[end].to_repr()).unwrap();
+        let mut c = Option::<W>::from(W::from_repr(public[end].to_repr()))?;
         for i in (begin..end).rev() {
-            let v = W::from_repr(public[i].to_repr()).unwrap();
+            let v = Option::<W>::from(W::from_repr(public[i].to_repr()))?;
             c = c * base + v;
         }
 
@@ -164,20 +166,44 @@ pub fn public_to_point2<
     let x = <C as SplitBase<C::Base, W>>::from(coords[0], coords[1]);
     let y = <C as SplitBase<C::Base, W>>::from(coords[2], coords[3]);
 
-    C::from_xy(x, y).unwrap()
+    Option::from(C::from_xy(x, y))
 }
 
 pub fn hash_to_curve_bn<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> bn256::G1 + 'a> {
     hash_to_curve_evm(domain_prefix)
 }
 
+// hash_to_curve_bn with the same output and a constant time map to curve for each field element;
+// the hash to field and the final point addition are not constant time
+pub fn hash_to_curve_bn_ct<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> bn256::G1 + 'a> {
+    hash_to_curve_evm_ct(domain_prefix)
+}
+
+// hash to G2 with keccak expansion and Budroni-Pintore cofactor clearing; there is no solidity version
+pub fn hash_to_curve_bn_g2<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> bn256::G2 + 'a> {
+    hash_to_curve_evm_g2(domain_prefix)
+}
+
 pub fn hash_to_curve_grumpkin<'a>(
     domain_prefix: &'a str,
 ) -> Box<dyn Fn(&[u8]) -> grumpkin::G1 + 'a> {
     grumpkin::G1::hash_to_curve(domain_prefix)
 }
 
+// keccak based hash to grumpkin that matches Grumpkin.hashToCurve in solidity
+pub fn hash_to_curve_grumpkin_evm<'a>(
+    domain_prefix: &'a str,
+) -> Box<dyn Fn(&[u8]) -> grumpkin::G1 + 'a> {
+    hash_to_curve_evm_grumpkin(domain_prefix)
+}
+
 pub fn load_or_create_params(params_dir: &str, degree: usize) -> Result<ParamsKZG<Bn256>> {
+    if degree > MAX_DEGREE {
+        return Err(anyhow::format_err!(
+            "degree {degree} is above the maximum degree {MAX_DEGREE} of the downloaded params"
+        ));
+    }
+
     // read params
     let params_path = format!("{params_dir}/params{degree}");
     log::info!("load params from {}", params_path);
@@ -245,60 +271,107 @@ pub fn load_params(
     Ok(p)
 }
 
+// key file name; padded keys are named after the circuit shape (t_max, n_max) only,
+// and circuit params are only added if they are not the default
+fn key_path(
+    params_dir: &str,
+    key: &str,
+    dkg_config: &DkgConfig,
+    circuit_params: &DkgCircuitParams,
+    degree: usize,
+) -> String {
+    let threshold = dkg_config.max_threshold();
+    let number_of_members = dkg_config.max_number_of_members();
+    let mode = if cfg!(feature = "g2shares") {
+        "-g2s"
+    } else if cfg!(feature = "g2chip") {
+        "-g2"
+    } else {
+        ""
+    };
+    let padded = if dkg_config.is_padded() {
+        "-padded"
+    } else {
+        ""
+    };
+    let default_params = DkgCircuitParams::default();
+    let window = if circuit_params.window_size == default_params.window_size {
+        String::new()
+    } else {
+        format!("-w{}", circuit_params.window_size)
+    };
+    let grumpkin_window =
+        if circuit_params.grumpkin_window_size == default_params.grumpkin_window_size {
+            String::new()
+        } else {
+            format!("-gw{}", circuit_params.grumpkin_window_size)
+        };
+    let canonical = if circuit_params.canonical_random {
+        "-c"
+    } else {
+        ""
+    };
+
+    format!(
+        "{params_dir}/{key}{mode}{padded}-{threshold}-{number_of_members}-{degree}{window}{grumpkin_window}{canonical}"
+    )
+}
+
 pub fn load_pk(
     dkg_config: &DkgConfig,
+    circuit_params: &DkgCircuitParams,
     params_dir: &str,
     degree: usize,
     serde_format: SerdeFormat,
 ) -> Result<ProvingKey<bn256::G1Affine>> {
     log::info!("start loading pk with degree {}", degree);
     let pk_path = if metadata(params_dir)?.is_dir() {
-        let threshold = dkg_config.threshold();
-        let number_of_members = dkg_config.number_of_members();
         // auto load
-        if cfg!(feature = "g2chip") {
-            format!("{params_dir}/pk-g2-{threshold}-{number_of_members}-{degree}")
-        } else {
-            format!("{params_dir}/pk-{threshold}-{number_of_members}-{degree}")
-        }
+        key_path(params_dir, "pk", dkg_config, circuit_params, degree)
     } else {
         params_dir.to_string()
     };
     let f = File::open(pk_path)?;
 
-    let pk = ProvingKey::read::<_, DkgCircuit>(&mut BufReader::new(f), serde_format)?;
+    let pk = ProvingKey::read::<_, DkgCircuit>(
+        &mut BufReader::new(f),
+        serde_format,
+        #[cfg(feature = "circuit-params")]
+        (*dkg_config, *circuit_params),
+    )?;
     log::info!("load pk successfully!");
     Ok(pk)
 }
 
 pub fn load_vk(
     dkg_config: &DkgConfig,
+    circuit_params: &DkgCircuitParams,
     params_dir: &str,
     degree: usize,
     serde_format: SerdeFormat,
 ) -> Result<VerifyingKey<bn256::G1Affine>> {
     log::info!("start loading vk with degree {}", degree);
     let vk_path = if metadata(params_dir)?.is_dir() {
-        let threshold = dkg_config.threshold();
-        let number_of_members = dkg_config.number_of_members();
         // auto load
-        if cfg!(feature = "g2chip") {
-            format!("{params_dir}/vk-g2-{threshold}-{number_of_members}-{degree}")
-        } else {
-            format!("{params_dir}/vk-{threshold}-{number_of_members}-{degree}")
-        }
+        key_path(params_dir, "vk", dkg_config, circuit_params, degree)
     } else {
         params_dir.to_string()
     };
     let f = File::open(vk_path)?;
 
-    let vk = VerifyingKey::read::<_, DkgCircuit>(&mut BufReader::new(f), serde_format)?;
+    let vk = VerifyingKey::read::<_, DkgCircuit>(
+        &mut BufReader::new(f),
+        serde_format,
+        #[cfg(feature = "circuit-params")]
+        (*dkg_config, *circuit_params),
+    )?;
     log::info!("load vk successfully!");
     Ok(vk)
 }
 
 pub fn load_or_create_vk(
     dkg_config: DkgConfig,
+    circuit_params: DkgCircuitParams,
     params_dir: &str,
     params: &ParamsKZG<Bn256>,
     degree: usize,
@@ -310,26 +383,23 @@ pub fn load_or_create_vk(
         ));
     }
 
-    if let Ok(vk) = load_vk(&dkg_config, params_dir, degree, DEFAULT_SERDE_FORMAT) {
+    if let Ok(vk) = load_vk(
+        &dkg_config,
+        &circuit_params,
+        params_dir,
+        degree,
+        DEFAULT_SERDE_FORMAT,
+    ) {
         return Ok(vk);
     }
 
     let start = start_timer!(|| "failed to load vk; generate verifying key vk");
-    let circuit_dummy = DkgCircuit::dummy(dkg_config);
+    let circuit_dummy = DkgCircuit::dummy(dkg_config).with_circuit_params(circuit_params);
     let vk = keygen_vk(params, &circuit_dummy).expect("keygen_vk should not fail");
     end_timer!(start);
 
     let start = start_timer!(|| "store vk to file");
-    let vk_path = {
-        let threshold = dkg_config.threshold();
-        let number_of_members = dkg_config.number_of_members();
-        // auto load
-        if cfg!(feature = "g2chip") {
-            format!("{params_dir}/vk-g2-{threshold}-{number_of_members}-{degree}")
-        } else {
-            format!("{params_dir}/vk-{threshold}-{number_of_members}-{degree}")
-        }
-    };
+    let vk_path = key_path(params_dir, "vk", &dkg_config, &circuit_params, degree);
     let mut f_vk = File::create(vk_path)?;
     vk.write(&mut f_vk, DEFAULT_SERDE_FORMAT)?;
     end_timer!(start);
@@ -339,6 +409,7 @@ pub fn load_or_create_vk(
 
 pub fn load_or_create_pk(
     dkg_config: DkgConfig,
+    circuit_params: DkgCircuitParams,
     params_dir: &str,
     params: &ParamsKZG<Bn256>,
     degree: usize,
@@ -350,29 +421,26 @@ pub fn load_or_create_pk(
         ));
     }
 
-    if let Ok(pk) = load_pk(&dkg_config, params_dir, degree, DEFAULT_SERDE_FORMAT) {
+    if let Ok(pk) = load_pk(
+        &dkg_config,
+        &circuit_params,
+        params_dir,
+        degree,
+        DEFAULT_SERDE_FORMAT,
+    ) {
         return Ok(pk);
     }
 
     log::info!("failed to load pk; generate and store vk and pk");
-    let vk = load_or_create_vk(dkg_config, params_dir, params, degree)?;
-    let circuit_dummy = DkgCircuit::dummy(dkg_config);
+    let vk = load_or_create_vk(dkg_config, circuit_params, params_dir, params, degree)?;
+    let circuit_dummy = DkgCircuit::dummy(dkg_config).with_circuit_params(circuit_params);
 
     let start = start_timer!(|| "generate proving key pk");
     let pk = keygen_pk(params, vk, &circuit_dummy).expect("keygen_pk should not fail");
     end_timer!(start);
 
     let start = start_timer!(|| "store pk to file");
-    let pk_path = {
-        let threshold = dkg_config.threshold();
-        let number_of_members = dkg_config.number_of_members();
-        // auto load
-        if cfg!(feature = "g2chip") {
-            format!("{params_dir}/pk-g2-{threshold}-{number_of_members}-{degree}")
-        } else {
-            format!("{params_dir}/pk-{threshold}-{number_of_members}-{degree}")
-        }
-    };
+    let pk_path = key_path(params_dir, "pk", &dkg_config, &circuit_params, degree);
     let mut f_pk = File::create(pk_path)?;
     pk.write(&mut f_pk, DEFAULT_SERDE_FORMAT)?;
     end_timer!(start);
@@ -409,8 +477,23 @@ mod tests {
         let _verifier_params = general_params.verifier_params();
 
         let dkg_config = DkgConfig::new(3, 5).unwrap();
-        let vk = load_or_create_vk(dkg_config, "./kzg_params", &general_params, degree).unwrap();
-        let pk = load_or_create_pk(dkg_config, "./kzg_params", &general_params, degree).unwrap();
+        let circuit_params = DkgCircuitParams::default();
+        let vk = load_or_create_vk(
+            dkg_config,
+            circuit_params,
+            "./kzg_params",
+            &general_params,
+            degree,
+        )
+        .unwrap();
+        let pk = load_or_create_pk(
+            dkg_config,
+            circuit_params,
+            "./kzg_params",
+            &general_params,
+            degree,
+        )
+        .unwrap();
         assert_eq!(
             vk.to_bytes(DEFAULT_SERDE_FORMAT),
             pk.get_vk().to_bytes(DEFAULT_SERDE_FORMAT)
@@ -427,14 +510,14 @@ mod tests {
 
         let g = BnG1::random(&mut rng);
         let public = point_to_public(Rc::clone(&rns_base), g);
-        let point = public_to_point::<Fq, Fr, BnG1>(&public);
+        let point = public_to_point::<Fq, Fr, BnG1>(&public).unwrap();
         assert_eq!(g, point);
 
         #[cfg(feature = "g2chip")]
         {
             let g2 = BnG2::random(&mut rng);
             let public2 = point2_to_public(Rc::clone(&rns_base), g2);
-            let point2 = public_to_point2::<Fq, Fr, BnG2>(&public2);
+            let point2 = public_to_point2::<Fq, Fr, BnG2>(&public2).unwrap();
             assert_eq!(g2, point2);
         }
     }
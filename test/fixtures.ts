@@ -0,0 +1,31 @@
+import fs from "fs";
+import path from "path";
+
+// fixtures written by scripts/fixtures.sh from a real NIDKG run; they have to be regenerated together with
+// the verifier contracts whenever the circuit or the layout of its public inputs changes
+export interface Fixture {
+    threshold: number
+    numberOfMembers: number
+    maxThreshold?: number
+    maxNumberOfMembers?: number
+    // contract file of the verifying key the proofs were created for
+    verifyingKey: string
+    pubKeys: {x: string, y: string}[]
+    // instance and snark proof of dealer i + 1
+    publicParams: string[][]
+    proofs: string[]
+    input?: string
+    gpk?: {x: string[], y: string[]}
+    vks?: {x: string, y: string}[]
+    partialEvals?: {indexPlus: number, value: {x: string, y: string}, proof: {z: string, c: string}}[]
+    pseudoRandom?: {proof: {x: string, y: string}, value: string}
+}
+
+export function loadFixture(name: string): Fixture {
+    const file = path.join(__dirname, "fixtures", name)
+    if (!fs.existsSync(file)) {
+        throw new Error(`missing test fixture ${file}; run scripts/fixtures.sh to generate it`)
+    }
+
+    return JSON.parse(fs.readFileSync(file, "utf8"))
+}
@@ -0,0 +1,80 @@
+import chai, {expect} from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+
+chai.use(chaiAsPromised)
+import {solidity} from "ethereum-waffle";
+import {ethers} from 'hardhat'
+import {Contract, BigNumber, utils} from 'ethers'
+
+chai.use(solidity);
+
+let Grumpkin: Contract
+
+// grumpkin base field, the bn254 scalar field
+const P = BigNumber.from("21888242871839275222246405745257275088548364400416034343698204186575808495617")
+const G_X = BigNumber.from(1)
+const G_Y = BigNumber.from("17631683881184975370165255887551781615748388533673675138860")
+
+// test vectors of test_hash_grumpkin in src/hash_to_curve_evm.rs
+const hashVectors = [
+    {
+        domain: "DVRF pseudorandom generation 2023",
+        message: "hello world",
+        x: "0x28ed2615657a4d525e56135c76b5d5f9492ee16744fdb12c7d9f36673f6c3642",
+        y: "0x2f3d2b9c50f15fb2e020985b5818e32a9d66d2407155e703132be9ab395bf2a6",
+    },
+    {
+        domain: "another generator",
+        message: "",
+        x: "0x1dccb3657213352d6b75b8f5cc21a73179baa3dc11ef9d08835e1c4886964b4a",
+        y: "0x0368a122beb95b21f170e0941b0b18f9606f0f48e2b85464cc2493039139f018",
+    },
+]
+
+describe('Grumpkin', function () {
+    before(async () => {
+        const factory = await ethers.getContractFactory('GrumpkinHarness')
+        Grumpkin = await factory.deploy()
+        await Grumpkin.deployed()
+    })
+
+    it('should match the rust hash to curve vectors', async () => {
+        for (const v of hashVectors) {
+            const h = await Grumpkin.hashToCurve(utils.toUtf8Bytes(v.domain), utils.toUtf8Bytes(v.message))
+            expect(h.x).to.eq(BigNumber.from(v.x))
+            expect(h.y).to.eq(BigNumber.from(v.y))
+            expect(await Grumpkin.isOnCurve(h.x, h.y)).to.be.true
+        }
+    })
+
+    it('should map the exceptional cases onto the curve', async () => {
+        // t = 0 and t^2 = -1 - b fall back to x1
+        for (const t of [BigNumber.from(0), BigNumber.from(4), P.sub(4)]) {
+            const p = await Grumpkin.mapToCurve(t)
+            expect(await Grumpkin.isOnCurve(p.x, p.y)).to.be.true
+        }
+        await expect(Grumpkin.mapToCurve(P)).to.be.reverted
+    })
+
+    it('should add points with the identity encoded as (0, 0)', async () => {
+        expect(await Grumpkin.isOnCurve(G_X, G_Y)).to.be.true
+        expect(await Grumpkin.isOnCurve(G_X, G_Y.add(1))).to.be.false
+
+        const g = await Grumpkin.add(G_X, G_Y, 0, 0)
+        expect(g.x).to.eq(G_X)
+        expect(g.y).to.eq(G_Y)
+
+        const zero = await Grumpkin.add(G_X, G_Y, G_X, P.sub(G_Y))
+        expect(zero.x).to.eq(0)
+        expect(zero.y).to.eq(0)
+
+        // 2g + g == g + 2g
+        const g2 = await Grumpkin.add(G_X, G_Y, G_X, G_Y)
+        expect(await Grumpkin.isOnCurve(g2.x, g2.y)).to.be.true
+        const a = await Grumpkin.add(g2.x, g2.y, G_X, G_Y)
+        const b = await Grumpkin.add(G_X, G_Y, g2.x, g2.y)
+        expect(a.x).to.eq(b.x)
+        expect(a.y).to.eq(b.y)
+        expect(await Grumpkin.isOnCurve(a.x, a.y)).to.be.true
+    })
+})
@@ -0,0 +1,150 @@
+import chai, {expect} from 'chai'
+import chaiAsPromised from 'chai-as-promised'
+
+chai.use(chaiAsPromised)
+import {solidity} from "ethereum-waffle";
+import {ethers} from 'hardhat'
+import {Contract, BigNumber, utils} from 'ethers'
+
+chai.use(solidity);
+
+let Hash: Contract
+
+const SUITE_SHA256 = 1
+const SUITE_KECCAK256 = 2
+const SUITE_IDS = {
+    [SUITE_SHA256]: "BN254G1_XMD:SHA-256_SVDW_RO_",
+    [SUITE_KECCAK256]: "BN254G1_XMD:KECCAK-256_SVDW_RO_",
+}
+
+// BN254 base field
+const Q = BigNumber.from("21888242871839275222246405745257275088696311157297823662689037894645226208583")
+const Q2 = Q.sub(1).div(2)
+const DOMAIN = "DVRF pseudorandom generation 2023"
+
+// test vectors of test_hash_suite in src/hash_to_curve_rfc.rs
+const suiteVectors = [
+    {
+        suite: SUITE_SHA256,
+        message: "hello world",
+        x: "0x1c2ffe13f873ad57c15c60ebb115bad2a233a5da7708c47b5a1d3f3c1aba8766",
+        y: "0x0b730ac2570bc6a8bcbf366d4e56a0dc8897884d5cd9154644756753f7e197f5",
+    },
+    {
+        suite: SUITE_KECCAK256,
+        message: "hello world",
+        x: "0x0cc3dbd4c392eee575e0fe3a990be7d022a080838a97b966fa02a7c31bbfe256",
+        y: "0x1d84e9c05b3e12192622f61fd56e645cad6d71bdce71a336d4e60f2eaa8d10cf",
+    },
+]
+
+// rfc9380 test dst with the vectors of test_hash_to_curve_sha256 and test_hash_to_curve_keccak256
+const rfcVectors = [
+    {
+        suite: SUITE_SHA256,
+        message: "",
+        u: [
+            "0x2f87b81d9d6ef05ad4d249737498cc27e1bd485dca804487844feb3c67c1a9b5",
+            "0x06de2d0d7c0d9c7a5a6c0b74675e7543f5b98186b5dbf831067449000b2b1f8e",
+        ],
+        x: "0x0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86",
+        y: "0x02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5",
+    },
+    {
+        suite: SUITE_SHA256,
+        message: "abc",
+        u: [
+            "0x11945105b5e3d3b9392b5a2318409cbc28b7246aa47fa30da5739907737799a9",
+            "0x1255fc9ad5a6e0fb440916f091229bda611c41be2f2283c3d8f98c596be4c8c9",
+        ],
+        x: "0x23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1",
+        y: "0x04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d",
+    },
+    {
+        suite: SUITE_KECCAK256,
+        message: "",
+        x: "0x0c112533eaaa53fc814ee3ee0c23c45264bd0d24524d4af3a4abaea2fa9bc358",
+        y: "0x1418c14cf13dcb591a3ef964d0214db0936bc5efb568e1d168f6d66fadd34006",
+    },
+    {
+        suite: SUITE_KECCAK256,
+        message: "abc",
+        x: "0x1810adf4e1884db8b8ceb08ff576172d17456ce8eb4f0bffb878daa8470acb90",
+        y: "0x20926f6ce4d67301e6d5329a650da7074bb48088b363092eebd7472250635bc7",
+    },
+]
+
+function dst(domain: string, suite: number): Uint8Array {
+    return utils.toUtf8Bytes(`${domain}-with-${SUITE_IDS[suite]}`)
+}
+
+describe('Hash xmd', function () {
+    before(async () => {
+        const factory = await ethers.getContractFactory('HashHarness')
+        Hash = await factory.deploy()
+        await Hash.deployed()
+    })
+
+    it('should match the rust hash suite vectors', async () => {
+        for (const v of suiteVectors) {
+            const h = await Hash.hashToG1Xmd(v.suite, dst(DOMAIN, v.suite), utils.toUtf8Bytes(v.message))
+            expect(h.x).to.eq(BigNumber.from(v.x))
+            expect(h.y).to.eq(BigNumber.from(v.y))
+        }
+    })
+
+    it('should match the rfc9380 vectors', async () => {
+        for (const v of rfcVectors) {
+            const tag = dst("QUUX-V01-CS02", v.suite)
+            const message = utils.toUtf8Bytes(v.message)
+            if (v.u) {
+                const u = await Hash.hashToFieldXmd(v.suite, tag, message)
+                expect(u[0]).to.eq(BigNumber.from(v.u[0]))
+                expect(u[1]).to.eq(BigNumber.from(v.u[1]))
+            }
+            const h = await Hash.hashToG1Xmd(v.suite, tag, message)
+            expect(h.x).to.eq(BigNumber.from(v.x))
+            expect(h.y).to.eq(BigNumber.from(v.y))
+        }
+    })
+
+    it('should reduce the expanded message to the field elements', async () => {
+        for (const suite of [SUITE_SHA256, SUITE_KECCAK256]) {
+            const tag = dst(DOMAIN, suite)
+            const message = utils.toUtf8Bytes("hello world")
+            const b = await Hash.expandMessageXmd(suite, tag, message)
+            const bytes = utils.concat(b)
+            const u = await Hash.hashToFieldXmd(suite, tag, message)
+            // two field elements of 48 bytes each
+            expect(u[0]).to.eq(BigNumber.from(utils.hexlify(bytes.slice(0, 48))).mod(Q))
+            expect(u[1]).to.eq(BigNumber.from(utils.hexlify(bytes.slice(48, 96))).mod(Q))
+        }
+    })
+
+    it('should map the exceptional case u = 0 to x = (q-1)/2', async () => {
+        const p = await Hash.mapToG1Svdw(0)
+        expect(p.x).to.eq(Q2)
+        await expect(Hash.mapToG1Svdw(Q)).to.be.reverted
+    })
+})
+
+describe('PseudoRandXmd', function () {
+    it('should hash in the domain of its suite', async () => {
+        const factory = await ethers.getContractFactory('PseudoRandXmd')
+        for (const v of suiteVectors) {
+            const pseudoRand = await factory.deploy(v.suite)
+            await pseudoRand.deployed()
+
+            expect(await pseudoRand.dst(0)).to.eq(utils.hexlify(dst(DOMAIN, v.suite)))
+            const h = await pseudoRand.hashToG1(utils.toUtf8Bytes(v.message))
+            expect(h.x).to.eq(BigNumber.from(v.x))
+            expect(h.y).to.eq(BigNumber.from(v.y))
+        }
+    })
+
+    it('should reject an unknown suite', async () => {
+        const factory = await ethers.getContractFactory('PseudoRandXmd')
+        await expect(factory.deploy(0)).to.be.reverted
+        await expect(factory.deploy(3)).to.be.reverted
+    })
+})
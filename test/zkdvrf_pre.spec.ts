@@ -5,6 +5,7 @@ chai.use(chaiAsPromised)
 import {solidity} from "ethereum-waffle";
 import hre, {ethers} from 'hardhat'
 import {Contract, Signer, BigNumber, utils, BigNumberish, ContractFactory, providers, Wallet} from 'ethers'
+import {loadFixture} from './fixtures'
 
 chai.use(solidity);
 
@@ -40,119 +41,40 @@ let player2Address: string
 let player3Address: string
 
 
-let pubKeyAcc1 = {
-    x: "0x2f9fdedc3d0acf6943f1a68f35a3904465f9b7aab27f7b5af82cf97dce8d13e9",
-    y: "0x1aad0acb5ac8a713180487e98881e63018ef86a6a8fb98b5e6c4f673cef3f31d"
-}
-let pubKeyAcc2 = {
-    x: "0x09c42e708fe42383a32fa883fb0087d82abb47a164415f50644d9415390d4fb5",
-    y: "0x231fb26798de67fea057c9db73be5938affc38e80900f2201e4461458008515b"
-}
-let pubKeyAcc3 = {
-    x: "0x1dd80e1b2e20a72f40e1ebc8175c07337e4305da67992eb38484f03d0d3b754b",
-    y: "0x277c5851d846067608fa34bc27d4266dd3713c5288656fe9448bd02caaf24fab"
-}
-let pubKeyAcc4 = {
-    x: "0x2302a39d5a3f90865709a8c384f28bb2c638ef66348e288f58c57dd24eeb505f",
-    y: "0x1b0c9b743d7516310c8645e238c0f3bab0c56534061638d0772f0afd4560222f"
-}
-let pubKeyAcc5 = {
-    x: "0x1657872876ba3afc01e20551de678c90421a384de7ffc0556054047ffd924662",
-    y: "0x0bd2be9a919ddfddcf0562bc1caa2ecbcf1b0baef679ed186262135e961ba487"
-}
-
+// a (3, 5) NIDKG run and the pseudorandom of its first round, generated by scripts/fixtures.sh
+const fixture = loadFixture('zkdvrf-3-5.json')
 
-let ppAcc1 = ["0x000000000000000000000000000000af48ecdcdbe438b929d41ce1785e162b7d", "0x0000000000000000000000000000000000267eb459f60eaf1fe6ffc896414df4", "0x0000000000000000000000000000003bd0c88137548fa6c74856c0eadb048e8e", "0x000000000000000000000000000000000014f378832b07e0fe53d79d6459d565", "0x0000000000000000000000000000006c55768caf5a3526c8c4394ae824037d08", "0x00000000000000000000000000000000002e492b63aa3e9c957f3d9d1c6eec7e", "0x0000000000000000000000000000005f5d886ad65598ca2eca89c304d78afe7c", "0x00000000000000000000000000000000002aee5741b252dac77fa9947409bc13", "0x0000000000000000000000000000005282366d7d14a89ba6a22a0fa1f13fe40b", "0x000000000000000000000000000000000015a52c1c0849a34f2a33067e71dfa2", "0x00000000000000000000000000000075a3bbcae0a1d50e3b883913384edeb4ee", "0x000000000000000000000000000000000021e68590614d84c6c32d1a41ba3815", "0x000000000000000000000000000000c797de29d818450a2faff9fb9f6456e72c", "0x000000000000000000000000000000000018fa2c24148ac2c2a870029a7058f6", "0x000000000000000000000000000000b440d0d06d845bb673943440bd7189d3a2", "0x0000000000000000000000000000000000295741ac3744eb89f0f17f9d2ddeec", "0x0000000000000000000000000000004b963042d4debbc730bfaa24d63734ab87", "0x00000000000000000000000000000000001a0d7fd3509281006ea8de2afc76aa", "0x000000000000000000000000000000add0c4dfe2a803dc4220377344135ea81c", "0x00000000000000000000000000000000002afc429cef9de94861a182412be1f8", "0x000000000000000000000000000000958374f942d901f4e6ae8d9d3b7e49684d", "0x000000000000000000000000000000000006454a61f8ea0c8158b8fe9e168286", "0x0000000000000000000000000000002662a7151ca8a1646992ae56c104dded36", "0x00000000000000000000000000000000002fa8fbd0623a2d8aef0349e2330317", "0x00000000000000000000000000000005ffa1cd109e1e1a283a9491e640000d9c", "0x000000000000000000000000000000000025951694fecf7a7687c160aa9dfac7", "0x0000000000000000000000000000002fce9562826cf755d33fc155655af469e1", "0x00000000000000000000000000000000001dbacb50b98e45cde9b66f7dc846f4", "0x000000000000000000000000000000e086d65181fa24a32d7a961fb2d6d7be49", "0x00000000000000000000000000000000002b5862a6107a83ef84698165c59c35", "0x00000000000000000000000000000052de7115c575324c8562757be3d0521698", "0x000000000000000000000000000000000010dc065b9698a8ba43396f45edcf7e", "0x0ff8ac645f3520dd634f2c031462157d19c3fc9e9b664ea57f111cabfad20835", "0x1371dc8f052d5323f7c1327933d0cb37a85e0335642d3ce9ec682121358e99fb", "0x0f90fd54420559e535c9820161ffcb0ed76757f54db365eaf0c88d7f628f00a1", "0x1c9996512dd037b1a7cd1cfafb56db81ebc405f90591a4ffd19105a7d04ae1a7", "0x24dbd836da1e09e2fee132df82a723bd09cbfdfcc86edfcfcd1c51afb245fe4e", "0x0ddfc2cbdfa1073ae8381a30d589b9ce2c36624da42d78b04e71201437e58c92", "0x2732086e6ca83e8cd4f9e3cb03cb5cd9a134f59cd02acbc14af37e8c2a811e22", "0x2f9fdedc3d0acf6943f1a68f35a3904465f9b7aab27f7b5af82cf97dce8d13e9", "0x1aad0acb5ac8a713180487e98881e63018ef86a6a8fb98b5e6c4f673cef3f31d", "0x09c42e708fe42383a32fa883fb0087d82abb47a164415f50644d9415390d4fb5", "0x231fb26798de67fea057c9db73be5938affc38e80900f2201e4461458008515b", "0x1dd80e1b2e20a72f40e1ebc8175c07337e4305da67992eb38484f03d0d3b754b", "0x277c5851d846067608fa34bc27d4266dd3713c5288656fe9448bd02caaf24fab", "0x2302a39d5a3f90865709a8c384f28bb2c638ef66348e288f58c57dd24eeb505f", "0x1b0c9b743d7516310c8645e238c0f3bab0c56534061638d0772f0afd4560222f", "0x1657872876ba3afc01e20551de678c90421a384de7ffc0556054047ffd924662", "0x0bd2be9a919ddfddcf0562bc1caa2ecbcf1b0baef679ed186262135e961ba487"]
-let ppAcc2 = ["0x00000000000000000000000000000077878604392fe0e3370bc806486ff0499f", "0x00000000000000000000000000000000000889eecff890b1c2c6253c8c7dbdce", "0x0000000000000000000000000000002e8d98be6ebaaf3a559b1c6cc69c1357d0", "0x000000000000000000000000000000000005ae37819017d122dd8e96a6ff4f55", "0x000000000000000000000000000000c2913a76b6a1def0bdab40dd5128559d54", "0x00000000000000000000000000000000002fa45f992f4191f32b92b71444d96b", "0x000000000000000000000000000000b417fe3c7405399b144f4becff0bb8ea24", "0x00000000000000000000000000000000000679210422583c0edc6a80e69d8771", "0x000000000000000000000000000000b9443e7dd9d4ec27571620f7e098d235ae", "0x000000000000000000000000000000000012f47308709432b448f5e235ce35b8", "0x000000000000000000000000000000a249af7f62961bb3437e99c123d1bca97b", "0x000000000000000000000000000000000020c70ff4111da219e97b8d0fbdbd02", "0x000000000000000000000000000000d33c5303e589864b94488f1a5a437c3934", "0x000000000000000000000000000000000024c8664be78571760d8d07cf7b0ce4", "0x00000000000000000000000000000017fe5e4944ad83ac2e3eaa113984fa760d", "0x00000000000000000000000000000000002819fc78ae1cc0979d456212a0b7ea", "0x0000000000000000000000000000007a9a65b95e0763febbe0322f7437c41a53", "0x000000000000000000000000000000000022cde2a4fd5624729524d7e07bf995", "0x00000000000000000000000000000089a2755afe01b606f16470b0b3fe3e4eb3", "0x00000000000000000000000000000000001ca8d2f0f377bd355fb32022107838", "0x00000000000000000000000000000032192fdba15dff142602fa8ea46faf8d89", "0x000000000000000000000000000000000003b85e2d95baf03700393ecda6a81d", "0x0000000000000000000000000000009c57839e269e3ca25b184073973d707339", "0x000000000000000000000000000000000029e32d1bd97857e5e3a36bbad39d73", "0x000000000000000000000000000000c1c9ccbfb9ee93ca56ca86242aee49b4ef", "0x000000000000000000000000000000000011362d72868afbbef395afedc450f8", "0x0000000000000000000000000000006e325454781e071df98af7694c505ae1a6", "0x00000000000000000000000000000000001fe5ceb5e516027ef876705a96bbf6", "0x000000000000000000000000000000cbc4b28912653db778567ac0e2c74d4657", "0x0000000000000000000000000000000000255dd3454e00622046c28c3c2b24cd", "0x00000000000000000000000000000047412568ddf6c176831e39ee9766089bf2", "0x000000000000000000000000000000000007a084b46e347e1d944f60ba02ab54", "0x1166958a4572f53a9be697e400feb310787f28591907dadb4e75ff8d5d8429e9", "0x27994eee01b1def1812293d93426ff13755fb5a512c5b6f4e11f29bd095dc974", "0x173da235fef6bd2349feeafabee0822927b968387b4b7bf6602d58852d582714", "0x125578ed1f3d9b324068d97d032e812f2147e9d2e3becef9cb10c64c887e45ba", "0x2f6e2b1d2b7730d7a7e4209c58efd192e8fba2156d034235426dbff71a56b2be", "0x0d7510c815efabb4772433a086a57a58cf5c8e55be9f386eaaff2c8a43ee58e4", "0x25e331598f972f6b7c2775bd788c7a7daf28016fbe2c67856cc901b3712b7953", "0x2f9fdedc3d0acf6943f1a68f35a3904465f9b7aab27f7b5af82cf97dce8d13e9", "0x1aad0acb5ac8a713180487e98881e63018ef86a6a8fb98b5e6c4f673cef3f31d", "0x09c42e708fe42383a32fa883fb0087d82abb47a164415f50644d9415390d4fb5", "0x231fb26798de67fea057c9db73be5938affc38e80900f2201e4461458008515b", "0x1dd80e1b2e20a72f40e1ebc8175c07337e4305da67992eb38484f03d0d3b754b", "0x277c5851d846067608fa34bc27d4266dd3713c5288656fe9448bd02caaf24fab", "0x2302a39d5a3f90865709a8c384f28bb2c638ef66348e288f58c57dd24eeb505f", "0x1b0c9b743d7516310c8645e238c0f3bab0c56534061638d0772f0afd4560222f", "0x1657872876ba3afc01e20551de678c90421a384de7ffc0556054047ffd924662", "0x0bd2be9a919ddfddcf0562bc1caa2ecbcf1b0baef679ed186262135e961ba487"]
-let ppAcc3 = ["0x00000000000000000000000000000061ec8eb619304cd1bc0d53aa341140d11a", "0x0000000000000000000000000000000000038d1069e678fe54a97731c8fec1e9", "0x00000000000000000000000000000095130432562cc7cd491a09fdc398fc06ca", "0x00000000000000000000000000000000002b3427f5c10f4a683c2ee8cc0b7b4f", "0x0000000000000000000000000000003f22be3f46bfafc0944a140fe75799de83", "0x00000000000000000000000000000000001f43e626df524e14611d7e7afc5e26", "0x000000000000000000000000000000593be2b28462bf0b6e8c7da5d733bc573e", "0x00000000000000000000000000000000000ae0a54b5300133fd2210118a87928", "0x00000000000000000000000000000010350d4b78ad196c06f506bdab712d8f1a", "0x000000000000000000000000000000000000d112e694bb605ef7a278b3195ef5", "0x0000000000000000000000000000006b850409409390cee31b24810ed5269eae", "0x00000000000000000000000000000000001ffeb4f339fca0563ec95f16a17cd3", "0x000000000000000000000000000000952356ce47719d236572cfdac30b1ebe0c", "0x000000000000000000000000000000000027db46e80c528165e583ff89f1ca8e", "0x000000000000000000000000000000f9a5a0320e9983cafb3a35790c7d755e84", "0x0000000000000000000000000000000000074e104a657eb9323d4ecbb3c299e3", "0x0000000000000000000000000000009ec1c119a5b96bb572e658eb52f21bf5d8", "0x000000000000000000000000000000000022e8e18446c15f2c16ebea085fae01", "0x000000000000000000000000000000786419965a40618dcf791f0151ced0eaf8", "0x000000000000000000000000000000000003c3f71d70ace6cb9664d2880a6d64", "0x0000000000000000000000000000000ea59eb1d4edfcdd3646616649df03f3e2", "0x00000000000000000000000000000000000629186c5b4df7bde85671e77a6821", "0x0000000000000000000000000000000bfeb5f1d042fdab6a89586f48d23b42ed", "0x00000000000000000000000000000000000007629ab17d6b32632675a6b11dcb", "0x000000000000000000000000000000c54815f341d6303c5fafe84ac6732a2afe", "0x00000000000000000000000000000000000680d2ba864ba904da2c012d25564b", "0x0000000000000000000000000000007bd06a3b6fa02f43e5ffccbec2445803d3", "0x00000000000000000000000000000000002676fe96853382f30566150499610e", "0x00000000000000000000000000000058352cbb7b91b8fd36e8c25c3cbab3e05f", "0x00000000000000000000000000000000000cbf72a4f928307a1430e24867fd09", "0x00000000000000000000000000000084ec0d21cf6b35256c5d95786be6720f47", "0x0000000000000000000000000000000000220d28ebb7de5018e4777f90c9dcfc", "0x2547357d7418af8826b694fcc8136bb9244820e767f29ba8f2b81fe24187a734", "0x1efa08fa065cf840ba25650bd32166bb6dfb63a9ee3fe977328321d896a2b5ba", "0x29ef27b36fc7a1c3046b7d060bde765d50b95cd49fe9a69205ab6f9c90e59ff8", "0x0900277b1cd7dc65b31282e92eebd70e57d230497cf6557ca57af066a19ec554", "0x076b62f6a067ce5e52dfd88022661b25d779cb24ec03d275db0986c72080d805", "0x2abcb91b9d1e38e291ff30418c4df69371e7183076b147f1e9da8d01aa0a3946", "0x224fe9e27b5a7c6cac0f85ae37366c67087ea76a834b08c4cb1b9fe8c8793b87", "0x2f9fdedc3d0acf6943f1a68f35a3904465f9b7aab27f7b5af82cf97dce8d13e9", "0x1aad0acb5ac8a713180487e98881e63018ef86a6a8fb98b5e6c4f673cef3f31d", "0x09c42e708fe42383a32fa883fb0087d82abb47a164415f50644d9415390d4fb5", "0x231fb26798de67fea057c9db73be5938affc38e80900f2201e4461458008515b", "0x1dd80e1b2e20a72f40e1ebc8175c07337e4305da67992eb38484f03d0d3b754b", "0x277c5851d846067608fa34bc27d4266dd3713c5288656fe9448bd02caaf24fab", "0x2302a39d5a3f90865709a8c384f28bb2c638ef66348e288f58c57dd24eeb505f", "0x1b0c9b743d7516310c8645e238c0f3bab0c56534061638d0772f0afd4560222f", "0x1657872876ba3afc01e20551de678c90421a384de7ffc0556054047ffd924662", "0x0bd2be9a919ddfddcf0562bc1caa2ecbcf1b0baef679ed186262135e961ba487"]
-let ppAcc4 = ["0x0000000000000000000000000000008a03baca4ae5aa61faa96b1e51f3fd84fa", "0x000000000000000000000000000000000016b74f3d6511ddebe441aff2142e6c", "0x00000000000000000000000000000015ad94efd86d5ce2aa09368278c5516e11", "0x00000000000000000000000000000000002083b0dd917f184becd50e500d93b2", "0x0000000000000000000000000000006bedba71310427a2fbc8f04ce3b1931d6a", "0x000000000000000000000000000000000007bec0e49fd56a53e274bc664ee014", "0x0000000000000000000000000000002952b0c72b7332c9623ee3f66fb670af0f", "0x00000000000000000000000000000000001f8ec770e8a446014970a4860950e8", "0x0000000000000000000000000000009ef25ca0e4aa817b58bddefa06240a86de", "0x00000000000000000000000000000000003044ed95228acd185ae511eccd62dc", "0x000000000000000000000000000000e184dc07d017ab471d5488fc85568086cd", "0x000000000000000000000000000000000021e3055eec84d5c5593bb5e59dde12", "0x00000000000000000000000000000005f820030b31b145cbf46bbfdc5925d6c7", "0x0000000000000000000000000000000000244ca5042f94192cd5fb57764e8dc9", "0x000000000000000000000000000000b002a09ac17d9dadfd1293d63620ec7fae", "0x00000000000000000000000000000000001ebb3cfbe0b1a78a65cecaf1dce052", "0x000000000000000000000000000000f62522e8ebf75aad2e1e71ad968a65ddae", "0x000000000000000000000000000000000008643d0b587a9f3fd3286bce8babcf", "0x000000000000000000000000000000f96b06a191fbdf842dfe259be6fd8825ce", "0x00000000000000000000000000000000002153c727c639a684c3c8922efce29a", "0x00000000000000000000000000000046e0035e466cd94a7001c3314710479062", "0x000000000000000000000000000000000024a283b71a68bc1644da803beff8d7", "0x0000000000000000000000000000005a1fec6c9438319920c6eb8b8f850ff1b4", "0x000000000000000000000000000000000001817fa7a5cc71c009783f8a34f5f5", "0x000000000000000000000000000000e280af5072cd6d2c96b3514f4d31855c87", "0x00000000000000000000000000000000002fbb05321f51748a0d9e70c84c6360", "0x000000000000000000000000000000258e426a27225697e7e07a2044a9d78d17", "0x00000000000000000000000000000000000a2cf2ffb34242847bdbe22956d749", "0x0000000000000000000000000000001817db82001a7f0a0568d3056540dc75ac", "0x00000000000000000000000000000000001cea53b22ef8c0311b1078d23e8d29", "0x000000000000000000000000000000c0483435b35d80aaa3dff97c9b8103b683", "0x00000000000000000000000000000000002f7661a9d58cf908989fc0353ebd3c", "0x11bb50c90f64a4c8479d9141f0a7dc4d87ebac7dbe4b381cd639ac9ec791eeec", "0x0449cd2cbbfc2b53614e4030e27b210658cb06fca06b0e79532d61283e4a3361", "0x053cb37fcb2be0d60f276cd99b8c8053e4920ddc6212e297810640f9c36c9045", "0x038c77309c73ac52e5b21696618e39fda88f417f646001c8edf8e025f5bf4317", "0x086d4a83238ee959ebdb5e6e74dbaf411c2d5eed3958e67245a688bcae445333", "0x12283d7a23c8a17e22f0b108c19e61a70f2f7aa7a10f2960eefd4b28e3713d92", "0x2173808917cfa631a74bc75d566b4d6a9a39c7778328a49156c5e74fc349da2a", "0x2f9fdedc3d0acf6943f1a68f35a3904465f9b7aab27f7b5af82cf97dce8d13e9", "0x1aad0acb5ac8a713180487e98881e63018ef86a6a8fb98b5e6c4f673cef3f31d", "0x09c42e708fe42383a32fa883fb0087d82abb47a164415f50644d9415390d4fb5", "0x231fb26798de67fea057c9db73be5938affc38e80900f2201e4461458008515b", "0x1dd80e1b2e20a72f40e1ebc8175c07337e4305da67992eb38484f03d0d3b754b", "0x277c5851d846067608fa34bc27d4266dd3713c5288656fe9448bd02caaf24fab", "0x2302a39d5a3f90865709a8c384f28bb2c638ef66348e288f58c57dd24eeb505f", "0x1b0c9b743d7516310c8645e238c0f3bab0c56534061638d0772f0afd4560222f", "0x1657872876ba3afc01e20551de678c90421a384de7ffc0556054047ffd924662", "0x0bd2be9a919ddfddcf0562bc1caa2ecbcf1b0baef679ed186262135e961ba487"]
-let ppAcc5 = ["0x0000000000000000000000000000006f10d40786a2ee631d0b97ea199ba1c98b", "0x00000000000000000000000000000000001e1f73604767632a301c2fab39a5db", "0x000000000000000000000000000000904d4e997404a4820428e027a098744eda", "0x0000000000000000000000000000000000244f57d2f440549bd6dbff5d83375d", "0x000000000000000000000000000000f43dcbba833120934f24fe62b47882b715", "0x00000000000000000000000000000000002139fb57a2c0e69e1b1aec5a44fedb", "0x000000000000000000000000000000685d980fdd8e3fc94a18cc73752e1f6e77", "0x00000000000000000000000000000000000ef171479dc9e241dc8720b6da3453", "0x000000000000000000000000000000d10c762c2aec328febb1a1aa8e9fdeccd2", "0x000000000000000000000000000000000029c858d158fdab3c1353eaa5798e93", "0x0000000000000000000000000000005004b288ac9b9db24ab636b0ca685f5cc8", "0x000000000000000000000000000000000026439ee7a024a8d05dfc9644da2769", "0x000000000000000000000000000000894c211e36dafa782b8d3b53b21bd63369", "0x00000000000000000000000000000000000875006a93e0940a7048e228a18ea2", "0x0000000000000000000000000000000d60045b1ce629ee211c62522575f53ec9", "0x00000000000000000000000000000000002366080caba7d466b11b15c17a03d6", "0x000000000000000000000000000000b672a714fa7e4a427dc109b4ed435023ac", "0x00000000000000000000000000000000001da593869d9b8c1228a5072ee0ee1d", "0x00000000000000000000000000000058ed3ff80603b0fc87f34e13c2559e0f33", "0x00000000000000000000000000000000001d8c00fb1970081af1c1147ed0ff19", "0x0000000000000000000000000000008fb8e9dc5d5576d8af0daeb6cfe03e7a68", "0x000000000000000000000000000000000005374aa22fafb9868e3c817ccf8fa0", "0x0000000000000000000000000000008a214adf326ef85085077ac987f601f41c", "0x00000000000000000000000000000000000f813296c458692649d0031ed2c7b5", "0x000000000000000000000000000000dbccfbc3d998f716079d048bf8f8d7f04d", "0x000000000000000000000000000000000010e2eafd9f88c5443c0247c0dc178e", "0x000000000000000000000000000000902051e64f7f55737db00420243a7cf548", "0x00000000000000000000000000000000002237b160710bea690f1fddc8f57f36", "0x0000000000000000000000000000000044d39b841d379dc6d918652c3e8931b6", "0x00000000000000000000000000000000002df5a0627d6e67d6114df337418852", "0x00000000000000000000000000000074d62df148820e5a41c73e18866e80bd29", "0x00000000000000000000000000000000002cf0c1b349b424194e5eefcb5fef09", "0x1546bd5b6a2e3f60dfd75b78602e475d16f5a5fe15a1f823f441ff3cd3879099", "0x110c1ee61d09dae3fdcbc78866ac2e7084a43e1c1ef38f19ff862af2ca360314", "0x1624ec997c79bc28c1b1fe884a4c4cc79110e41669c77bb23ad0e9fe8856344b", "0x2baf2b28f34516b77d6f7772412da02160662f12908cf23d4ac736712b73249e", "0x2cf363fe1d643ad938eda5c5909352c00cf29feaa8877baa362356782407e47c", "0x25abd81ed81ee237729693ae187ade97adf6e52c9fe7a628bca327e056147a6e", "0x07ed0466cbac62d2cdd671db1cd8fa30ccb0cd6fbbc9689871d9c1f85c6142b3", "0x2f9fdedc3d0acf6943f1a68f35a3904465f9b7aab27f7b5af82cf97dce8d13e9", "0x1aad0acb5ac8a713180487e98881e63018ef86a6a8fb98b5e6c4f673cef3f31d", "0x09c42e708fe42383a32fa883fb0087d82abb47a164415f50644d9415390d4fb5", "0x231fb26798de67fea057c9db73be5938affc38e80900f2201e4461458008515b", "0x1dd80e1b2e20a72f40e1ebc8175c07337e4305da67992eb38484f03d0d3b754b", "0x277c5851d846067608fa34bc27d4266dd3713c5288656fe9448bd02caaf24fab", "0x2302a39d5a3f90865709a8c384f28bb2c638ef66348e288f58c57dd24eeb505f", "0x1b0c9b743d7516310c8645e238c0f3bab0c56534061638d0772f0afd4560222f", "0x1657872876ba3afc01e20551de678c90421a384de7ffc0556054047ffd924662", "0x0bd2be9a919ddfddcf0562bc1caa2ecbcf1b0baef679ed186262135e961ba487"]
+let [pubKeyAcc1, pubKeyAcc2, pubKeyAcc3, pubKeyAcc4, pubKeyAcc5] = fixture.pubKeys
+let [ppAcc1, ppAcc2, ppAcc3, ppAcc4, ppAcc5] = fixture.publicParams
+let [ppZkAcc1, ppZkAcc2, ppZkAcc3, ppZkAcc4, ppZkAcc5] = fixture.proofs
 
-let ppZkAcc1 = '0x160bf983b1e6431527c3e148caa7bec4d230431db7e9e7490118cc1da9d22d39069812fa84a3c7b96cac03c4ebe16dfdb49a0bf9b938a30a215832fb991805e72edfca1f80e3caa0c27bc30968e2e27875f86386593532c68f51cef83901d1ab16781d622519c209ff3cd2943944d4ebb2e8a24b842ec0231beaae072a8c12460761b2251f8f2062deac05a201c1498f08e872502cc0d82221ac9368f63c623720008ad16f944cd8c8215d802ba613a5f94a4f891f42b40b63200fbc7901cab40dee4aea08c76f228569a13c37cb89d83a959e0863cc1b7a46527e8b7a929cee2ec084585cbf35d0e1e9db6ac1bd71f3adbba3d1ce66a317dc75fe97c552670717bdd1d60115022c7096b3e258589f2d827b2a3afb96ea5fd72c37cd8c5499790edf834057f9813644dc9ddd76692b8db08d0a436615afb2c029cd2e352701c21d26c3e0a215da8620848b67dcc4f1707d331b22de1795ff5da90b765992f5212e6dd0b438052bf081216f215f4ae4785415889d375c6d410a212c003707018216416454d6c202ec313de0ffffeaabfb8a4b5f15030cec351e8a356f7e0d16eb1495b8024db154ffc0c9f64eab0d6a4be59b612173e0ee1683e0d8d7a4ca4aa0294bcfebcf8633f8cb8df874e2b9d251f5a8ee620eede8b4e48241948bf342f11cbc3beb87c679dd1cebe0f1d33e7d7f68e3346f45b9b5203641cf1a1686902c08e16cb62816e56824a1aea20827a4ecf7387b3023a3a71edfdc7d7aca8ec7e0137dc3cd10e0e917b59493abc5ba7b2c532ef5d9664ed23ace1351006ba8750716f251e5f7ce2107f5c82630dd4c6662ca8f2f46d803f101ed4bb57ee4527ede095bff0dc6a60e83204fe043d0f1c888349dc89b033290d11f92a0eba023a3360dbee14cf1b272f630121dd7d573ba8ca02cd20d6fdfcd928065b132861d9fa7124361cf1d471a125cb19099fdf3123249c94d2be165035fa6be70f7d900541b2253afb8f63dc68f8e4ea8613fc7f95ec1f7ff51375eeb26759a2b7d60a96be904f0d76a099377b01fbf818a7643dd003493c94d7e44c5c7c234d6929a7706690c89cd7c52655cfd457dc0817ed1df67db7152705a2717d26f6c761464d005f21497087b25c18a0ab0136e97bc692378d9e0c4f3900200cc17c999dd4d830c8623aec20fb133a56e029c9636cd82e7ae38c9a4f902a3be3708b4ac6475c9a3010d076cc68c59bcfdd0dc3309962fce099b58d671d640d684ee4bb4850b3c55eb304bddde8a5536c8ba75ed5a64d750ee8780074770b29d5fbdac7227c0b844962fd9d80df1fc37163f5bb09224e5dcd283bd4698f09c43397db46aa2bedbf76c050c8323466ff01bfcdc73a34cae9a9b9920b4696da681c1b5b90c4c1d44b4c72a51ca5e74dce7c8efc2ef56d049071582be252e786ae3a805edc732a6e5224d23b477ebcfa5a373c8ee0fa29118ce3643ad375ec46752bcd0b2d69e909700b72f07c837e7643bdf32d3539a853b8b25eb69dd3cc6771012eed4b3fc4cfef9632ea6f91d6ee24c02e99a48ab941f418a692741f906da46211e228903be88d44a1685daa92cf2908d72034a09d552fb3516e95a03463901417065478f8646bf212b9dfcddb5e4c0f3e581ad32cb592b775662797e99b69665c204ace3442279b625f594c50202619acd8b52c5ec537440144fb7740efdb456c755a15b0752faf8188e7a94a4bd54eb99470b60ce61ab164281da8b7d29f6253b803933033832f40323571814df9efe2bfb16edaa59fa5cdef8ebf2ddfd21b3d75464bde9f3a6c429c5e50425d42dac306fc0f57bef066c272f2af76e6dad74f1adac8cafa64b0d21ef94d4a42ae00df0a81baf76afb73dd16b1b66ce95ca292eb13fe781d0a51325957267299467e001bef988ad2c0302219f10dcc6854508ee6c0610378fd5440d985297881f6f0254431517b265df9f376f4bd97c01162936eac91141b3c8670ecabbad5b4d47a01ce661769ee1391fc8d9ead5bcb51f72d43f4eb4621088af0cd9c9e0a8ebeea6ca74eb13a3ec61fc9adc59bd7194f121329a063a0153a2b81add990141a39527acb06c7bea35dfc2a11d9fa62ae2a3732664bde1854e3259079a54bacbf966b267473c37c89b1e1fc3cf503b9c5eea78855a975436ce19640dfe74d8fb3bdd973b2cc1455d844656fd2e2a964e66efa9c1b209fdfc2ad6262cf2758c5802c7c2bfacd489a20b0f9abbc93b04a0e31cc9e6d6cd69bf009ee604c2283383b7395fdd5a8c6d084bc2e36758c7aaf117d00464681c7a48bcc9291a947c1413059d1c6247e0b58533eec52ab8b651ef504f82d05ebacb69ce6dfa29beaec74d060e6175ec681565aa8e2e7872a6068481f132246c533873f0a49d0d4daaf09c8c297810842b43f40264a157e74a957900d45b62dfac953b6b2b3315e37bab56507c8175c67778adcde78b130be0322164763dbc4f986821f8806c206f41b109ad53dd91ebdb2f5aba789f89d852fa626740ed747a20b8d588457929f841db77c2bd87392d171e4c7d32398bf10b2cadb362a4acac55d62339d5fd11434b741d3e9c6bf08bf352dba1eb6b580acefaf0c3e7863e4586b30c407f2f2120f88102922f66355a831efc08f72b8922bb9a0c7d16f6b0ef45a301151d2e2fa288d267c6096a16a79bebe3cdebf987fd11568fc74bf560ff7b8f3dec8ffd136c27c64b5dfcf4e2062a7fffbaae1c7c745964a5e7c0db2b2a8cbec9e941f00d348389c9abe6cf13abe77ecba7b26bb06249e65be4cf78f0367e9d39bf1ac122fc31f71f187247cfd9d607819f8542b1a97e04fb3b7fcb53588a26bc1d50401a1c200d1a72f827fa6ec6910754c9a23f64599b7746496a83c5d9641d4538411c5d4e722bb9c88ca5f4a7c31d41d13f5100883c67be2f9eed5fc556a584241a274ed8daa62269fd56fadc235c075e2d15bedfa7d2f79475a29c5318f580dd3310810dc1650379aff82b89d9eb1512bd4aa8ae7c8f8ef3b2d3b62dd5680eb97e16e5523c46ddf7ae5f34026ca3ff88e1fea419c8c8dd8a134403cf2eb35294630d00d24f38a9713116621b7842e058dadcf77676481640d8ff852ec84c0897da1c6cb9368cd5c697ea36aea2a08f7ab48502c100c428f9d10fff682c7e37e3b301c1eeaedd6044137255affa889ee993633a38ddf0fc5abb990b349d1bcd94f028d093486dd8691d1ab43ce9aa32c18114e4e071111439c9f720814b0aa9d8a61dc3412d8dcada696ef15bbc4643cf7c054fdc3e4f88295c6017c1b78e8651782696d0e1255f1de882b1fe5c7d63f21ea4c65c52afa0798e53b3719bda2e59200ec9b072e7c43501121ca868a6e316125ab36910e3b7cef6077260cd60257fea2090ca9f2543ae6a9eef98adccc6016037e0649aa0b61c988d1305211f77f9f11b21984fca1aa4494be530ab1f95f1ae950c1ed54a086c19b8b3b1219ff8a5b316490112b91f15dec3761586f4ebbd604747f2d74ac0da367adc096ee14da0862bbbb8fa71143e114bb5e2c01be6070744486e1a0e80d2c3943239b5c16a3c4812c88e115b73d4cdd6213213c59fec24da81799d4d690869fea678b88b6a81542dff19d14102dab5139387614d6021bfdef3fb57fcdbad8392433f4e3ad8c0410ba862598040c7c6c2857e73870f9e76061a6a50570ebdb4413c285a6623c3a928be55581be5cb5c94b1e1962159a76130f8966273d866927b75586b3d9d95d717af93742ac8185788650654b186d7225c8135775694cfea3bee58b38a811ae9164742b3d714e4a26d93f3094f43b6afb101ab5064b63a055ba7723b7d687d8613ca9126ac0929048ec3a3caae247583e63880153f9c2bd793d633b18507552d297735b8e1fd952b2999eee7282edb2c2e93bd8fc36d2327ca6b869c9f05f4db1023f5e1c216619834169125c4af77c72165fa53c76fbaadbcf5a0f4b67edb3c0bb4a45a7f0aa59a2a951eeda4d9f2bfb3e19e8a080b23bbdf691ed908d12114183fd3d020ec8bdea66807c59beb225ddd2ca0721c8ac9747044a878b314ba8d2d44909bda912dcf0f57fa113e5fc661b42a1eed26d3c133733c55e92165857107636ab1885cc4355b85a1d9d1ae573a83bb9ec67aa20180caa614da2b6d3f85074a50bd59fea1b5a2c53a84edba1d762827a4d6760a9aa5a52302266a1796001d85e0799cf72db40c95eabff6608686933d9788819f98513955c28864d364302c7bd3d1fc5fb5ea96f0a77cd9222376b1fc3d6128ffcbb3b4c83135d68ba0601229f6c60f4662e5a244cc755f4cda4ae76f4e4b8fdbfdefab5d0bf8690dfbb90e30fa9372fe5c9a4c23d73cbc6dc1a117b85244622c59b0ab80523cbeaac3222e49708cc33b3704353a8c0b4cecbef55b5e78ece56e423843df634067e526cc2effa8ba4a9c9792496aafd4c2974f76c6f2edbb0ed0a8563cca35f6ef64cc8812f0ae73d2b397730fe4fed1ec7898645e8d51ed65a487123f9f9daa21fe855000298beeee66a26e637b30017d05c8b2bcba9ba501498d08631295aa0493b73825723e8c7987c1d33ff8c08820dcf20ecf1d30bc4f7ac430bdee327d88c865db155044af10b5bbf42e9de7b23dd0101181fdc2a90689842ac0e2d73815d178ef2738f7a4fcf5ffaa5dbaf33d470b0b90ffe1f43ac8db71141100ddb985dd77f50d9a3ffd081fd11db891d06d2c3e1fb2d07a5ed5ef8bdf5d08234ced038e6a650cc316d443a04355a15dd3b1230f3246a36e05b4d44262115862df237f4121fc0923372d338c4163f6520a030556e80bf4e045c54f6381f07fd6207c15d0a5542072f601d5044ed0da1a6e5b4c435ec9f72a32ed675643de984488e3173bf7e101e742677fe1e6301246dde7f9eff6d48f6d5202ba1f68a232a19e48e49202eb205d54b740358d8fdff62ca13bf9c614248f2552076f8c3b7bb23323bc6307df01cd7873edb5ef7dea544559355b450cd7ad74fb12384f6a87382ba28fe9d6f41b78e21fc8b50bde174b22c826dc90c9439af8b79a49cf7e69c9b4095fa291c1131121b9662ce5cc8c874d171714dad173ffa8034549d8568f8bd88b2db2a11e0e9181cc16a41a2e8db2484f4af5c557b35ebda53da39ec39729594dd957030c17c99cc83893f2a0036fe4ca4fd4b34a61fbcf97662c0a619565aa22930ebb7c1192cb53777f2ef093c1dc912b8917b49b2b318466dd019571c2aa82ed3a85b50d8ff1bb3e26bcdf1206d8a6bce4e637d502d6a53cb672c2f1a3a181805a081b2aec850859104c0f050321984b2e033b35c127b472c3dad93f40ab2184f478400412970dc22397125a5fc599b929148f4aa6f50063704df37f3bb52c77615fdd0a9ed5ab4d6cc0ae2fa0a64e1aa917a98344f8356717ef3fbb3266e698ef44a21e0f422fbf4509a68223bb6fe09d9b971d3670053f18823537b046ce7ab9a9f825ebfe524db6f09f6134151814903c0f2c2aef842662a06fd0c3bbf08d9c030d1920459ebe542dde054c7ccfbfcb6edf6eeb3d0d0ec3cc5cbf596472993dc24c2206c46ef9457f187eb18f36246574e53381571521390f097366aa12c9d969b1232ea90e1bdbba991461d7c391a162432cf022dfccb641daa3f292b59c0003ba2e17fbc0fec575c0ce2bbbe2204bef6acd54ff8335bb4d785cd853ab3ced78472f79c66f1435588b00f2d98df7711dfd4c60c99bffab17acec165f22ada8d5e51644de0f0b7f667ee3ec98d293948f9912ad3f4f4ad87b35f417b50e2699a3900a2a06529d9a4164ab1946a4db7b98e6bbd3325ffc42885fbcee7b29812e2efd1936111204d632435362e4087efdaca62c155810abd51a6b4460ebcde225f2670c8690784e40ba9acd1418da052d0bd45c3ccb68e246554bdc66e15dfc1d8dcc2ddb01eafa761b562e0b0dee891a886840170a1e69872f8e491dd8840319bfb90de1edf767deac1ae3af19b05f5e8522d3411bc3b745659d4e982a224a71773f187d741bfd7a2f6bd171a2d877a7c6c51eadc8c321a4caf677df2ccb3fa36ead2bf92973baa19da95762b049c6753f17746773620966b76160b69d3cb82e44210397161fa746fb71d0987683480ab8d2431f4b9b6dacf9fbef4c1c19b2a25471161f2ae8248385b54a0beab394569c9ad9cecd4004114fbe532e8f555212cd940a1272b32600d74a9bfce26fa6c2cc6e19f2009eb6d1c3467133ca19a810a11e2fd7ff2e8d2a2cc6fee86e362445f6ea3bdc55cbae983af767fb0342ed7ef5891f075186a6e67c378101def693601cc50eb303081e8202e0a2f90ef9e5b135c7'
-let ppZkAcc2 = '0x09d85d2a262d38be88bee5312fd58afaad474b32c3568eea12ef5385298548c305573d57017e9018e2f87f3938cd940e1c6710a81b2a249c53547e9970629c9e251940f87529e4368c1a65ee5091a01ed1053c2c16f6d6610e7619240c3796c925b5ab42441c18dd05c813d436d6c571991b5956a95771e9023d2dfb65ff982614ec502d139f50238a50cca8618113de6a775e78d1e059a42da05db9ba647c961dda20066258ff8f8674ec234f12d0dfa3b59ccd1d325911a026a254921d776301fc62c1856d6d6eec081c3c99f41ad09dd9f894824b5e84cec03798075b0ba9092fe679ab834330f950016c5d282cb898d85bc596c29a169b287ac1181cdaa7231d8c92b31d052402bb99ac75ebf198fb45f0e89d3368bcc21a2bc6bcfd5e0e1d1fa7e739d74af4cb7f5befd92ec462e047e694baffcfac31fbe80e70700dbf2f6f32d57ec3268bcd27bea2a3fe33aa45a7b7bb472edabc33369626800aabf002ec3fa3012f56cd540ad88754ba33a83d491cbcee7a14787ff33a3c76e99f581d0e7fdaf76a685d07526ddabbd427cb7a058b7a9b4f95d7ea740bd7898c698a27fff139b9cf65b2ceb83d8bc99b1820b296079fba6141b8f2ce2c9b5b89b8531c8d651b7cd6b9d3078aec86ef229742cb5a37073f6bb8f1dc0ef9072695ad2a143a2de2bdef729ece32c30b84a63d6506a88672cdb5c2f0d539afc6539f89372da1895b0935296d7ac658e5092bc85083e452538a8589a5932420191fa6b02d1d0c36e9fdb0514cdce995128e0c85cee2d7581672159af8792d4c20c6a56b8b124289714ae6ba204354ada5c4ca080105416c5541abc949dd3ca316bb7ef34f15587b26e50a7e19abc99a046aa713042ff2c71a814ae55daa4cb8f4e949fa1c0c035f0b72a81f614d63fee3c5a923652b8a0e669f46fabd5dcc45c611b507c405c585acdd41df7495fcaf540eb8fe8fa2785b0e9dbcb5b96eb55ed1b98272ee1126110c9f0a5fd7483b46a1fcd0527988170b4b80357e8ab719c3154682f6081063809ba63fcbaabb30e8ef8c2147c9dc31e441aaf2c753f011448139052edc18ece8b2d9daefa5f8a89b4f1c34c65cb94aee8ac2aec660a140cbb58694afbd1971f95fc5825c7bce04ba6086c73c40a66823d5585947685ccdae3abed78a5d1d00fe9213fa48549c85c0a6f39e2ab427485f0582e18ce2be290917d1f44be52b834d1e7e6909c784b3dbe13509307b6d3a693e283bf727addee3a87d1f757b241e822fb46da733c7602d1157a83cdeb1d0c01a7edcb1108d985a158a47adc613bdda2572a4d5e679b13fc531598f67768a55528def5da60dcba9cc9e574a75118020ea17957efe4979e2f8fe8ffd8ba4a274fd19947d6009abdbd07ced809a1a9abcd453be755abe3f8f8a292d18ae5bfdb1769274552785820aa1d2a9198e1638c1358bc0dd15f533d9a38b66665c62b80d3ba6903ccfe829789ddb3579972eec17d8eda1cfbc342f4398e4fe7bdcf75a7e0d654aeadc0ca2a48d31ef6dd523156562ac0dd870faaf3b13f4250745e848830a22d2a55b46a5b30836c64af61375caa8052c6edfdb3bf45e5ba63432dda58cb9cba3fd539009b701bfd826950546c6bac384fd1905176bc84a778794b601b4ab3552febc2d3b0af912f18a1b09420a6f55e4dd5cfc0ed020e0a2314631483659a5fa701b5f775ec053fe5a361849e6a5343e692b83d37be15b212384ff1bf50f3d7034a075ba10fafd67e5e8164e26d0051818006001b910464f6fe8d0741d77827284f83697fae1e809b4cb2c28a4198cae67c7f9fe11b0a771af7154f1a365f2fb2d36a89a1dc2dd4058fb0c7615c13f1863968adb99bee5256557125d010b9814d36a8ee2566067d2a581110f6387b0f5ab3612aefc2634ae9552a117eccc249b2e1006fb1237460691fa021a89881b0b4a395bd846ed404b6abc6959d4357516dc6c0e49258892d448360b0d20215f55b89a77ac1529bffd1079f6d659de103323d19d2f934ad886851112d3460b4bf043f193eb7bc4602aa34af63e6f569477a41d854cb13860aad87f25b10a5df150a95f0a1ede643078f40233c099b4bbadb1d68d0212486fd42407110f165b17940dccd24187d59ef4ec60a421ae8b22b7d8506611ae4f8def722e10c064bb189a37c23833ac85055753818f448a36aff509c31ef562c648a025671def3bc90933ebd33c81da1adee9dacadc6bd1a5ae991e6731ff5e3d9adafc491ece9a7abf8ff2ff09bd9be6200e1c195a01978a3109ed16838ea3de3cd4150201d043599fe9a3249ab27f6fb7251c393f55925d2be6da18f6f4fb08203ad13f2c3d587c3d0c34201c6056ab7017bd08240d9d68914b2be52fc651d33c3fee0c19ff0e8536cbe268e70641bcbc1cc3a4c15741f3f21245c0c586ab8b4b47f1e818c05e4d895c9434650abd6435a8f5bcd9b4a1823affa72d3b113543ddcd7bda04aa797ad1617f919ba5ce40f71506d6d7cdeecf5c3ce52ee0c444c882b8a3a22cbf2901adde6f8ce1f7711ae812cfd10d0053b07e6920f87825c45cb328bbd70207bebfd6406e7c2e5d5880decf46b7add35f115120320aeef435dd7e66cf1f1e63b3114f5aa445eef54a58fb56d84005da3958d75fb9747075e33ddd71047603cbf3a5d2d6a90712b1746472424402cefb890b9b6ddcb15aee7e0b5fb3e59e20a07ad5f1d0a4fae5cbfa38f061a71dfeb6437e52d7d57288ecd39828f704c508da9548ac9b7f1f117ef172d186ab1789eccd7ce72fe7a5d0b2579ff82814e7101beb530fdbe85364fd008babe4817e38180a950154def785b4d115c89bc88b2cc827f31ee2a913407526d1c02e17ad7a0bf81af20a16430fcdc109b50315451fb80d6aa85be0e9c95032ec2025aeff1d7a959798d348f232c3d3b9b7bd1992162c4ccd1a6cfb98653641fd94b275cd8e26595141a9a72c088813342d5e519b19b778a2db9257d686d839d63749720188291f759198e2b058535b14b736f39410a56faf725e94d3075e7be0e9eb26fb0458e83be5a20f89eff03db78753b7f21bef7fb63f30824d6e3ed4990eca8646a8594817ffa24e123ac2604df60e063b1c8eccf14b766d974a69d857cc81ccd7376b9a383d771270644ce15181e9bd5b1ae047448d5cc03a7da1a5093e2f11b369baf35e64f7030cfb3573ceac8260061c8d56422bb40996cc68cf1c51294a4aa99ea9054ff1ef1effdf4bc37950995f0edd9ca6d35ee3e55d7d1120bc56bd71972220ba0e9d50092184676f606de4370ea81e73c62688931fb95404fd681fdfbcd41b338171ee598070e51c63c080ec0824aafa7692b14df9b6b2319a341b959be5aa6d1e16bf5a1534c268fc003cb82c11dcf14b38056b85e0e6098b397b9b8237311546349d37d06ea0ab629e9d403002724bce5c85b5724133dd200410ae0f75fd292596246a9623877399aba3c1294d655973817154b6f868106029527cdf172839de68c553a94e3e6a28ca9c220d70b28f2155303b0f2e3c0e37c3ac2c504e7249c9a0f6594b5a3b4ea3bd836006ef6cb879ce1088b227ca2275d19f9e2b7cf215bd3e04c2daf31a0deb19bf29067006680b64f358c96f1e124517575a1e7893a6f3f38b5b1e3ebdedbbc538f72a07b50ea8404cc9c4815990e5c941378243d47473794e8ad63790c2438063602640708681346d48e4945e0cca722d25fd922d45e9594b2a69491bdacda16dd70db40cad16a3d83cd8d724f33722df0b1f0e5a55e9b9d4c027e2d602db0f72752145ce0ae03525521d1188a41f2233a6709982c6e3e7f65fd0624b01e1763d9b19e8416de2bcd5526f45566dbf8bcfbb852fe2385bbd3781a5a1803e09d686db17ccbc4393e6b81183c56303fd8b93faf2a5f96c7cdec978dbc27fcd0593cb0925c7f6d461f6663950f78ba8d4f6adb02db102f03c2f6234d146ac52563c72f10363e01ff460999c4f0c07cee9b093a9a6a292af4309bf2c49138315355c310d29677282aa28d7470ef2dd178dd4e970a663b2644e72e3db9513f9ed0e7f850122e30cfcfb2687c8cb8014f75a8441f5d7949ccfb16dd9bef8b38adc1e3df03d224b2ad17e8f199c193a57571869d43a14808946d1fe3fbbd6de2a16c986b52316efef3de16fd058288c6fc5cc2e46ce021669260e14b86670b9ccc48552a64503f76f579a610fd799406360594b78476d989a243586a58112a66414570e7e2310a738929b59d806465f5ef8c2a8c871dced190af043e6e4aedb35140560a4571fca007617a9f93fcdcfa0024c809d359ae720a16ef141519007b3a45b2b4f6e26972f377cd83997fa6d1503bf3491fa5a3b8b2e434878c18a0459a586c6b1c40242dbef8c2b0d27aed142998fa35ad0017b4ab9224e5f890b3051d1612806fd1f0228d79bb612337c0ca619846312fdb0a1fcbc729d76026f769ad1424a30f40acb0fce0e6f38227a932efac32a77d60babcb076cb6bfa11ba8ad0e2bf7f6d12b3a46e1ba3456b2e15642f1102a8840a9167550516cedc6bbea4fce19414b8220570e3281cd20a00216f73882bbae317045aeb6ecd024c7fbad5b827291f2cf2d47ba2db62db8b00defeb89d2e7e46807242b30fb742081e0558758d7235966037e52ba044fb7c84c5a7470616af7cb8370494c2135a87210d3387d20b60b111acae81293b1d63dd0600286b92cdb2899fc3880aabd7e6d9c6759afcbf767181d29bf36a2068b13c5e1785fc18267ace9101b741b0e7d5702e62cbf99f995ab1349b35f7a4457300eb9597e8fd434010e937ecacf5049a24b7b8cab85b817a21e29d7450983027d72ee0ff18621c7d5375cd393fe4767b25f6182d6dc4cff251a5edde53a9e1233ffb086f0e4cc3b93afd475e86102e8d9b089a7e8cc19000f2fd7cb835bb6a7d26a88fb6c322c196bd5dfa753febc393322761c39b80d753210922797912cdf57c35e6a3f19fd0c8ae5ab6231aa8eb3044d2712bade419dbc18b2edd6f4addfd621f75af4de2cb57d20355fed9d8940bd864d778827c16bec1695eee6afc475ad57bcfc7ca2009c595084ed2a572d22856b31e0643e7eb77c2180c782f297357b9a88f3ce55e7f40f9d1a6d8aa4c1780c0e572daefa910413162dbf1fb50a6befc2f9eee3b95273b4b686f0ac3f958a1f19863edceb5fd4cc231f272d1006d96c9fca82f90b7bafd4c1977201e374a38567f95fc32e5a80ad08defad63be0541a9941278448c66a48426e22b076e901339b64a1ca33f986762137e3d7c2236de2c497099973dfb9060d2cea0dcb1ce85ed376dc43344b464329db573138bc9a1e7e477d354014abf612e819c3ff95a20751da789569d441f023a0095c1bfed79dae69d723fbbd2d0b440491de3f69581b8d6180de1d460974261f9fad649dcf4efb13d5ffc17a97322c64f013e82fdc73b75ef71f3030d6ec1fb6e3604ea6e9f5ad0b856fdad5c596dfa154b4a7d2a8c8e1c6befc42e8805e1911adf33721fd123f56946c83c3d92bd02ff6c51f92290160b85ecfb3a30978179348548b892ea7bf33242fee438c34529b42e4d485bd7eef2f921476dacdeb04b524c584422a74cf0b5daee885800e5410b0af3b1b63606cee076f9339d56f2a094b9c8f36ad6c1fc5f73bb4d429709dba758d6e3de23d23c855384affef251afed46b9d71b87dca7d25385de460cae93132501d48fed8c07ab12a4af1deea22770e5f59e00b5800e00978d3367f2a9e067a01d20037c4a3decaa187c11ab52b0b1f34382b632b799c8e9a623bc3e70c1d649e6b7493294bd47897be3bb4831f924e2789e641afccd041b3e50f9a2fa30cfd0bb803db5c7f57e82dfbbecf0828d37edcfd13a7404af61f937d576c150a4f0ccaff099bd4bb2ae0363f2bd6ee06ca6044f2011657a9585c0842ff2f7c3b020101dda3c34a3689176e707a5d1c0ad2a85d3619357b6c19144b8c9a3aa34394abd5029e173673fc784e058e9de71225bdf84e2e67df4aa9ee8c6e84e2f74830dc5d012dce048b7d69195275ea021255bb9bebd285446ee172f1541d97cde25bc24f9ee05a347ebd7be8eef4a651256c576c3c54d9451d97e5c1b19e422ad003ae2c49b117a22a8348a3b152acc92ba87d438b4a1b2b10994120e1c6f409192681a8a6de76d8bec3195d2b60c20c01af688e8821565b841655bb00d2a76007d082ad5c43d655370c9ea10e9f636f20231649b641dfa1d5309793c8236293fad47ce9f3a7768037426bf2d3b105991fb44acf6291c6dae6107725394d2eaa39a5097f683d83a3a0bc709542edc1b5'
-let ppZkAcc3 = '0x0b237d6ebe5ff8fe1195c03251246a7e89f42e86504e8333b5de0a805a96fdff035c70d24b8104506d3959ae8db2bfac7ac23c153bae887ded51ecb6daa005e61dd1b67471a40659d46abb07d28857cf96eca07fddd52900de9d376c01d4b1e10a859f2133058800c6a062f2ee104fb2f2a1b0d4d010f8e74248bf212bc82a09180a7d81139e7ba82bac119aaf738f360865bbae494d791259e86a55abf11abf15a0cbb590b38a53c6490997de4cb86a43173a154196cd4460543fd79798081203e4caa46b9eb4b7ae67b1100f311898a8d8dbc336d31bc3b0b328a61e9b06b50b6a9fc33a08770d3f32258e4072d34242fad7da54c8137c1dcf1e16b9836898118721e009bee12e767c8695218579ef245f76a52b6c0a1d6e3557afd178a40314dc802bb5a704aa9a9d720a302f71fc18bfa7ecf2a8af02bb6f8b71a37772810f26bd34dfef937c9f73ddaff78b15ce8d943517cce09d5309cdf0ebfa04bcbe13323e12056e46968e72cc4c1e14d5059c4c149f777727a41d7e0173f77d0ca10b0506a976ac644e840ccd4a0b5d1c38d59cd42972ba825c9ce04a7b3345dfae179496321121490b2a7d328d36b06b4b1127baea9572cdc1fad631662980a00b1f6fead641d06982bc57e39bd0f4d5f65f2a38bb4936dc0fdc265985f09455521c1de0ceaf1d38b38dac2e0912f56a75175557ac8770fb9af29f2e27684ad73911cf5e59c655123de699e4d98d0f6d6f3843e8195a471e0fd7659cb615b0960429f93b4625d1f55373df7c727e6c9220d69eb243fee57dacf7c38704110e60e023cd2c613a032932199ad193fd85edc9556df23cff95c16e61d17a40fdda062c232710b75324cd33af72d0a233d61639d4c2f7deaec515847ee298b33fcc5f771512b3707f7d1ffa2616bbd720da9d5e9b86cbca9c76ebe61a2580c11ccfd92c108829912ab2cf129aef3019f7a187ffcddfea37af52ee7ed4b9590b44bfa3f929bdddfa48950011b501f95547c74b80bd689bc7678e889e87c977b16905b36c131e35656a37144a7d833566f267b9363568530aa849cc51d8aa6d4cd805788b05a30e6f155c9b3f62da3988b415ee5ab9b8ee4749b4ab5d156bce5233cb6d1e2edf84c681458dfa8fd36a17376f212b1dd4045498c48b7d9fb2e06a46e479670257b9b2575904754bcffd4c8cda2a2073a6f07ac20a0798f1cf1612d24b79a515b8759ec350005b161ad996aef72924c1fb4781bbc2094c10715dfc096d55a828045a3f7c9c4af0136fe38a605facb03076059d8a5d8b7632d45e658b5482fd2a3fa701799c12a66703489ad3ff2864bd5ed3f28ee1148a2117429f8438fa25040c2c95fa02e3a829d145cca178a35b6d021c2365eec7c4ea52ad2ceff27ff009b059c3916c4ea36bfdc9b34e849c0d289851d8a3df63109306321d5be7fe082db5ba8efc81964932760f7dc9aaa2f8a7508513f315e090cc5682a125206e7b2a71ae1235922e78dca97f9b9819779c46c96cdd776bf3e6a1254b81983984e70af1ec27f11a51cf72eeb8b416c814e7a8866b9646204eeffb2f48d7c470c09518097de63746e9931ceb012210c54207bef0f6ec24bf2a6e1d0b0b92a9b37cf61fa23b1fc5a27fbb54ca32f91dd2dcb5a972d3cd65cdad5f6abaed3e69cbe4ea202e6c85e30692a785ece382ee479dd9d2452aa3c84ffc09a51e7d04d60212130bd024e62d935055aaaa3f7dd488fe3632570928175d28a731f57e9ea4c42bac29d1e90fb8c4864442002e0af39613ce8b2a9ab269e8ab73a8fa0b5a8d25475a0bcfcc22d48967b92d159ea1ba0685094d75fed63a95b6bfe004b589a37272b72cd8166ac96c49e8ce19da91279df96cdb0ca49f33481b4c6e5337ab083d434403a1e01aca9a4bde9d40268d8822307713ecfdbff63038812957b4c26a50074c01bbfce770c64bf5804a4ad60f7ae465fa763722982af1cc403902ddac15929e2e32c2fab14d9aba1b243c946a44fe7520a9073565a73bdead90622bc1733dc1259265897b04a66f7c96c3e23f0d70acbf8d1fe1b9f15b968f0db1b7747d83f30b0ed38a5f97a6321d4d317502409ca399336ea9acf8755779728bfb20ea6e31225cb46ca54eb9fa23402bb48b718dbbbe5f239da8209742de0097509579787a3008ab83efb45a91867db466a56ac45d6980f5e7364b1f8d238276aa181e5314175b3fa96f6fbb426995ca150481613dc13f7d00f3cf71a7ed4a648d8097f6df05154472846d27d8a47d22e98237bc94d191d174ae5d7d6e468e38cddb56b53d071a95fdabdd544d4378348348063404356820dd70394e0103a7236898f11a6819bbdd62cdecd101f5c7e682d496bf59ad0596fa99ffe677539a176ec5c7dc9e1f151f38cdff498e4b3a3c0a57ad8b496acd24ec6c25c1cd140168c3d5a07e1b20d64f08bb83070cf799b9095418e56bab8e4514eb5bc461967660676aa2d1b32e63f655fd1cd25c4322229afd1f789a5bf4a0df9097def6d8cd93ad6b44acab13e184df9bda0076a3fae2bf14ab2efa33c620f6855b363726dd492b1a7c091c2bbdf67fac028e3cbf62275ddf7578ea70a9b960de84e9e49d7d0844842d87d30298bcc43abcae78c7760aec0e49e86658bfab892783ebf58bc9783198fe57c7028b267b7ae6a77e086c52be18c64ec7845980ab4de94c295ca7af4dcca803eb22477cd19f156e421935ff3e325688c29c0b604a0083b5db96b44547cd15d2101feca9977d2827e850cf2a3e426884d1f129f6dd321ff5e17df09f1d06bce8e11d99425f503446cae91d81b443da3fd0c33497450638a691a988f4be6ea0897a2b7e2fa72bd909382b07e5901ced03a721947349de73e1b7893aad0b767a4ace28bb8702a83b40148b134a0a19043e45bf3761616cd0acc7d8b9ed34582455730e6a341b73637984ab29a49ea3eb2e322d625c772a93387495ecb76fa2d71512093fab1331b34229f12aea2d7e77c0057705ab9a7234c8db7568ce873e5ebe920da848cb7b5a83ce8f1950d9dbab126d3e33104c1b776970bc2f97af69567df4050069edecc01ad0bc898b7d038dcac3ecf05e7d4864526f65f6f166553d3ca827a28273246a0b9b7972b918878eabe075e33a5bb3e0fa2135ba6a98f0499ae12d0a7b1a1574025b396b4c55f029fa810f1803eb416e401a728d81b6aa186b201bf8be39ddfd15a3bc0a4d3bfcc749f3271762af488ace771db7fb6b31c0e1740b69ba6de9bf42e92aeb63ee47550b44ef9d9c7e7eb2f356cbc5b53464ca6f390a3643a4f490c8024f6bce053d2e2c9bb0508ca8bc04867b5cea6063c80452a829dba32cd5c94930315eb0bdff32d0fbf441303fb59e94aa6048d6842468a6c12847946906e0c189ab92bc35f1e10ad6178899eecf29aaabf3daae16d44f537d28a0eb776886d21b3c7c31483be165f1eaededc457ea4a06692011e84dc5f9ac135644ae1bfbf93e60ac1447dd6126ca00c5fb1ab58fcd954651a7fd8988938d1e7ce5db9996974f4318ba66e614032aea4187887c7b1e7faa37c60d04393c3f069850e3e33fbd5ab248959013436f5bfad1e8d101b5e5e62401daa4fcc9b6dc11aa359e0261dcfa015cf6439ad17b261195ae7cfdd9cc29cc3c0e469b9545b82e0653ea84d1d5a9c3df9f61940b661debf96207f902facf462dcd77004ee8912824590c03b191021e3f7883423ff3f88d3fa803f3efaf72a48007e9c90992590b8ad5e45e896191533d5f0bc32a112d58b41fdbe4a299ad8978fd67e6c6c4ca0f4224e1dd7c4e46aa9cb3a6c2476f1af126ca75f61c9797f3ef42c145cb5d5d2e1004cd7a910dfea2333385e5404118e59fc996c1c58735d94ef939468b2df81f20a8732775428e1c3bf3f4a6bf309b332edf0e6f92a18dbae70c095e486b27017bd9f2caf95841441112165a241af3a40230d2a6dcc98333f55bd794bc1f0c2086c6d0076d2886bcc955d9772f5aef67336545e151fe1252520c29f4369f6f1c450b926499e4886e3b87e93997340941b8c3dedcc79c8f2fb99c1824e57b5a0883f4a50addea4aee77c4bd0e97afd720285d539b23253afb2a49f7f239b1c02e49a4fa4053a39a263e2962ef899033a225f6909f66aad63184ed94078e760f198d85669f157a1741b08a6bfd54f1e557e69ea6410105fc5caf4ddbb2da0f572706f763cb4906d4ca88a5f6dc9db73e74215c89c44f2324f78b0455931217721a96ee0b20e840a8e9824a88b5f03b6bbd989c4e1102115d3180c43680945ccf0e9207198e3e35c30f3599a2c76eb304dc945368065e4e633c9fd57f0f784c8e04eeb16674a0cb21a45837f8bc771c1140cece9c117c59f3651395c563c452721ea138ba2c3e011f1dee6e029d3e59af52804dd791abf191dd334a0b4ce305700e677510cc0e40663eabd157cd496dfa9012906c59eeeda40195d26b94610ed311fc0271ef2adb8ddd1c0e92ce25d32b87b2b1e9bb86cb9deae8003f907e21481fc0473a27e5c1b25c755281c62d1c968d31e6ebc261ca4e26c3474fd7dc448126a1d914be581ddfaee80b832c4e9daa9b95da15894c95a3a603bd139118a2560a2a27b663bb5ad314ab2c25da1c4597f39e2750c9f82bcc8f970169d5e07a340aed74cb5a02292c66a61101f15db3452e0780b7ab17aceb916ea9d520a1d6ef01355c52672f717b626c31d812f5a8fa6ae0f2f3ed7ac60f4385b40e78d00d350eb753d0f36ccd35a62e46b51d7faf7b369be540a6ed27016f1976aba0322ce918759617b21ae65b126395295957743a22bcaad016953c78562da55a90acf647038a7b0d1500974a085123915d10e80593a6e2e4c19364af10029609c8ded56b1ea0e88c605306b674599802ef7483407942a2affe28582e071a691b7106bdd912edf4b6885f3d815f4cad916ac2e02b68ff96d687b55c0275980159fe0c04912c67943866d99f2819a86a3d20f7e82f7d222f3fd84ea8e555e34b77435e61611e495d58627d1034dd4c07986dce48a77b21c9849db5b561253d90abe33217522fa94f4ffd21fbd262b017bd53b73a31af3a7feae4b086025915f8bf19b42d6e034f3b8a37a5cc044d8d4ac1bd8a410578030bcbbc1a86f306c1087401a9322110d767ec5def3570f0c85f0fac4e542c2517305dadda3700aab583b00e8bc6ac08c1ab4168e4a5e6c0d8a7bb4e7f4c4f7bda25ec25a5c43183b428e3678551272270de672ab34e921ee08fc244d84cc99da2a6222f6b39f2aa9c4de9aa13030823017bc6a73dd92ecdfd3f3f2301f80de2a39a7ee6b6859522845c6eb44a30013016b324449a2d2f40aa98fd22df84d285fa15a8d568187699a38d2ad2a34a11173289a70305f875bddd9e8a695a600ee6d4c72d64e07539cb8d11b7d5f96d360275ee34aedbacf607ac7156072c882af3154440fbdfa193fa009fee7d687c5d1e81b3c00b2c3a800cc6593ee2d234c5836d9599f9d9abd055b886957878b8a20212db743c02d4f5fcd2eb09894adfb98e2907f9f2240ea4576906750038a0af0c854ab8b059b9a8dbfb61465a7f97057602976c1143adf45d50c93370702789149664b31fba546309c6b88bef3ca2e97192858380a67d563dc7bd0292b642fd17db00ffc50940a3f6856af51cc91f4152ad2759e2f55dac7245a7fa1ff116ca1553233b3bd2a24d9cf2fa284d7d468e2817c598687cfc597c0ad530719931531f69a9836368afaa17dfe7b764f59997d58af0b1706a290a21bd2380d73902f20eba754c0eff0f4a7683f7891060e86ba620ccf6cebe8c0daf1bec0bf2ee5774186a93f13741659229b821555ab7ae813b0b0c84f63d151ef3280cbfe350ad512079ddebb6f9464ff4f550b680931dfabfd69a1ac5e31dd743a0725959fc048217dc596003e10bce25b6a3c833beab5bfb5b74eebee01d1474e6a1e4d7570c1e0f2c7166daf8dd9229076f9f2d43c27fa65bcb17ba8a90fd390ef29d81032df20498c54b94e9d8575e9502c9d36687eef42c043402c84fb6296b9d8ab92d621f09788136375db6aa41a9a6cebccce4894f2ac539ad46b9d244dbfb5f6eb080c1125a2436a588878334917fb0dbc2a03d8598d80bae1338dcc0f9be9de3186dfd0790755e6a23cf76e4a169230ddfca0ebed94f25f836608ec6a54de3d3f790bf2ada5ea5f37d1204b6ee09a82a9f715199fee1f47b75ba02338bb28e7fa3a83013e48abae3c7a39d1e31d34a06a322c1e767d36421b6464fd4721592d06593830cbf91712ab14f7ca87b26c68d54e7b38bc134f1ba657e2a7bbb56cf0f3d5e6f'
-let ppZkAcc4 = '0x0bb2356585cf837a526f6106bc77f24cca67734a67bf190b071148a0e87d2ae318e72d6b6c45684a5bd98b4b3f17f00cc5ce4f57e5a170b0f6936340f9bf90f80fcc8f3d907c7112620b33ca31152af2c6382b225f8966843e27cf4c3c63973f15683a66fddf96cfddb3870624048c9e2060008c1f83b4bbf3fd8923a4bf9f940e0c56f1c3970320bed2d03a7a320d334ea61cba339ebafc541058e1555a4b5e0e4e8097ee33d1e5f68fe71d7d13bbbbfd15a38786886e53b98162b2771b6a2812530f4a66e9409b9b5854b5828eefb3bd9b756b7c327c99a833112f73ddb8fa2b7ecbdaba97b5e8e34b4b81b1532cf346f1483af1c3e51fbc0f3ce0b8ba56710a8af008f163aca4cf547fee9ca89d6094d0345a21b4c42ad995ae48c9a5364901eac9e86ccc07ffe7a90442b492d5f7ca61f9fb4c6802f6ef22eb8ed8d775e53009f419e19c969fdcaba1f063c2dac8e40307f524574404dfea056c8f5952070329e19cfaa43c0f5bac94e9bdd5df5e126109fb6e49449023bed55e96a24367157215c0557e8d5912ee64552f0cf42fd1aaf9aa83f2fb0ac4363353f64b27ba09a9bdbbbf94504fdca6e379c392646d0e8a38bfeda5e57299245ce5cad3596a038e53c001f79f1132547e1c0cdf1f919304b440f64e9f747219fa1bb9dfeab01ce9db6c360deafeb2d597433e99f25e534312a38c8430608d41c4ee52ec97ce1b3d4bfc6e5482d7c20162a02b3b2f78e887fd658db6547c52ad4c3a385cea6927ad04ed446db0d076f3108300b0b7f3d2e89a47985d416af937be20888c4f4903b9929a46665055fc6cd951bafb46b68c6333a9f91c94fcf3732c739f1552e5286edc2aab5f2a330cffbf862a231ea9ca6632173dcfe5e0c85c6216f9c6914f087eb43e8a1f91b60a3e5055d99b0c2844b248cf53e6b6030c241baa9e8339ac1ebb85401dc0358eab6fee5dab47212e76b9587ebc7c4801dd14c2ffbbfab9681f4f325e241db85cf1545fd9874cc8f18106741e6d1639f500ea809da06e739904267d1c40482519f7d7047d12a89a341d7e0e960cf77935cd6c755d5b15d3670eacd06d41502de44c6bc0d6a56b173a0bce7917984dcb02c0d038126a5ca2dd125c58844f303f18d01adba873479b5609e5928edef04e125d63adf5f28c06512b36872df7ee27fe6bc60e01c580695ccdfd143ade242a344af25fecd1e9ecae2885eda38577bd13fb8c8fcaa96cdea90cad276bd9ec884d0e4eb76dbab0782125595f08fb3d34e4ec5ee2921df0e8fa983c426b0581aedf5c442bdef1ca4b1d0ad8f13a467793314e0227c43cbb76fbc39303b09063b0e178457219ee1d5744166aa2a1995c2dc5d87781e181c144bf9152af22b75900b26008add0a31d2e2106ded3d2b4c8ce7a2c7769da34918a706c04261f045d5925ed026ec18ed7ea240c5985fa2ee2460b9b84da757a3947e2ca31bcb1b434490ffb20a5805971d4a91f3b2de00c3e74e300eb83cd00ed6c18bc8527b40b437b934fcf5a6b43fad34c1b8f0b03fc99acb0915fd3629fd97d804cb536724299f771eec269f1695c6ab61002374bc18214ef3098987031bf91fffbcd198cfd505457ea20464559a60ee1148d958c729bf35faee658322526d91c0daa89e258235907511522f2e15a9ed82ae640cc4ec0762434ab961aba7d2339aa8082c90b6bf5e7b6ffdd10dd345cbc0e3044696d4ede556f66c316e269b51b58bdd797297a3cc823731e0fe73d33870f9525f4989223aadde859381c706532cf198da9482edc3925925e729c0af38219e7bc134fcb8419f63083df8a82290eae42661badaad71e19c2ab9c144fa6b8042b4ec6aa488356ff63278659e3da73a4991a53860101fbca57232259b53f751378eeed9194092715261dba63c5d102fcedd1f17610eac7bdd8e17324f6b9e41b1954a09693822e092cf08d202a18e5fb52550b6d7ff76861be6c30fe74393a0bf774575eae00aa82c1f3d3633a425b49e0850322f2c09e205c99d5a34caf8d25cdbfebf450fb1d546f2e85ee499c3302873eca6fa4d1cd821309e71ed56eb001260cb27f99b2b4cb5bede90f2489af25efcd9305fc4b42c9d91cdac6cb461f00d31c13952f8929884857a4c83b20a218c0417035c991160ebab320ee80117610e3c3e06370115ace2b0e19b609329c0e6d5076d25ce9547ef136beaf71f5b80c37036e0c9316191251a52ef33f7f4d1e8de8b3df6a28409fed341257c76f5c03cb1c5cb792fa09adc796be78cbd399a6ebd961cb376987904a1b81b0f6a00d2acd282de21734253b0176b85a2e8cd777b5b275ef5c015e341f5dc501a0c5b01b33fa424d93ca3d906357eb09388e32c7a270777af8eeba189d6803b926704e20d80c74226cc069810914795cbd1a51aac141371c02acd7d6d2a9e444080b9f13c9480aa10a2a2919885fb175a05b70a2ed28d16adbc5b3c402cacdc9d4fdcc1e3f8528fb0c4bbd1aff4350849ef3323d53a4c5a8e5e1dc166fb5fd9780c0c522f86e6a2b874c08db3d7c4c0d9fa88e6b0633d2cb90fe1cab1b76b914423dd505e293065b40f9f474cdcadd071b06c1bef97318a2aa7a655de844d5b3cb1e340786b3ec19589b533a15db232082568414069a97680eeae9d4af4a3eda73a107166ece5fe618c4c2195a0598b1e98d0301fd6d396507dcec91bdb277758dee7f0f7b14af9b0642eae90d4af37ff69a7c45cb86a232dace2f0e46fe02635f94cb1673a6322fd425d15754f2e8cd35e9d0064b0acb73739053e7f4700eb161500f2e2b3707c894f788802eec431e1556d285e5a16e7d7a3c33b69343f232415ea51ec2514b922ce3ba954283a8e15eac2a7ff2eec8f35f8c667c3778b8392d0de50081abbaa8f77211dae2e356f99c2a9a22b1cb3c2e3120ccceaf470916e535ff048e1dacb368a187f3da9ebb874d90c7859124416723cd805cfad273b055595028b5c3f32b72c7197d6e58167b9864ae2180fd7ddb43f6357541d6adefa558ba1bc61da46d1a3209c05d4fdcda7d9618b2af006da83298f46af48d703c08d9e51da0fe12dc7bf6344e7f6450c993a677296cf844c5f02ea6cd0b3465ee91c42f0aa5fcda39b8b4d4ed956865152acc41692dd0b73f52abbaf3eac40fd3f82edb17d3cc47a6380231bc44f3fd22ebc2981312247f1290dc6d2645b2cf799d0cb012deecb6b67d13755aa6ffc5308e1e662140e608113f953b26c75f81ba04553324d02d7a47db1132dfe213c0165921a71cfca2069e04dc2dd55c4be374ee3b2a0ac8679212de34fc553c342f581fb2eab051229ef4098d476a5b456b0895b13d032d38dd72d8698af945e0e878001edbc716326903562c2bf97cbcb74c9ae4761b18765aac2885a07771f2a40321c7738292beaec50a05d11f4710cdcce19e4019c4d9a16055b3954209e3b35b554a829ed1f20747f930c0d6c1a6f3980428b1231ac0c7fdb39be1d0f098f5961474027af8952bcf8a0a6b09d7595c4a32b58e179d180a05dddcf41d0b14f587ce2ab0c6bad3a2de970c2081792fe2a3f7c9710a344bd103e90c1bb86664d68122031b62b8c30ade9bf6013dae0e5f7d74d30b08cedef346df93e6091892f903be800f5f05b3f537d7627a5219292138ea63af2e50940dd6c288d6cf2a09e006abdbdffb12525aea7eaf60485687a9a4ccd740183e5526ea06c88206ecfc085de0eb0b3d6e4fe13fdfa04ac4176e042337011b1fdbf02f3e998759d6fb944d0568b18bd1f5c66cc81beba455df592f68e69d6e2b7169c2afc7931375d8f83e346c21450ed41ded81046beb6fdf14e3edd67472000d6ffbc435976ab6f353fab584d4792e1592e95e7ba673eee6a036a9b32c710aa9d2da42c3ea5b05f27999f0c77a3acab7a04ad8eb28734f537f9d29e2f2f72a9f250b03f340652bc724275f99acbda3f870f32ba7535e536cf9097435b62e0ff94c950bf529ee7034baa07cbc8c23b72bbbe4a3c89cbde47e6da3ed81eed50dc3760bb5abbb13e99b83670fb9a2ae2facbbca3e091e15b32e613da13a3a0d287dc97f4c8883c4166b6bc17825c6219634d5549a8a48da3b183e3a0b6f77661a035448f766152e99d9b5defae99385e6635de9af1181d885fff7baff3c894e020d5145a7bd3b39efeff509f3375281a9f63560eeab094b618b963b06d42b2c0c0c8966773349bbbeeea684e11bf8fd771859aa8f184103811540cb7c7ae1fa03bdacda2c92858a9d509685ff88116ca08da8c10066fa6e2dc797b1d0bae6c00df90e6c1d8003efa2de0f188160953b5f8a51b255b71c18640165ddc675c52914436fe1aef443692577a53e77c27a4da9e28931c93feb727a1fb2ec07c905f11cf59fd11fecbd3813863e4d1717ee5c994610c77f6ec439e501c8a38452cdd60b549b544bd296e363474b7e841f9027970fd8583177250fa6d653a857e408102b9061eaf1fd770611f128f95a2c50f936cdbe9e0bf81bddfd40e5cd3abb667f29758d5c2300f683b1faffaacedb0444ef1d05543e761d5e50e0c58c3b012ce31e112bdbf2a1d9473714fda97e5f175858d14f345a0409097a4c08fc9cc61d15181f07ebe4db3012e8a2e42f239e0c0d51576c952e8270f8f02a676b9adf5cf5190cf6b3ca772105b6927359cf35a22ce558d0f9a81da03dda5985f4b5da4acc299ddd98f5b9fb06c8c8fec8322b2b85d738d4f8cb020ec09093a2e904ea2097174ef9ba230fbd8e48382a929b38fb855d683194ac9c3202de589b406e94d375280d2fc29774c682784d6344533f1d84b4191e1c55eb0cc94a82373f77dd717a1b2c9ecd0bd2dfd9ccf9d06f7b2a371a9c3963ca77577cefc285abae77a142d42e23f0c5ba2338b9cb22fc53496b0bf67cbc251320f3e7f2ce704bb24edf0fbe22a89d69a1cd142c9eb581c2002ee834a5ebd0f004cc0cfe08f1c528b3770ffe0ee687898baa157dc85abba7115b9f899d25045105e13e87a1ed2833c82896742fa39c48e10238fee6814ecfdb4bb528b34b22c0b2e0c6f7a8743408444d77d025994a7b347107794729f947d865be9252d04beb1aa5f4e01f9e0c3eb741071a128468cf27f6a306549c401216167f908d1039a4daf57f0b4e664a906bd6479500140786c2e599344366645d339f769b3a7c8e7b09dc79f3a32c0be116b369402605b9c32d4c2e8dc6834680eeef33f1c21e4a9b8581b9c7cd583f35bb4fc7611fdd9a0bdf096c90246e0398f1c7a4e4a47b287f4c9c2204319081cd8538487205d8d1a7dfcfa17c156ff0087233e916d12266045f87bf5103daf73d8e3a2fde0e62e7ef97b6c6836289c7d890dd5d298f3e9007c99963f0276e60746f8b03ad1003b6104124aa0c6b920e93166f1c978dfd2603ee536b5c82593352f87a95662d201bd0ce8a1681c40eeca6c973fcb9c922ff5d152a2645f5b59598e8cec10701baa06a568e7820105f93877452a3e0940d566b2991572c1634e9af19ce97da242d0a2d8affeab5f6c12b72882405b940c32322205c8c8e080d610167cbdb3e1973c0e2d829a5e26c98fa5268f48c7ea50a9e94ebe5a7a0575b77ebe5b274150297671aa0beca8d6595d290783fa165d6b2d3f8caa4e65515ce56321eb569001f4d19c2f0f1b093ca9057768fac67cc4ff47ed0ee09af9a37998b0ffbae5161295ab80416501937418f5d79c968af8cf07ae08762060ee5ebebeec6188ec4ef1fb8ffc385160cf543ca0ff47d95ba83bd83a9156e2b092047fa6fcda6963643186c109829abe10d1c3f41fd7331b9ff7ee2ec0a9830309381dd5875b4a6d96422224135e4071ad902e8155962ef4e5acfb1ff04ecabdd539636ac8a1a22de5a00ae0777e205666f929dc7ca3ed3248c5114d9186ae37e30875546aa97e85cdf116b1c088d83911d8d624eef919b9a33285c4c1688b9f2022f2d9b6a5dbd050f1f12f8fa5fbd6318a7d71785f6419b4964d24230e2fdccb5d6d3757b82cd514714cb4e7cd2abb89b1c4f3f25d796ccc69b6774395c1ccbaa239a8f9198994d6a2a3b3363e241099fa701207dda7fcbbf493bd7b627d47ed85157c73c680fb8ed2d3d89dcec0605f2177d391362e8fd51c9253687bbc4835ca521baa7ac38610f26fa4eb068f21d632f7e1a644be77215c28d0ab3b4cb1e88218ec84351227a5402481fbf23078ae83581756551be2bfca44f35574d34844677c5b2e0fb0c33a229a51f78a5b8a46386ab8d81d367f76fdfe2cd3afa03224efb83ca575271dfe4277f5a2b6b58929e24de6350a230e2d58698dcef9bad67ebe6ae5a256b80a69a'
-let ppZkAcc5 = '0x27ed42af98dc8e530f6d0a8779c6c89ec224d029ab91f1559ab865b6d9485bb903365f033fc50f81efd5ce6126f0d45b92c0fb0a4272fd88bfc970e856f7b704024e28cc022b603d25534f754fcdf1972d648c398cf3ad65088922898c791c31036f89073374b9f18e7ebe7bb57a226cb672098e556bcb8b6799802a540edcb907a696c5e6cb87546593d6b07988140363f7d0bd75559ad822566a373fb2d1d71b77f9c6cd5fe34d20a15666133daaaa327a7ca2f0566a7d8c3bb5ceee2831a9184ec2390aa2710dae729389e729f113eb26302d7dda961b569bfaf1618b95d007b3787751e8a0a235db36065ab01479c49e74f4361e5e0aa91cebd6e097352a1bb031136720b54cdd0c383efb5d5353433c3b2e97dba0166df32e7bf9df3dbb2cc70b06bb60171d0d00744da8c12df68a80ab1804fe1fdffb138461a02bb80b096ead1a64f1c9b803ab25f0b144366756d1dcffbe1e91e168c995921a8c87be28e26e8c9f5af55692f809ffa42b5c730770169dad42b1208aed6013fc3bd21c05ea36a987818b8d27b62f22b0cc0d6d8fbbbb335a62013ee23edb069043546f1b6a3c93a991dcde9883aedbbbb064199cda4d90a151dae5a059931676937a3012b8128069e0e3f0322847a878b63f7188cfc45b8487c0bcae27e57f802f0466302f8235f57a0dd560066bb8d6c81b1551c26e4a96456ae85b52c6b059c195992c2387e2dc29c97045c897c0f8e7585c3a476ba89454a17b77a6ef9054528ae40d8cfb452dc447b556d294e37d05e8b2b2c3a87074333e18d6d4751887e17d7f184f00d6803a1db07a2b44d2a76ec2e36c82986edd727977bdb4c24a10d13200061bf803db4f2e860618a92f30efaf11718c15f53190efb5912773b91dd199432e9bce8c764d2eca2de7dd4284946700105acdcc3387da14944919123d52dc3320973e2614f5f73f7c912e76c83ec7eb452f1579c82374e8ca54346327b2db2e076048b23722f22a056948410af7b395d47cce0789f6c9ce49b9767dfc93c6a817eee403916691cae31cdb6137c7ea030f2d8f7f4ff765e890bc86b3b25cbb8b24aec45c5d8fb07a5a3c3065a4241a1573f5dc65cf7d82368b24fa7c870fcca70c0eb8f06f0ee8deb6856dc803db33ab9a613175c361ed92fd2ccc8c1e2e553227e297be1a4fa9ace53dcc8c3520dfb160e638e371609be8cdfb9a7de45166e62f46fb482d923502367d5494eb20a49d274f3a49d450798b8eb484732ad3c54e20668a5faa20e64e62e2463fea3a7afd425cc935cf2c737733c6854f1c79e6bc2c44323625aa44b71576ef04464f9bd4f43701a02c6161172ab0904852b906c2197d34fd3f3fb39cde4f45025511573965b488f9fa25d370085af37878a4484a001a387fda5b72e640d2a667a4f9a7738cd291f1ca364440e88ebb76e788f4861bd4271085419d8c1503c8079303f161f2f4eb3628cc214c3cf63cec966c75f41b2233dc8ea3413a934ff6ec24e3b66eb9a9e1748288dc32983f420864eb5bce0921d0db7c4942954dfec6142e11ba9dca2427eb6dc2f0b94b08fa0305c9193307c76abe4f2c8306d833bb799e710a8545829b57db6101c12273b8f132c0f5480d849f815003acbf18d4ac4d8119bcf85d73e59cb8ab5fab3bc7d758be4b4fb026ecca6261553a05572ab50a311fe5f5cb416198e82b48380054924f9f8d326704f917f39f073488e66d96a47fcbc78be8777ad0f0b68e5606ee3370aab88cb70f14171f600e75045ed12500247ee32b9fc620acaf9e1ecb49f19ba73a06190a0cac7bec57ff666ee540aab23267207e4777cd138a4af832197b78ddc1c4c9152e62822b1db716cb49cbfdf70da61c5fbe02ff2585958e99ee44f107370428260e61831ead4f603374e2ed3c784dbc2037efdcb837a64996ad5c0efe3ab87ac80347cfb84cd15fece2f5243013073352a527ea450f300f37f61164b00336830902d4ebc718f81453096ecc87225343e32c0cc025e45080b922428f4e70acb83d201e7f7d4f9a0cfd6793daca8f1c67fa94bbdfc7864108858cf77a016239e55529cd03ae8e37906f9ddc8a185d8781baea45f311b40431acc46c2f337499640c1cb78335eebd5b684368663d5db2ff4352f5f303a1802fc92f56c021fea6f0f727335618e717a38a846dea4ba0465932df61df90376cbf1669a596089f11ffe32101fd27343125169864ac28ecedcbebd342b029fb385d9fd56293280b8e63e021237f8b9ae819875e79f5054938ef78b87cd6394f3c7cce488f5dd4d703fc690392b3ffc50b8d12ab67092965367e6287ddfe026d957c87e65a7b06e6e8dbc7301b9f508d1480070653ab1f813d795c0c5f2b39651a8306b8971378d7d6150d211a6c5c4a43b3da3f490cc767ae031f100fcde52b8ef4abe4a1ae8203b5ff09164f881e56fc66295d142654a2d26037c89ff097a0b041c74e8bc8f404967b7218f7842f500ecea1cf10c02c91fb18dbde0f59ad9b2f9ace92d744e76ff00e13171cfecb1672ad2df4b9e44d9b2bcd28f16006db97403a20ca7b7291e303b0f01d04fd9752ee802683e3c554e3eca84f43d5a0353620d14e22720d373fdce1401dcc2acbff2332628f39bb12b4e21b69c8560791d1094625470f3832c58571ff1c32ab4d026d9245e6115c7b24f0a2e9cea0e06c63a0adb1148658fc4299476605457b3a041967be6753d7c4001fa95d65bee269c7d2f2c55f2547d2cddc38d410035857e4fa448a2dd43ae2665705fc33ab7dca13b0c90bd426c6973eb616c91f01d8600f7fa2ac37526fd4a533de66da1081ffbb5b8fe83a3507445c35ea6a24e177feadf8fc617cec2d1b828f689f20e8c4c81fb8ddcc9b21f4180e5f8a0f026427c9a39422ab05c5f4a3d3482f11ef71a0f041803f902b7602d7e511983c13ad540fdf600d47612807d1b54813b5f8e45ccd798e0d6b7c215291e49df3602f2d3d37f62d75b61fbb3687138317fc932464474eac558aba91d69fd54369251b35f606db2f8bdc298b2e014c1bbb0b04ab3fafb0fca09172c0508c67d10a8d061db22e0d2f2588218f4467fe051e3fb5a6ec673672494641bcbd352119a100296f70bf15ae4743796c4e8561bddebe49f4c708f096d25fa84f472ac9eed6e415ba54595d70a9ed89225c6e0051b546996331c43dd5adf00a945b99fc6907821dcdf743ab77b58b6916874a9a00db95c19b1c98f9ea320a419731ca01284e43290f43de2ca602bf0fb05d8c07a5f435610de54cda70034abb163cb42ac750d913f327a3013e253bd9253c0fcf044b760ae2cf0d2b11adbc9047a4bbda1862900bb78a84b4b49eec212671e05b0bdbf7529bd75d02b79b06ec3fcabf913b738d128944d7f0bb699cf734e93ad703fc46157c40f483fa974907230e840c7314501a45c5cce8ba47d905ff25126e328b8059e089c8b7f055a306e716cbde41f74111a5f67da9b73486327617b3013e7019c1b01a9be05def3ad4f1679ada8f888903a919f93b044519834ce43638fcdf538838efeecc7545ce09477d970f43c2a818f3b96e4622a94ab16b2ed86a2867f0d98d00c2c8e8c35fe942da3486caef6c24921073aa22eaecd7cd3ec012dafedd20f01feb9b15d2d51e745be6a5a7bc4b056f57b8af2bb0c70003f25a519f59df8b9efbd750ba7a4cfeb6d7f306afef2d234cc125b4e80950d1355c8befa3cbcb37bb16bf2d0afcf5406dfdd928ea2393101daac81cf15bbdf9c3bc347c6c3908202f494b83423aaf44d8c0294235dde40440ace60af38b530b6b3cd27570921361fbd92734dc8c85d618524fff42291922b5000cb0fbee0b58e2dcd33a6b0506d8f0ad18616d8d0d32bdf7a9852445a50b9ef1bf9e9cb1a97398de5752156edf1d997b689ddd9cf5f1eed2bee401001413c0393286cdbc4fc8792132961d281bbfda4769d3c799ce9637f4b884fc26a1149b58771497c8db280fae1aa4d1f551bcd22ce868edc868fc3730a8c4d80c3d277866e23638e9f2994dd578f90384096157461b7bdab716cf4e1848d45512342b4874ce121b8cde20abdf8a6c9f0a3df096a9a9d00498ca408d64e49a9d8294287d291ff93f95a0f4a54a3599dc72d5a59e5393a8310d09faae92be3ae49c32240f28b3154f22c4456251bda5d01800ecb472b9668d144df2304a630022a2d10397dbf673a35a2906cc03dacefcfa5cfb7856b3a83b0785646e186903543c7e156d1fe5c42f7564f1acd2935c1edc935d911d522d040677ee615805777706960becbc136e3debc7fef4645627150b3ce3041485d2809e1c30e329a83a5e05220f8481d974738932fe3cd3be4931e9833e17799c1248ef82e9c6fecbcf117b0110665af4b5c47d88d06e4b7ab9860ad6ee1705a5389859c451611d72937f2390280b0e8dd185a41e4c3d3a9cc281ba47288bd4599688c5b310516fd0628610de0354706ae97c58c05896d9f0a28506241e45ee4610194041a75009c7dc1ab21e28970c4c1a5e323d61fbcf730795a148e336be70f7c2735a056d42f9723f76520ea73580961a0272914d07f43757b0980830a1a68037b6f9852f1dd7526ef6590c64dd152b011ed145926da021fd80e81f54c9accdfd319f4d69d0f80d93939c18305b9892f44e0e1433f86ae25ff5d2a4e9f4366c688f5285f66b52881dd22425d769364dcb7869fa36c16558c037eec2e1f0f91aec5312cf9794d5bb8f232a2e25d9da903fd46d41900d1783b04e0b3c8d9e040edf5de9a2ef53b03ce2ad5b2e8fbafe3c0922d973b96a1c6d43751d12ee3c9c41bd4df9b60bb477b56c2fa70196e759d24313db8c2dcf8563515d1918952aebc585cf59301eb88ca5b3b9711c5999073ff2d006194a56de87b856e31730d258c81175531f955c3eac9f619011bd60e9ab9f3c432d5a104d3ce3a96caf7bee8a4abbbd490ca332afebf5ce7312ded6ef9ec4263bdb573bc81c02fa2d7064ea95e5635ad0f74601519a98426c1779e2dea0a0a4f7376f5ef804c45e24e887e73a63b2876f52159900facbf8d60afb00eb05234113044ae70e16636c973a4542d0fc9170fd1c4d1700a614a92a240112ae396a8b38e56957eb8d4c6c8800f0c18d99d72a0ca67b1acba307848f0245237af08d09a3e13cc59435bdf759e277f90f6ff3aaa23dc451fda8d03d922845a3a181c88eeef05e3bb6ea176977cc3771ecd79e2c211dcd38f253cea16003ba38642cbee87449ec5e5943ca2e677c80aaffb982960501eb298f19d1d4af0d8b2dc1ff3f3219f6eccd854e6171b1bae9a4d0f954f94e60e1ef973022c9660c2c69698b9901fd1d03749a52c23c9d776f2f76b08c6b0e5ad6efb4b99984481f7f162ffb9878c5567828a024d3186ebf680c71d93e691a502bfb8b0017720121fa096c354ce3975f45444f37c92bce7f0885a0433b4d1706e2063c6b9bbceb27d63531aa210754806acf62e4bbb0248e3f127156a4d22f0a2a0bc6dd5c07810dd80c982fcb46df51b49fe85b033d341b76a4cf79e8b22f295f9cc94679856503be3315a5d9c54cb05579144043f25384751012f7a5d58af838606cafc780bd2cc21d3b2d69b99907a5be7c1bec6728d23596390eb167d269e994d776286fe82b2d3e2d1a7d507f29b488cc41e072170d44e2596f0d4fdc893ef2d1af14d31711d9f9920e81fe1a25761bfd5e7e32777e02218a9e88afebcd64675e8fbf443113afa7cadda79130417ebc3ff91867d5129fdbe55963e703b830bb07b2f110551f7395cb315180e49e93912fa44c863fa3100bd423a1efa15cd71c686a214a02014194eee045e0ba1c31685272530a38c016e90f7e6768da8f0c2b0ab7c6d45a27fe00d7f49fed3b73c9afeff5c1045e5f1dd84dc3ae2b033649cecb9bd87b7423806d8485fae469403e4c7f964d6ffd4cef673589c354d21afaabec1f04822b117d559d2a3140fbd0a5255f7cf4e9a7c3fbb7ad0e609ecfa69878bcac573dc509814e0ffe8f58276cbedbba1254a6a0531bf6e9e49d3d7225d4cd81a2953ae22e920fd6c718d80cfa47e47caf61c5c265728d25d20e9ef647c2a51c2e7f021012a8f4c5bd5b6d708919e5879247ffb2a6b98c7938a49c5ecbc8cbac25a494d40806cbc6cd8748a810d5681e0e7ddc751b9c246063211f92fe15d3ba677a4c461a97a5993bf80eaf6bd6c337a76c5df38fec38a6e548081774dfeab06ec5bbf91a062d87c71368326c302eed08dd8839f4291008a69141a3ff48f319bef1da8902f3329585aac885a52086b24fd49572adbc2cfcb5a08970c8a95936f3178785'
-
-let gpk = {
-    x: ["0x2bcb0fdd68cc2d0f526bcb2294ef8b39351bac5600d1cd985fd85327ffdbc1c4", "0x0b7ae56132b5109fd410ae9a3b7696186501bbc57907b65e010d2cd5ed30f3d3"],
-    y: ["0x2f2e8f9441636ec0b980652835a3f8f950558f7274a04025c7d1faf7220b65cd", "0x1f81397db3d8c87bd584f6c309de2335e15b550ed499c5a9abe42d510fd259dc"]
-}
+let gpk = fixture.gpk!
 
 // pEvals for input: "zkRand-v1-2024:1"
-let pEvals = [
-    {
-        indexPlus: 1,
-        value: {
-            "x": "0x09aa769f380c38c420b5fb74e2dfd5c106a13b3bd768df1c9cdc5210c96e7249",
-            "y": "0x12120e4356b1b3604590f7472c0faee47174a393bcaee8744e12234dce453dc0"
-        },
-        proof: {
-            "z": "0x04ddc3a5232fb5cb09f41f97ca57a07787d2a581c0c8983d50bf615c0df12eda",
-            "c": "0x19bf60ee9c990b025d965418b828d5695cafdae5355b28949cc9ce5fbc3affd7"
-        }
-    },
-    {
-        indexPlus: 2,
-        value: {
-            "x": "0x17a33948ee9787011dd28bc0e9517758482afeff87f8d99475f54be313b9156c",
-            "y": "0x0d53484ed8cee4ea08612dcc724d1f1b5046a1bfb8936d71ecdc5ab4d6a7f63c"
-        },
-        proof: {
-            "z": "0x1e028d7794323b8516c0db6c20bedcdc9e32e199485564598dd29b7efce19c6a",
-            "c": "0x0c3db24c9f47ebd59400ec8f6625c33d3c8fd8bcecfd24bdaab7fc12e98bcafb"
-        }
-    },
-    {
-        indexPlus: 3,
-        value: {
-            "x": "0x2ccb2c3d841dd9794f7fb2166af049480201d5f5b1e4d07765f2990f54af11b4",
-            "y": "0x1aa031e1d25d6a7c3d541c941bc377dd90bca7de2c16f94fff3dcd1c85517c51"
-        },
-        proof: {
-            "z": "0x25c357227cade450a40ef8bfc827b9ce3b2b60a9cf54c57ed81f1cc6fe1cbd9c",
-            "c": "0x2ce4175efa547f1c565f88d5086439607fd15f74911982931eecdc4b46e84434"
-        }
-    },
-    {
-        indexPlus: 4,
-        value: {
-            "x": "0x039cfa3f8c580b870233615c7175577bd7bf12a3fbbe31fd29e9884694799507",
-            "y": "0x06bbb987a7d5e69575d1a264e98aba163923bd59f3b7786e105f46f0e83f9159"
-        },
-        proof: {
-            "z": "0x06ce3e7211234a2f4c282af7215c190e58a0ad74907b5a02b740eac5f549126f",
-            "c": "0x0fbc6420abf4dc7a9308ca94099af2d97976b66fb538bb54c4c7ee59b45420d6"
-        }
-    },
-    {
-        indexPlus: 5,
-        value: {
-            "x": "0x2a24375a6db14da077302c97ee2d9bf1255e1ab3e05a5d6ad6d92af505356436",
-            "y": "0x21a61de02a08d9a12ee9c2e1b49d4f27c8251c8e41937b18efb845fe2f1176da"
-        },
-        proof: {
-            "z": "0x0cde5743442c2381b99cfc8fe72daf85b448d59e1616a9c01f4614b9115a5b1c",
-            "c": "0x2a8adb051f0c51963a5d913cdf705411a1979e21850cd97f8124177d83d180cc"
-        }
-    }
-]
+let pEvals = fixture.partialEvals!
 
 let pEvalInvalid = {indexPlus: 1, value: pEvals[0].value, proof: pEvals[1].proof}
 
-let combinedSigma = {
-    x: '0x0dcc26707c0c8c1551d8ebe86748117fabe8dcbfce25ff4420790797ed19d97e',
-    y: '0x19a125abbf5cf6e2f02211259638e721bc1727687a8ef9fdc939fa0927b00a21'
+let expectedValue = fixture.pseudoRandom!.value
+let pseudoRandom = fixture.pseudoRandom!
+
+// the shuffle of Lottery.sol
+function shuffle(players: string[], randValue: string): string[] {
+    const shuffled = [...players]
+    for (let i = 0; i < shuffled.length; i++) {
+        const j = BigNumber.from(utils.solidityKeccak256(['bytes32', 'uint256'], [randValue, i])).mod(i + 1).toNumber();
+        [shuffled[i], shuffled[j]] = [shuffled[j], shuffled[i]]
+    }
+
+    return shuffled
 }
-let expectedValue = '0xa9b9e5c66ab5090ecfcccbededdc92f572f5c1489a1c48c28349258ebb5ca4a1'
-let pseudoRandom = {proof: combinedSigma, value: expectedValue}
 
 const cfg = hre.network.config
 
 describe('ZKDVRF (with precomputation of hash) on-chain tests', async () => {
     before(async () => {
         Halo2Verifier = await (await ethers.getContractFactory('contracts/Halo2Verifier.sol:Halo2Verifier')).deploy()
-        Halo2VerifyingKey = await (await ethers.getContractFactory('contracts/Halo2VerifyingKey-3-5-18-g2.sol:Halo2VerifyingKey')).deploy()
+        Halo2VerifyingKey = await (await ethers.getContractFactory(`${fixture.verifyingKey}:Halo2VerifyingKey`)).deploy()
         GlobalPublicParams = await (await ethers.getContractFactory('GlobalPublicParams')).deploy()
         PseudoRand = await (await ethers.getContractFactory('PseudoRand')).deploy()
 
@@ -373,8 +295,8 @@ describe('ZKDVRF (with precomputation of hash) on-chain tests', async () => {
 
         it('should be able to compute vk', async () => {
             await Zkdvrf.computeVk(gpk)
-            expect((await Zkdvrf.vkList(0))[0]).to.be.eq('0x2e69d5a8ff30f4fea07e8d2c0a5a6712ec1a6b5e91439c3b0b64961c27abf9a2')
-            expect((await Zkdvrf.vkList(0))[1]).to.be.eq('0x29e5bf9a331bd2b595767cf23cb3f2466c7df7380c9d58baea5a68fdb35ac612')
+            expect((await Zkdvrf.vkList(0))[0]).to.be.eq(fixture.vks![0].x)
+            expect((await Zkdvrf.vkList(0))[1]).to.be.eq(fixture.vks![0].y)
             expect(await Zkdvrf.contractPhase()).to.be.eq(4)
         })
     })
@@ -477,9 +399,10 @@ describe('ZKDVRF (with precomputation of hash) on-chain tests', async () => {
         it('lottery pickWinner()', async () => {
             await Lottery.connect(lotteryAdmin).pickWinner()
             expect(await Lottery.contractPhase()).to.be.eq(2)
-            expect(await Lottery.players(0)).to.be.eq(player2Address)
-            expect(await Lottery.players(1)).to.be.eq(player3Address)
-            expect(await Lottery.players(2)).to.be.eq(player1Address)
+            const players = shuffle([player1Address, player2Address, player3Address], pseudoRandom.value)
+            expect(await Lottery.players(0)).to.be.eq(players[0])
+            expect(await Lottery.players(1)).to.be.eq(players[1])
+            expect(await Lottery.players(2)).to.be.eq(players[2])
         })
     })
 })